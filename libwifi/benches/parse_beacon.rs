@@ -2,6 +2,7 @@ use criterion::{Criterion, Throughput, criterion_group, criterion_main};
 use rand::{RngExt, rng};
 
 use libwifi::parse_frame;
+use libwifi::parsers::{IeIter, decode_ie};
 
 const BEACON_PAYLOAD: [u8; 272] = [
     // Header
@@ -52,6 +53,22 @@ pub fn parse_beacon(crit: &mut Criterion) {
             assert!(parse_frame(&BEACON_PAYLOAD, false).is_ok())
         })
     });
+
+    // Zero-copy path: Only iterate over the elements and decode the RSN element on demand.
+    group.bench_function("Iterate beacon elements", |bencher| {
+        bencher.iter(|| {
+            let mut elements = IeIter::new(&BEACON_PAYLOAD[36..]);
+            let ssid = elements
+                .clone()
+                .find_map(|(id, _, data)| (id == 0).then_some(data));
+            assert_eq!(ssid, Some(&b"My face when IP"[..]));
+
+            let rsn = elements
+                .find(|(id, _, _)| *id == 48)
+                .and_then(|(id, ext_id, data)| decode_ie(id, ext_id, data));
+            assert!(rsn.is_some_and(|station_info| station_info.rsn_information.is_some()))
+        })
+    });
     group.finish()
 }
 
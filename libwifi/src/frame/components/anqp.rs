@@ -0,0 +1,429 @@
+use super::{VenueInfo, WFA_OUI};
+
+/// An ANQP element, which is carried in the query request or response of GAS frames.
+///
+/// Elements whose payload can't be decoded losslessly are kept as [AnqpElement::Unknown].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnqpElement {
+    /// The Info IDs that are queried (Info ID 256).
+    QueryList(Vec<u16>),
+    /// The Info IDs that are supported by the AP (Info ID 257).
+    CapabilityList(Vec<u16>),
+    /// Info ID 258
+    VenueName(VenueName),
+    /// Info ID 260
+    NetworkAuthenticationTypes(Vec<NetworkAuthenticationType>),
+    /// The Organization Identifiers of all roaming consortiums (Info ID 261).
+    RoamingConsortium(Vec<Vec<u8>>),
+    /// Info ID 262
+    IpAddressTypeAvailability(IpAddressTypeAvailability),
+    /// Info ID 263
+    NaiRealms(Vec<NaiRealm>),
+    /// The 3GPP Cellular Network information (Info ID 264).
+    CellularNetwork(CellularNetwork),
+    /// Info ID 268
+    DomainNames(Vec<String>),
+    /// A WFA Hotspot 2.0 ANQP element (Info ID 56797, OUI 50:6f:9a, type 0x11).
+    Hs20(Hs20AnqpElement),
+    Unknown {
+        info_id: u16,
+        data: Vec<u8>,
+    },
+}
+
+impl AnqpElement {
+    pub fn info_id(&self) -> u16 {
+        match self {
+            AnqpElement::QueryList(_) => 256,
+            AnqpElement::CapabilityList(_) => 257,
+            AnqpElement::VenueName(_) => 258,
+            AnqpElement::NetworkAuthenticationTypes(_) => 260,
+            AnqpElement::RoamingConsortium(_) => 261,
+            AnqpElement::IpAddressTypeAvailability(_) => 262,
+            AnqpElement::NaiRealms(_) => 263,
+            AnqpElement::CellularNetwork(_) => 264,
+            AnqpElement::DomainNames(_) => 268,
+            AnqpElement::Hs20(_) => 56797,
+            AnqpElement::Unknown { info_id, .. } => *info_id,
+        }
+    }
+
+    /// Encode the element including its Info ID and length.
+    pub fn encode(&self) -> Vec<u8> {
+        let payload = match self {
+            AnqpElement::QueryList(info_ids) | AnqpElement::CapabilityList(info_ids) => info_ids
+                .iter()
+                .flat_map(|info_id| info_id.to_le_bytes())
+                .collect(),
+            AnqpElement::VenueName(venue_name) => venue_name.encode(),
+            AnqpElement::NetworkAuthenticationTypes(types) => {
+                types.iter().flat_map(|kind| kind.encode()).collect()
+            }
+            AnqpElement::RoamingConsortium(ois) => encode_length_prefixed(ois),
+            AnqpElement::IpAddressTypeAvailability(availability) => vec![availability.encode()],
+            AnqpElement::NaiRealms(realms) => {
+                let mut bytes = (realms.len() as u16).to_le_bytes().to_vec();
+                for realm in realms {
+                    let realm = realm.encode();
+                    bytes.extend((realm.len() as u16).to_le_bytes());
+                    bytes.extend(realm);
+                }
+                bytes
+            }
+            AnqpElement::CellularNetwork(network) => network.encode(),
+            AnqpElement::DomainNames(names) => encode_length_prefixed(names),
+            AnqpElement::Hs20(element) => element.encode(),
+            AnqpElement::Unknown { data, .. } => data.clone(),
+        };
+
+        let mut bytes = self.info_id().to_le_bytes().to_vec();
+        bytes.extend((payload.len() as u16).to_le_bytes());
+        bytes.extend(payload);
+        bytes
+    }
+}
+
+fn encode_length_prefixed(values: &[impl AsRef<[u8]>]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for value in values {
+        bytes.push(value.as_ref().len() as u8);
+        bytes.extend_from_slice(value.as_ref());
+    }
+
+    bytes
+}
+
+/// A name in the given language, as used by the Venue Name and Operator Friendly Name elements.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalizedName {
+    /// The ISO-639 language code, e.g. `b"eng"` or `b"en\0"`.
+    pub language_code: [u8; 3],
+    pub name: String,
+}
+
+impl LocalizedName {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![(self.name.len() + 3) as u8];
+        bytes.extend(self.language_code);
+        bytes.extend(self.name.as_bytes());
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VenueName {
+    pub venue_info: VenueInfo,
+    pub names: Vec<LocalizedName>,
+}
+
+impl VenueName {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.venue_info.group, self.venue_info.venue_type];
+        for name in &self.names {
+            bytes.extend(name.encode());
+        }
+
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetworkAuthenticationType {
+    pub indicator: NetworkAuthenticationIndicator,
+    /// The URL to which the user is redirected, may be empty.
+    pub redirect_url: String,
+}
+
+impl NetworkAuthenticationType {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.indicator.encode()];
+        bytes.extend((self.redirect_url.len() as u16).to_le_bytes());
+        bytes.extend(self.redirect_url.as_bytes());
+        bytes
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NetworkAuthenticationIndicator {
+    AcceptanceOfTermsAndConditions,
+    OnlineEnrollment,
+    HttpRedirection,
+    DnsRedirection,
+    Unknown(u8),
+}
+
+impl From<u8> for NetworkAuthenticationIndicator {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => NetworkAuthenticationIndicator::AcceptanceOfTermsAndConditions,
+            1 => NetworkAuthenticationIndicator::OnlineEnrollment,
+            2 => NetworkAuthenticationIndicator::HttpRedirection,
+            3 => NetworkAuthenticationIndicator::DnsRedirection,
+            other => NetworkAuthenticationIndicator::Unknown(other),
+        }
+    }
+}
+
+impl NetworkAuthenticationIndicator {
+    pub fn encode(&self) -> u8 {
+        match self {
+            NetworkAuthenticationIndicator::AcceptanceOfTermsAndConditions => 0,
+            NetworkAuthenticationIndicator::OnlineEnrollment => 1,
+            NetworkAuthenticationIndicator::HttpRedirection => 2,
+            NetworkAuthenticationIndicator::DnsRedirection => 3,
+            NetworkAuthenticationIndicator::Unknown(value) => *value,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IpAddressTypeAvailability {
+    /// `0` if IPv6 isn't available, `1` if it's available and `2` if it's unknown.
+    pub ipv6: u8, // bit 0-1
+    /// `0` if IPv4 isn't available, `1` for public addresses, `2` for port-restricted addresses,
+    /// `3` for single NATed private addresses, `4` for double NATed private addresses, `5` and `6`
+    /// for port-restricted single or double NATed addresses and `7` if it's unknown.
+    pub ipv4: u8, // 2-7
+}
+
+impl IpAddressTypeAvailability {
+    pub fn from_bits(bits: u8) -> IpAddressTypeAvailability {
+        IpAddressTypeAvailability {
+            ipv6: bits & 0b11,
+            ipv4: bits >> 2,
+        }
+    }
+
+    pub fn encode(&self) -> u8 {
+        (self.ipv6 & 0b11) | (self.ipv4 << 2)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NaiRealm {
+    /// `true` if the realm is UTF-8 encoded, `false` if it's formatted according to RFC 4282.
+    pub utf8: bool, // bit 0
+    pub reserved: u8, // 1-7
+    /// One or more realms, separated by semicolons, see [NaiRealm::realms].
+    pub realm: String,
+    pub eap_methods: Vec<EapMethod>,
+}
+
+impl NaiRealm {
+    /// Encode the NAI Realm Data field without its length.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.utf8 as u8 | (self.reserved << 1)];
+        bytes.push(self.realm.len() as u8);
+        bytes.extend(self.realm.as_bytes());
+        bytes.push(self.eap_methods.len() as u8);
+        for method in &self.eap_methods {
+            let method = method.encode();
+            bytes.push(method.len() as u8);
+            bytes.extend(method);
+        }
+
+        bytes
+    }
+
+    /// The individual realms, e.g. `["example.com", "example.org"]`.
+    pub fn realms(&self) -> Vec<&str> {
+        self.realm.split(';').collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EapMethod {
+    /// The EAP method type, e.g. `13` for EAP-TLS or `21` for EAP-TTLS.
+    pub method: u8,
+    pub authentication_parameters: Vec<AuthenticationParameter>,
+}
+
+impl EapMethod {
+    /// Encode the EAP Method field without its length.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.method, self.authentication_parameters.len() as u8];
+        for parameter in &self.authentication_parameters {
+            bytes.extend([parameter.id, parameter.value.len() as u8]);
+            bytes.extend(&parameter.value);
+        }
+
+        bytes
+    }
+}
+
+/// An authentication parameter of an [EapMethod].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuthenticationParameter {
+    /// The parameter type, e.g. `2` for the non-EAP inner authentication type or `5` for the
+    /// credential type.
+    pub id: u8,
+    pub value: Vec<u8>,
+}
+
+/// The 3GPP Cellular Network ANQP element, which lists the PLMNs of the cellular networks
+/// that can be used for authentication.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CellularNetwork {
+    /// The Generic container User Data version, which is `0`.
+    pub version: u8,
+    pub plmns: Vec<Plmn>,
+}
+
+impl CellularNetwork {
+    pub fn encode(&self) -> Vec<u8> {
+        let plmn_list_length = self.plmns.len() * 3 + 1;
+        let mut bytes = vec![
+            self.version,
+            (plmn_list_length + 2) as u8,
+            // PLMN List information element
+            0,
+            plmn_list_length as u8,
+            self.plmns.len() as u8,
+        ];
+        for plmn in &self.plmns {
+            bytes.extend(plmn.0);
+        }
+
+        bytes
+    }
+}
+
+/// A BCD encoded Public Land Mobile Network identifier, which consists of the MCC and MNC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Plmn(pub [u8; 3]);
+
+impl Plmn {
+    /// The Mobile Country Code, e.g. `"310"`.
+    pub fn mcc(&self) -> String {
+        digits(&[self.0[0] & 0x0f, self.0[0] >> 4, self.0[1] & 0x0f])
+    }
+
+    /// The two or three digit Mobile Network Code, e.g. `"410"`.
+    pub fn mnc(&self) -> String {
+        digits(&[self.0[2] & 0x0f, self.0[2] >> 4, self.0[1] >> 4])
+    }
+}
+
+/// Format BCD digits, ignoring filler digits.
+fn digits(digits: &[u8]) -> String {
+    digits
+        .iter()
+        .filter(|digit| **digit < 10)
+        .map(|digit| char::from(b'0' + digit))
+        .collect()
+}
+
+/// A WFA Hotspot 2.0 ANQP element, which is identified by its subtype.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Hs20AnqpElement {
+    /// The Hotspot 2.0 subtypes that are queried (subtype 1).
+    QueryList(Vec<u8>),
+    /// The Hotspot 2.0 subtypes that are supported by the AP (subtype 2).
+    CapabilityList(Vec<u8>),
+    /// Subtype 3
+    OperatorFriendlyName(Vec<LocalizedName>),
+    /// Subtype 4
+    WanMetrics(WanMetrics),
+    /// The status of commonly used protocols and ports (subtype 5).
+    ConnectionCapability(Vec<ProtocolPort>),
+    /// The operating classes that are used by the hotspot operator (subtype 7).
+    OperatingClassIndication(Vec<u8>),
+    Unknown {
+        subtype: u8,
+        data: Vec<u8>,
+    },
+}
+
+impl Hs20AnqpElement {
+    pub fn subtype(&self) -> u8 {
+        match self {
+            Hs20AnqpElement::QueryList(_) => 1,
+            Hs20AnqpElement::CapabilityList(_) => 2,
+            Hs20AnqpElement::OperatorFriendlyName(_) => 3,
+            Hs20AnqpElement::WanMetrics(_) => 4,
+            Hs20AnqpElement::ConnectionCapability(_) => 5,
+            Hs20AnqpElement::OperatingClassIndication(_) => 7,
+            Hs20AnqpElement::Unknown { subtype, .. } => *subtype,
+        }
+    }
+
+    /// Encode the element payload, starting with the OUI.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = WFA_OUI.to_vec();
+        bytes.extend([0x11, self.subtype(), 0]);
+        match self {
+            Hs20AnqpElement::QueryList(subtypes) | Hs20AnqpElement::CapabilityList(subtypes) => {
+                bytes.extend(subtypes)
+            }
+            Hs20AnqpElement::OperatorFriendlyName(names) => {
+                for name in names {
+                    bytes.extend(name.encode());
+                }
+            }
+            Hs20AnqpElement::WanMetrics(metrics) => bytes.extend(metrics.encode()),
+            Hs20AnqpElement::ConnectionCapability(tuples) => {
+                for tuple in tuples {
+                    bytes.extend(tuple.encode());
+                }
+            }
+            Hs20AnqpElement::OperatingClassIndication(classes) => bytes.extend(classes),
+            Hs20AnqpElement::Unknown { data, .. } => bytes.extend(data),
+        }
+
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WanMetrics {
+    /// `1` if the link is up, `2` if it's down and `3` if it's in a test state.
+    pub link_status: u8, // bit 0-1
+    pub symmetric_link: bool, // 2
+    pub at_capacity: bool,    // 3
+    pub reserved: u8,         // 4-7
+    /// The downlink speed in kbit/s.
+    pub downlink_speed: u32,
+    /// The uplink speed in kbit/s.
+    pub uplink_speed: u32,
+    /// The downlink load in units of 1/255.
+    pub downlink_load: u8,
+    /// The uplink load in units of 1/255.
+    pub uplink_load: u8,
+    /// The duration in units of 0.1 seconds over which the load has been measured.
+    pub load_measurement_duration: u16,
+}
+
+impl WanMetrics {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![
+            (self.link_status & 0b11)
+                | ((self.symmetric_link as u8) << 2)
+                | ((self.at_capacity as u8) << 3)
+                | (self.reserved << 4),
+        ];
+        bytes.extend(self.downlink_speed.to_le_bytes());
+        bytes.extend(self.uplink_speed.to_le_bytes());
+        bytes.extend([self.downlink_load, self.uplink_load]);
+        bytes.extend(self.load_measurement_duration.to_le_bytes());
+        bytes
+    }
+}
+
+/// An entry of the Connection Capability element.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProtocolPort {
+    /// The IP protocol, e.g. `6` for TCP and `17` for UDP.
+    pub ip_protocol: u8,
+    pub port: u16,
+    /// `0` if the port is closed, `1` if it's open and `2` if it's unknown.
+    pub status: u8,
+}
+
+impl ProtocolPort {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.ip_protocol];
+        bytes.extend(self.port.to_le_bytes());
+        bytes.push(self.status);
+        bytes
+    }
+}
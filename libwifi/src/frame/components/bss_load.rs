@@ -0,0 +1,81 @@
+/// The BSS Load element (Element ID 11).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BssLoad {
+    /// The number of stations that are currently associated with this BSS.
+    pub station_count: u16,
+    /// The percentage of time the AP sensed the medium as busy, scaled to `0-255`.
+    pub channel_utilization: u8,
+    /// The remaining medium time for explicit admission control in units of 32 µs/s.
+    pub available_admission_capacity: u16,
+}
+
+impl BssLoad {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.station_count.to_le_bytes().to_vec();
+        bytes.push(self.channel_utilization);
+        bytes.extend(self.available_admission_capacity.to_le_bytes());
+        bytes
+    }
+
+    /// The channel utilization in percent.
+    pub fn channel_utilization_percent(&self) -> f32 {
+        self.channel_utilization as f32 * 100.0 / 255.0
+    }
+}
+
+/// The Extended BSS Load element (Element ID 193).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExtendedBssLoad {
+    /// The number of associated stations that are MU-MIMO capable.
+    pub mu_mimo_capable_station_count: u16,
+    /// The percentage of time that spatial streams weren't used, scaled to `0-255`.
+    pub spatial_stream_underutilization: u8,
+    pub observable_secondary_20mhz_utilization: u8,
+    pub observable_secondary_40mhz_utilization: u8,
+    pub observable_secondary_80mhz_utilization: u8,
+}
+
+impl ExtendedBssLoad {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.mu_mimo_capable_station_count.to_le_bytes().to_vec();
+        bytes.extend([
+            self.spatial_stream_underutilization,
+            self.observable_secondary_20mhz_utilization,
+            self.observable_secondary_40mhz_utilization,
+            self.observable_secondary_80mhz_utilization,
+        ]);
+        bytes
+    }
+}
+
+/// The ERP Information element (Element ID 42), which is sent by 2.4 GHz APs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ErpInformation {
+    /// Non-ERP (802.11b) stations are associated with the BSS.
+    pub non_erp_present: bool, // bit 0
+    /// Protection (RTS/CTS or CTS-to-self) must be used by ERP stations.
+    pub use_protection: bool, // 1
+    /// Only the long preamble may be used.
+    pub barker_preamble_mode: bool, // 2
+    pub reserved: u8, // 3-7
+}
+
+impl ErpInformation {
+    pub fn from_bits(bits: u8) -> Self {
+        ErpInformation {
+            non_erp_present: bits & 1 != 0,
+            use_protection: bits & (1 << 1) != 0,
+            barker_preamble_mode: bits & (1 << 2) != 0,
+            reserved: bits >> 3,
+        }
+    }
+
+    pub fn encode(&self) -> u8 {
+        let mut bits = self.non_erp_present as u8;
+        bits |= (self.use_protection as u8) << 1;
+        bits |= (self.barker_preamble_mode as u8) << 2;
+        bits |= self.reserved << 3;
+
+        bits
+    }
+}
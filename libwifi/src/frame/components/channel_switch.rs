@@ -0,0 +1,135 @@
+use super::ChannelSwitchMode;
+
+/// The Extended Channel Switch Announcement element (Element ID 60).
+///
+/// In contrast to the [ChannelSwitchAnnouncment](super::ChannelSwitchAnnouncment), it also
+/// announces the operating class of the new channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExtendedChannelSwitchAnnouncement {
+    pub mode: ChannelSwitchMode,
+    pub new_operating_class: u8,
+    pub new_channel: u8,
+    /// The number of TBTTs until the switch.
+    pub count: u8,
+}
+
+impl ExtendedChannelSwitchAnnouncement {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![
+            self.mode.clone() as u8,
+            self.new_operating_class,
+            self.new_channel,
+            self.count,
+        ]
+    }
+}
+
+/// The Wide Bandwidth Channel Switch element (Element ID 194).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WideBandwidthChannelSwitch {
+    /// `0` for 20/40 MHz, `1` for 80/160/80+80 MHz, `2` and `3` are the deprecated 160 and
+    /// 80+80 MHz values.
+    pub new_channel_width: u8,
+    /// Channel Center Frequency Segment 0
+    pub new_ccfs0: u8,
+    /// Channel Center Frequency Segment 1
+    pub new_ccfs1: u8,
+}
+
+impl WideBandwidthChannelSwitch {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![self.new_channel_width, self.new_ccfs0, self.new_ccfs1]
+    }
+
+    /// The new bandwidth in MHz, or `None` for 20/40 MHz.
+    /// 80+80 MHz channels are reported as 160 MHz.
+    pub fn bandwidth(&self) -> Option<u16> {
+        match self.new_channel_width {
+            1 if self.new_ccfs1 == 0 => Some(80),
+            1..=3 => Some(160),
+            _ => None,
+        }
+    }
+}
+
+/// The Channel Switch Wrapper element (Element ID 196).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChannelSwitchWrapper {
+    pub subelements: Vec<ChannelSwitchWrapperSubelement>,
+}
+
+impl ChannelSwitchWrapper {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for subelement in &self.subelements {
+            let (id, data) = match subelement {
+                ChannelSwitchWrapperSubelement::WideBandwidthChannelSwitch(switch) => {
+                    (194, switch.encode())
+                }
+                ChannelSwitchWrapperSubelement::Unknown { id, data } => (*id, data.clone()),
+            };
+            bytes.extend([id, data.len() as u8]);
+            bytes.extend(data);
+        }
+
+        bytes
+    }
+
+    pub fn wide_bandwidth_channel_switch(&self) -> Option<&WideBandwidthChannelSwitch> {
+        self.subelements
+            .iter()
+            .find_map(|subelement| match subelement {
+                ChannelSwitchWrapperSubelement::WideBandwidthChannelSwitch(switch) => Some(switch),
+                _ => None,
+            })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ChannelSwitchWrapperSubelement {
+    WideBandwidthChannelSwitch(WideBandwidthChannelSwitch),
+    /// All other subelements (e.g. Country or Transmit Power Envelope) in their raw form.
+    Unknown {
+        id: u8,
+        data: Vec<u8>,
+    },
+}
+
+/// The channel a BSS will switch to, as computed by [StationInfo::channel_switch_target].
+///
+/// [StationInfo::channel_switch_target]: super::StationInfo::channel_switch_target
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChannelSwitchTarget {
+    pub channel: u8,
+    pub operating_class: Option<u8>,
+    /// The frequency of the new primary channel in MHz.
+    pub primary_frequency: u32,
+    /// The new bandwidth in MHz. 80+80 MHz channels are reported as 160 MHz.
+    pub bandwidth: u16,
+}
+
+/// Get the center frequency of a 20 MHz channel in MHz.
+///
+/// The operating class is required to distinguish 6 GHz channels from 2.4/5 GHz channels.
+pub fn channel_to_frequency(channel: u8, operating_class: Option<u8>) -> u32 {
+    let channel = channel as u32;
+    match operating_class {
+        Some(136) => 5935,
+        Some(131..=137) => 5950 + channel * 5,
+        _ if channel == 14 => 2484,
+        _ if channel <= 14 => 2407 + channel * 5,
+        _ => 5000 + channel * 5,
+    }
+}
+
+/// Get the channel bandwidth in MHz of a global operating class.
+pub fn operating_class_bandwidth(operating_class: u8) -> Option<u16> {
+    match operating_class {
+        81 | 82 | 115 | 118 | 121 | 124 | 125 | 131 | 136 => Some(20),
+        83 | 84 | 116 | 117 | 119 | 120 | 122 | 123 | 126 | 127 | 132 => Some(40),
+        128 | 133 => Some(80),
+        129 | 130 | 134 | 135 => Some(160),
+        137 => Some(320),
+        _ => None,
+    }
+}
@@ -0,0 +1,108 @@
+use std::{any::Any, fmt::Debug, sync::Arc};
+
+/// An element that's decoded by a parser of a downstream crate.
+///
+/// Register a parser for it via
+/// [ElementRegistry::register_element_parser](crate::parsers::ElementRegistry::register_element_parser).
+/// The decoded values are stored in [StationInfo::custom_elements](super::StationInfo::custom_elements).
+pub trait CustomElement: Any + Debug + Send + Sync {
+    /// Encode the element payload.
+    ///
+    /// The payload doesn't include the element header, i.e. for extension elements it starts
+    /// after the extension ID and for vendor specific elements after the OUI type.
+    fn encode(&self) -> Vec<u8>;
+}
+
+/// Identifies the elements a custom parser is responsible for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ElementKey {
+    /// A regular element ID.
+    Element(u8),
+    /// An extension ID of element 255.
+    Extension(u8),
+    /// The OUI and OUI type of a vendor specific element (221).
+    Vendor([u8; 3], u8),
+}
+
+impl ElementKey {
+    /// Encode a complete element for the given payload.
+    pub fn encode_element(&self, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        match self {
+            ElementKey::Element(id) => {
+                bytes.push(*id);
+                bytes.push(payload.len() as u8);
+            }
+            ElementKey::Extension(extension_id) => {
+                bytes.push(255);
+                bytes.push(payload.len() as u8 + 1);
+                bytes.push(*extension_id);
+            }
+            ElementKey::Vendor(oui, oui_type) => {
+                bytes.push(221);
+                bytes.push(payload.len() as u8 + 4);
+                bytes.extend(oui);
+                bytes.push(*oui_type);
+            }
+        }
+        bytes.extend_from_slice(payload);
+
+        bytes
+    }
+}
+
+/// The type-erased values of all elements that were decoded by custom parsers, in the order
+/// in which they were received.
+#[derive(Clone, Debug, Default)]
+pub struct CustomElements {
+    elements: Vec<(ElementKey, Arc<dyn CustomElement>)>,
+}
+
+impl CustomElements {
+    pub fn push(&mut self, key: ElementKey, element: Arc<dyn CustomElement>) {
+        self.elements.push((key, element));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Iterate over all elements and their keys.
+    pub fn iter(&self) -> impl Iterator<Item = &(ElementKey, Arc<dyn CustomElement>)> {
+        self.elements.iter()
+    }
+
+    /// Get the first element of type `T`.
+    pub fn get<T: CustomElement>(&self) -> Option<&T> {
+        self.get_all::<T>().next()
+    }
+
+    /// Get all elements of type `T`.
+    pub fn get_all<T: CustomElement>(&self) -> impl Iterator<Item = &T> {
+        self.elements
+            .iter()
+            .filter_map(|(_, element)| (element.as_ref() as &dyn Any).downcast_ref::<T>())
+    }
+
+    /// Get all elements with the given key.
+    pub fn get_by_key(&self, key: ElementKey) -> impl Iterator<Item = &Arc<dyn CustomElement>> {
+        self.elements
+            .iter()
+            .filter(move |(element_key, _)| *element_key == key)
+            .map(|(_, element)| element)
+    }
+
+    /// Encode all elements including their element headers.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for (key, element) in &self.elements {
+            bytes.extend(key.encode_element(&element.encode()));
+        }
+
+        bytes
+    }
+}
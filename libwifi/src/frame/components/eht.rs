@@ -0,0 +1,408 @@
+use super::{MacAddress, StationInfo};
+
+/// The EHT Capabilities element (Element ID 255, Extension ID 108) as defined by 802.11be.
+#[derive(Clone, Debug)]
+pub struct EhtCapabilities {
+    pub mac_capabilities: EhtMacCapabilities,
+    pub phy_capabilities: EhtPhyCapabilities,
+    /// The Supported EHT-MCS And NSS Set followed by the optional EHT PPE Thresholds.
+    ///
+    /// The length of the MCS/NSS set depends on the channel widths announced in the
+    /// HE Capabilities element, which is why both fields are kept as raw bytes.
+    pub supported_mcs_nss_and_ppe_thresholds: Vec<u8>,
+}
+
+impl EhtCapabilities {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.mac_capabilities.encode());
+        bytes.extend(&self.phy_capabilities.data);
+        bytes.extend(&self.supported_mcs_nss_and_ppe_thresholds);
+
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EhtMacCapabilities {
+    pub epcs_priority_access: bool,                    // bit 0
+    pub eht_om_control: bool,                          // 1
+    pub triggered_txop_sharing_mode_1: bool,           // 2
+    pub triggered_txop_sharing_mode_2: bool,           // 3
+    pub restricted_twt: bool,                          // 4
+    pub scs_traffic_description: bool,                 // 5
+    pub maximum_mpdu_length: u8,                       // 6, 7
+    pub maximum_ampdu_length_exponent_extension: bool, // 8
+    pub eht_trs: bool,                                 // 9
+    pub txop_return_in_txop_sharing_mode_2: bool,      // 10
+    pub two_bqrs: bool,                                // 11
+    pub eht_link_adaptation: u8,                       // 12, 13
+    pub unsolicited_epcs_priority_access_update: bool, // 14
+}
+
+impl EhtMacCapabilities {
+    pub fn from_bits(bits: u16) -> Self {
+        macro_rules! bit {
+            ($b:expr) => {
+                bits & (1 << $b) != 0
+            };
+        }
+
+        EhtMacCapabilities {
+            epcs_priority_access: bit!(0),
+            eht_om_control: bit!(1),
+            triggered_txop_sharing_mode_1: bit!(2),
+            triggered_txop_sharing_mode_2: bit!(3),
+            restricted_twt: bit!(4),
+            scs_traffic_description: bit!(5),
+            maximum_mpdu_length: ((bits >> 6) & 0b11) as u8,
+            maximum_ampdu_length_exponent_extension: bit!(8),
+            eht_trs: bit!(9),
+            txop_return_in_txop_sharing_mode_2: bit!(10),
+            two_bqrs: bit!(11),
+            eht_link_adaptation: ((bits >> 12) & 0b11) as u8,
+            unsolicited_epcs_priority_access_update: bit!(14),
+        }
+    }
+
+    pub fn encode(&self) -> [u8; 2] {
+        let mut bits: u16 = 0;
+        bits |= self.epcs_priority_access as u16;
+        bits |= (self.eht_om_control as u16) << 1;
+        bits |= (self.triggered_txop_sharing_mode_1 as u16) << 2;
+        bits |= (self.triggered_txop_sharing_mode_2 as u16) << 3;
+        bits |= (self.restricted_twt as u16) << 4;
+        bits |= (self.scs_traffic_description as u16) << 5;
+        bits |= ((self.maximum_mpdu_length & 0b11) as u16) << 6;
+        bits |= (self.maximum_ampdu_length_exponent_extension as u16) << 8;
+        bits |= (self.eht_trs as u16) << 9;
+        bits |= (self.txop_return_in_txop_sharing_mode_2 as u16) << 10;
+        bits |= (self.two_bqrs as u16) << 11;
+        bits |= ((self.eht_link_adaptation & 0b11) as u16) << 12;
+        bits |= (self.unsolicited_epcs_priority_access_update as u16) << 14;
+
+        bits.to_le_bytes()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EhtPhyCapabilities {
+    pub support_320mhz_in_6ghz: bool,
+    pub su_beamformer: bool,
+    pub su_beamformee: bool,
+    /// Beamformee spatial streams for bandwidths up to 80 MHz.
+    pub beamformee_ss_80mhz: u8,
+    pub partial_bandwidth_dl_mu_mimo: bool,
+    pub ppe_thresholds_present: bool,
+    /// The raw 9 bytes of the EHT PHY Capabilities Information field.
+    pub data: Vec<u8>,
+}
+
+/// The EHT Operation element (Element ID 255, Extension ID 106) as defined by 802.11be.
+#[derive(Clone, Debug)]
+pub struct EhtOperation {
+    pub default_pe_duration: bool,
+    pub group_addressed_bu_indication_limit: bool,
+    pub group_addressed_bu_indication_exponent: u8,
+    pub basic_mcs_nss_set: [u8; 4],
+    pub operation_information: Option<EhtOperationInformation>,
+}
+
+impl EhtOperation {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut parameters = 0;
+        if let Some(info) = &self.operation_information {
+            parameters |= 1;
+            if info.disabled_subchannel_bitmap.is_some() {
+                parameters |= 1 << 1;
+            }
+        }
+        parameters |= (self.default_pe_duration as u8) << 2;
+        parameters |= (self.group_addressed_bu_indication_limit as u8) << 3;
+        parameters |= (self.group_addressed_bu_indication_exponent & 0b11) << 4;
+
+        let mut bytes = vec![parameters];
+        bytes.extend_from_slice(&self.basic_mcs_nss_set);
+
+        if let Some(info) = &self.operation_information {
+            bytes.push(info.channel_width as u8);
+            bytes.push(info.ccfs0);
+            bytes.push(info.ccfs1);
+            if let Some(bitmap) = info.disabled_subchannel_bitmap {
+                bytes.extend_from_slice(&bitmap.to_le_bytes());
+            }
+        }
+
+        bytes
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EhtOperationInformation {
+    pub channel_width: EhtChannelWidth,
+    /// Channel center frequency segment 0
+    pub ccfs0: u8,
+    /// Channel center frequency segment 1
+    pub ccfs1: u8,
+    pub disabled_subchannel_bitmap: Option<u16>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EhtChannelWidth {
+    Mhz20 = 0,
+    Mhz40 = 1,
+    Mhz80 = 2,
+    Mhz160 = 3,
+    Mhz320 = 4,
+}
+
+impl From<u8> for EhtChannelWidth {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Mhz40,
+            2 => Self::Mhz80,
+            3 => Self::Mhz160,
+            4 => Self::Mhz320,
+            _ => Self::Mhz20,
+        }
+    }
+}
+
+/// The Multi-Link element (Element ID 255, Extension ID 107) as defined by 802.11be.
+///
+/// Only the Basic variant, which is sent by AP MLDs in beacons, probe responses and
+/// association frames, is decoded for now.
+#[derive(Clone, Debug)]
+pub enum MultiLink {
+    Basic(BasicMultiLink),
+    /// Any other Multi-Link variant (Probe Request, Reconfiguration, TDLS, ...).
+    Unknown {
+        /// The whole Multi-Link Control field, including the type.
+        control: u16,
+        data: Vec<u8>,
+    },
+}
+
+impl MultiLink {
+    pub fn encode(&self) -> Vec<u8> {
+        match self {
+            MultiLink::Basic(basic) => basic.encode(),
+            MultiLink::Unknown { control, data } => {
+                let mut bytes = control.to_le_bytes().to_vec();
+                bytes.extend(data);
+                bytes
+            }
+        }
+    }
+}
+
+/// The Basic Multi-Link element.
+///
+/// All optional fields of the common info are only present, if the respective bit in the
+/// presence bitmap of the Multi-Link Control field is set.
+#[derive(Clone, Debug)]
+pub struct BasicMultiLink {
+    pub mld_mac_address: MacAddress,
+    pub link_id: Option<u8>,
+    pub bss_parameters_change_count: Option<u8>,
+    pub medium_synchronization_delay: Option<u16>,
+    pub eml_capabilities: Option<EmlCapabilities>,
+    pub mld_capabilities: Option<MldCapabilities>,
+    pub ap_mld_id: Option<u8>,
+    pub extended_mld_capabilities: Option<u16>,
+    pub per_sta_profiles: Vec<PerStaProfile>,
+}
+
+impl BasicMultiLink {
+    pub fn encode(&self) -> Vec<u8> {
+        // Multi-Link Control: type 0 (Basic) and the presence bitmap.
+        let mut control: u16 = 0;
+        control |= (self.link_id.is_some() as u16) << 4;
+        control |= (self.bss_parameters_change_count.is_some() as u16) << 5;
+        control |= (self.medium_synchronization_delay.is_some() as u16) << 6;
+        control |= (self.eml_capabilities.is_some() as u16) << 7;
+        control |= (self.mld_capabilities.is_some() as u16) << 8;
+        control |= (self.ap_mld_id.is_some() as u16) << 9;
+        control |= (self.extended_mld_capabilities.is_some() as u16) << 10;
+
+        let mut common_info = Vec::new();
+        common_info.extend_from_slice(&self.mld_mac_address.encode());
+        if let Some(link_id) = self.link_id {
+            common_info.push(link_id & 0x0f);
+        }
+        if let Some(count) = self.bss_parameters_change_count {
+            common_info.push(count);
+        }
+        if let Some(delay) = self.medium_synchronization_delay {
+            common_info.extend_from_slice(&delay.to_le_bytes());
+        }
+        if let Some(eml_capabilities) = &self.eml_capabilities {
+            common_info.extend_from_slice(&eml_capabilities.encode());
+        }
+        if let Some(mld_capabilities) = &self.mld_capabilities {
+            common_info.extend_from_slice(&mld_capabilities.encode());
+        }
+        if let Some(ap_mld_id) = self.ap_mld_id {
+            common_info.push(ap_mld_id);
+        }
+        if let Some(extended) = self.extended_mld_capabilities {
+            common_info.extend_from_slice(&extended.to_le_bytes());
+        }
+
+        let mut bytes = control.to_le_bytes().to_vec();
+        // The common info length includes the length byte itself.
+        bytes.push(common_info.len() as u8 + 1);
+        bytes.extend(common_info);
+
+        for profile in &self.per_sta_profiles {
+            let profile_data = profile.encode();
+            bytes.push(0); // Per-STA Profile subelement ID
+            bytes.push(profile_data.len() as u8);
+            bytes.extend(profile_data);
+        }
+
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EmlCapabilities {
+    pub emlsr_support: bool,        // bit 0
+    pub emlsr_padding_delay: u8,    // 1-3
+    pub emlsr_transition_delay: u8, // 4-6
+    pub emlmr_support: bool,        // 7
+    pub transition_timeout: u8,     // 11-14
+}
+
+impl EmlCapabilities {
+    pub fn from_bits(bits: u16) -> Self {
+        EmlCapabilities {
+            emlsr_support: bits & 1 != 0,
+            emlsr_padding_delay: ((bits >> 1) & 0b111) as u8,
+            emlsr_transition_delay: ((bits >> 4) & 0b111) as u8,
+            emlmr_support: bits & (1 << 7) != 0,
+            transition_timeout: ((bits >> 11) & 0b1111) as u8,
+        }
+    }
+
+    pub fn encode(&self) -> [u8; 2] {
+        let mut bits: u16 = 0;
+        bits |= self.emlsr_support as u16;
+        bits |= ((self.emlsr_padding_delay & 0b111) as u16) << 1;
+        bits |= ((self.emlsr_transition_delay & 0b111) as u16) << 4;
+        bits |= (self.emlmr_support as u16) << 7;
+        bits |= ((self.transition_timeout & 0b1111) as u16) << 11;
+
+        bits.to_le_bytes()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MldCapabilities {
+    /// The maximum number of simultaneous links minus one.
+    pub max_simultaneous_links: u8, // bit 0-3
+    pub srs_support: bool,                   // 4
+    pub tid_to_link_mapping_negotiation: u8, // 5, 6
+    pub frequency_separation_for_str: u8,    // 7-11
+    pub aar_support: bool,                   // 12
+    pub link_reconfiguration_support: bool,  // 13
+    pub aligned_twt_support: bool,           // 14
+}
+
+impl MldCapabilities {
+    pub fn from_bits(bits: u16) -> Self {
+        MldCapabilities {
+            max_simultaneous_links: (bits & 0b1111) as u8,
+            srs_support: bits & (1 << 4) != 0,
+            tid_to_link_mapping_negotiation: ((bits >> 5) & 0b11) as u8,
+            frequency_separation_for_str: ((bits >> 7) & 0b1_1111) as u8,
+            aar_support: bits & (1 << 12) != 0,
+            link_reconfiguration_support: bits & (1 << 13) != 0,
+            aligned_twt_support: bits & (1 << 14) != 0,
+        }
+    }
+
+    pub fn encode(&self) -> [u8; 2] {
+        let mut bits: u16 = 0;
+        bits |= (self.max_simultaneous_links & 0b1111) as u16;
+        bits |= (self.srs_support as u16) << 4;
+        bits |= ((self.tid_to_link_mapping_negotiation & 0b11) as u16) << 5;
+        bits |= ((self.frequency_separation_for_str & 0b1_1111) as u16) << 7;
+        bits |= (self.aar_support as u16) << 12;
+        bits |= (self.link_reconfiguration_support as u16) << 13;
+        bits |= (self.aligned_twt_support as u16) << 14;
+
+        bits.to_le_bytes()
+    }
+}
+
+/// A Per-STA Profile subelement of a Basic Multi-Link element.
+///
+/// It describes one of the other affiliated links of the MLD.
+/// Elements that aren't contained in `station_info` are inherited from the frame that
+/// carries the Multi-Link element, see [StationInfo::with_inherited].
+#[derive(Clone, Debug)]
+pub struct PerStaProfile {
+    pub link_id: u8,
+    pub complete_profile: bool,
+    pub sta_mac_address: Option<MacAddress>,
+    pub beacon_interval: Option<u16>,
+    pub tsf_offset: Option<u64>,
+    /// (DTIM count, DTIM period)
+    pub dtim_info: Option<(u8, u8)>,
+    /// The NSTR Indication Bitmap, which is either 1 or 2 bytes long.
+    pub nstr_indication_bitmap: Option<Vec<u8>>,
+    pub bss_parameters_change_count: Option<u8>,
+    /// The Capability Information field, which is only present in complete profiles.
+    pub capability_info: Option<u16>,
+    pub station_info: StationInfo,
+}
+
+impl PerStaProfile {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut control: u16 = (self.link_id & 0x0f) as u16;
+        control |= (self.complete_profile as u16) << 4;
+        control |= (self.sta_mac_address.is_some() as u16) << 5;
+        control |= (self.beacon_interval.is_some() as u16) << 6;
+        control |= (self.tsf_offset.is_some() as u16) << 7;
+        control |= (self.dtim_info.is_some() as u16) << 8;
+        if let Some(bitmap) = &self.nstr_indication_bitmap {
+            control |= 1 << 9;
+            control |= ((bitmap.len() > 1) as u16) << 10;
+        }
+        control |= (self.bss_parameters_change_count.is_some() as u16) << 11;
+
+        let mut sta_info = Vec::new();
+        if let Some(address) = &self.sta_mac_address {
+            sta_info.extend_from_slice(&address.encode());
+        }
+        if let Some(interval) = self.beacon_interval {
+            sta_info.extend_from_slice(&interval.to_le_bytes());
+        }
+        if let Some(offset) = self.tsf_offset {
+            sta_info.extend_from_slice(&offset.to_le_bytes());
+        }
+        if let Some((count, period)) = self.dtim_info {
+            sta_info.push(count);
+            sta_info.push(period);
+        }
+        if let Some(bitmap) = &self.nstr_indication_bitmap {
+            sta_info.extend(bitmap);
+        }
+        if let Some(count) = self.bss_parameters_change_count {
+            sta_info.push(count);
+        }
+
+        let mut bytes = control.to_le_bytes().to_vec();
+        // The STA info length includes the length byte itself.
+        bytes.push(sta_info.len() as u8 + 1);
+        bytes.extend(sta_info);
+
+        if let Some(capability_info) = self.capability_info {
+            bytes.extend_from_slice(&capability_info.to_le_bytes());
+        }
+        bytes.extend(self.station_info.encode());
+
+        bytes
+    }
+}
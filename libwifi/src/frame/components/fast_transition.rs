@@ -0,0 +1,243 @@
+use super::MacAddress;
+
+/// The Mobility Domain element (Element ID 54), which is used for 802.11r Fast BSS Transition.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MobilityDomain {
+    /// The Mobility Domain Identifier, which is shared by all APs of the mobility domain.
+    pub mdid: u16,
+    pub ft_capability_policy: FtCapabilityPolicy,
+}
+
+impl MobilityDomain {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.mdid.to_le_bytes().to_vec();
+        bytes.push(self.ft_capability_policy.encode());
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FtCapabilityPolicy {
+    pub ft_over_ds: bool,                        // bit 0
+    pub resource_request_protocol_capable: bool, // 1
+    /// All other bits, which are currently reserved.
+    pub reserved: u8, // 2-7
+}
+
+impl FtCapabilityPolicy {
+    pub fn from_bits(bits: u8) -> Self {
+        FtCapabilityPolicy {
+            ft_over_ds: bits & 1 != 0,
+            resource_request_protocol_capable: bits & (1 << 1) != 0,
+            reserved: bits >> 2,
+        }
+    }
+
+    pub fn encode(&self) -> u8 {
+        let mut bits = self.ft_over_ds as u8;
+        bits |= (self.resource_request_protocol_capable as u8) << 1;
+        bits |= self.reserved << 2;
+
+        bits
+    }
+}
+
+/// The Fast BSS Transition element (FTE, Element ID 55).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FastTransition {
+    pub mic_control: FtMicControl,
+    /// Either 16, 24 or 32 bytes, depending on the MIC Length subfield.
+    pub mic: Vec<u8>,
+    pub anonce: [u8; 32],
+    pub snonce: [u8; 32],
+    pub subelements: Vec<FtSubelement>,
+}
+
+impl FastTransition {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.mic_control.encode().to_vec();
+        bytes.extend(&self.mic);
+        bytes.extend(self.anonce);
+        bytes.extend(self.snonce);
+        for subelement in &self.subelements {
+            bytes.extend(subelement.encode());
+        }
+
+        bytes
+    }
+
+    /// Get the R0KH-ID (usually the NAS identifier of the R0 key holder).
+    pub fn r0kh_id(&self) -> Option<&[u8]> {
+        self.subelements
+            .iter()
+            .find_map(|subelement| match subelement {
+                FtSubelement::R0khId(id) => Some(id.as_slice()),
+                _ => None,
+            })
+    }
+
+    /// Get the R1KH-ID (usually the BSSID of the AP).
+    pub fn r1kh_id(&self) -> Option<&MacAddress> {
+        self.subelements
+            .iter()
+            .find_map(|subelement| match subelement {
+                FtSubelement::R1khId(id) => Some(id),
+                _ => None,
+            })
+    }
+}
+
+/// The MIC Control field of the FTE.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FtMicControl {
+    pub rsnxe_used: bool, // bit 0
+    /// `0` for a 16 byte MIC, `1` for 24 bytes and `2` for 32 bytes.
+    pub mic_length: u8, // 1-3
+    pub reserved: u8,     // 4-7
+    /// The number of elements that are protected by the MIC.
+    pub element_count: u8, // 8-15
+}
+
+impl FtMicControl {
+    pub fn from_bytes(bytes: [u8; 2]) -> Self {
+        FtMicControl {
+            rsnxe_used: bytes[0] & 1 != 0,
+            mic_length: (bytes[0] >> 1) & 0b111,
+            reserved: bytes[0] >> 4,
+            element_count: bytes[1],
+        }
+    }
+
+    pub fn encode(&self) -> [u8; 2] {
+        let mut first = self.rsnxe_used as u8;
+        first |= (self.mic_length & 0b111) << 1;
+        first |= self.reserved << 4;
+
+        [first, self.element_count]
+    }
+
+    /// The length of the MIC field in bytes.
+    pub fn mic_len(&self) -> usize {
+        match self.mic_length {
+            1 => 24,
+            2 => 32,
+            _ => 16,
+        }
+    }
+}
+
+/// The subelements of the FTE.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FtSubelement {
+    /// Subelement ID 1
+    R1khId(MacAddress),
+    /// Subelement ID 2
+    Gtk {
+        key_info: u16,
+        key_length: u8,
+        rsc: [u8; 8],
+        wrapped_key: Vec<u8>,
+    },
+    /// Subelement ID 3
+    R0khId(Vec<u8>),
+    /// Subelement ID 4
+    Igtk {
+        key_id: u16,
+        ipn: [u8; 6],
+        key_length: u8,
+        wrapped_key: Vec<u8>,
+    },
+    /// All other (or malformed) subelements in their raw form.
+    Unknown { id: u8, data: Vec<u8> },
+}
+
+impl FtSubelement {
+    pub fn encode(&self) -> Vec<u8> {
+        let (id, data) = match self {
+            FtSubelement::R1khId(id) => (1, id.encode().to_vec()),
+            FtSubelement::Gtk {
+                key_info,
+                key_length,
+                rsc,
+                wrapped_key,
+            } => {
+                let mut data = key_info.to_le_bytes().to_vec();
+                data.push(*key_length);
+                data.extend(rsc);
+                data.extend(wrapped_key);
+                (2, data)
+            }
+            FtSubelement::R0khId(id) => (3, id.clone()),
+            FtSubelement::Igtk {
+                key_id,
+                ipn,
+                key_length,
+                wrapped_key,
+            } => {
+                let mut data = key_id.to_le_bytes().to_vec();
+                data.extend(ipn);
+                data.push(*key_length);
+                data.extend(wrapped_key);
+                (4, data)
+            }
+            FtSubelement::Unknown { id, data } => (*id, data.clone()),
+        };
+
+        let mut bytes = vec![id, data.len() as u8];
+        bytes.extend(data);
+        bytes
+    }
+}
+
+/// The Timeout Interval element (Element ID 56).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimeoutInterval {
+    pub interval_type: TimeoutIntervalType,
+    /// The unit depends on the interval type.
+    pub value: u32,
+}
+
+impl TimeoutInterval {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.interval_type.encode()];
+        bytes.extend(self.value.to_le_bytes());
+        bytes
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeoutIntervalType {
+    /// The reassociation deadline in TUs.
+    ReassociationDeadline,
+    /// The key lifetime in seconds.
+    KeyLifetime,
+    /// The association comeback time in TUs.
+    AssociationComebackTime,
+    /// The time to start in TUs.
+    TimeToStart,
+    Unknown(u8),
+}
+
+impl From<u8> for TimeoutIntervalType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => TimeoutIntervalType::ReassociationDeadline,
+            2 => TimeoutIntervalType::KeyLifetime,
+            3 => TimeoutIntervalType::AssociationComebackTime,
+            4 => TimeoutIntervalType::TimeToStart,
+            other => TimeoutIntervalType::Unknown(other),
+        }
+    }
+}
+
+impl TimeoutIntervalType {
+    pub fn encode(&self) -> u8 {
+        match self {
+            TimeoutIntervalType::ReassociationDeadline => 1,
+            TimeoutIntervalType::KeyLifetime => 2,
+            TimeoutIntervalType::AssociationComebackTime => 3,
+            TimeoutIntervalType::TimeToStart => 4,
+            TimeoutIntervalType::Unknown(value) => *value,
+        }
+    }
+}
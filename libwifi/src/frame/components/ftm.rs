@@ -0,0 +1,60 @@
+/// The Fine Timing Measurement Parameters element (Element ID 206), which is used to negotiate
+/// the schedule of an FTM session.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FtmParameters {
+    /// `1`: successful, `2`: request incapable, `3`: request failed, retry after [Self::value]
+    /// seconds. Reserved in FTM Requests.
+    pub status_indication: u8, // bit 0-1
+    pub value: u8,    // 2-6
+    pub reserved: u8, // 7
+    /// The number of bursts is `2^number_of_bursts_exponent`.
+    pub number_of_bursts_exponent: u8, // 8-11
+    /// The burst duration in units of `250µs * 2^(burst_duration - 2)`.
+    /// `15` indicates no preference.
+    pub burst_duration: u8, // 12-15
+    /// The minimum time between consecutive FTM frames in units of 100µs.
+    pub min_delta_ftm: u8, // 16-23
+    pub partial_tsf_timer: u16, // 24-39
+    pub partial_tsf_timer_no_preference: bool, // 40
+    pub asap_capable: bool, // 41
+    /// The first burst starts as soon as possible.
+    pub asap: bool, // 42
+    pub ftms_per_burst: u8, // 43-47
+    pub reserved_2: u8, // 48-49
+    pub format_and_bandwidth: u8, // 50-55
+    /// The interval between two bursts in units of 100ms.
+    pub burst_period: u16, // 56-71
+}
+
+impl FtmParameters {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![
+            (self.status_indication & 0b11) | ((self.value & 0x1f) << 2) | (self.reserved << 7),
+            (self.number_of_bursts_exponent & 0x0f) | (self.burst_duration << 4),
+            self.min_delta_ftm,
+        ];
+        bytes.extend(self.partial_tsf_timer.to_le_bytes());
+        bytes.push(
+            self.partial_tsf_timer_no_preference as u8
+                | ((self.asap_capable as u8) << 1)
+                | ((self.asap as u8) << 2)
+                | (self.ftms_per_burst << 3),
+        );
+        bytes.push((self.reserved_2 & 0b11) | (self.format_and_bandwidth << 2));
+        bytes.extend(self.burst_period.to_le_bytes());
+        bytes
+    }
+
+    /// The number of bursts of the session.
+    pub fn number_of_bursts(&self) -> u32 {
+        1 << self.number_of_bursts_exponent
+    }
+
+    /// The burst duration in microseconds, or `None` if there's no preference.
+    pub fn burst_duration_us(&self) -> Option<u32> {
+        match self.burst_duration {
+            2..=11 => Some(250 << (self.burst_duration - 2)),
+            _ => None,
+        }
+    }
+}
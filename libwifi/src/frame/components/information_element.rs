@@ -0,0 +1,71 @@
+use super::StationInfo;
+use crate::parsers::decode_ie;
+
+/// A single element in the form in which it has been received.
+///
+/// [StationInfo::elements] contains all elements of a parsed frame in their original order,
+/// which allows to re-encode them byte by byte via [StationInfo::encode_elements], including
+/// elements that aren't decoded by this library.
+///
+/// The decoded value isn't duplicated here, it's stored in the respective field of the
+/// [StationInfo] that contains this element.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InformationElement {
+    pub id: u8,
+    /// The extension ID of elements with the ID 255.
+    pub extension_id: Option<u8>,
+    /// The raw payload of the element.
+    /// For extension elements this doesn't include the extension ID.
+    pub data: Vec<u8>,
+    /// Whether the element has been decoded into a field of the [StationInfo].
+    pub decoded: bool,
+}
+
+impl InformationElement {
+    /// Create an element from its ID and its complete payload.
+    ///
+    /// For elements with the ID 255, the first byte of `data` is the extension ID.
+    /// The element is marked as not decoded.
+    pub fn new(id: u8, data: &[u8]) -> InformationElement {
+        match (id, data.split_first()) {
+            (255, Some((extension_id, data))) => InformationElement {
+                id,
+                extension_id: Some(*extension_id),
+                data: data.to_vec(),
+                decoded: false,
+            },
+            _ => InformationElement {
+                id,
+                extension_id: None,
+                data: data.to_vec(),
+                decoded: false,
+            },
+        }
+    }
+
+    /// The payload as it's sent over the air, i.e. including the extension ID.
+    pub fn payload(&self) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(self.data.len() + 1);
+        payload.extend(self.extension_id);
+        payload.extend_from_slice(&self.data);
+        payload
+    }
+
+    /// Encode the element including its header.
+    pub fn encode(&self) -> Vec<u8> {
+        let payload = self.payload();
+        let mut bytes = Vec::with_capacity(payload.len() + 2);
+        bytes.push(self.id);
+        bytes.push(payload.len() as u8);
+        bytes.extend(payload);
+        bytes
+    }
+
+    /// Decode this element on its own, e.g. after it has been modified.
+    ///
+    /// The returned [StationInfo] only has the fields set that belong to this element.
+    /// Returns `None` if the element isn't decoded by any built-in or custom parser.
+    pub fn decode(&self) -> Option<StationInfo> {
+        decode_ie(self.id, self.extension_id, &self.data)
+    }
+}
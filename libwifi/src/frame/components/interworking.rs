@@ -0,0 +1,228 @@
+use super::MacAddress;
+
+/// The Interworking element (Element ID 107), which is sent by Passpoint/Hotspot 2.0 APs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Interworking {
+    pub access_network_type: AccessNetworkType, // bit 0-3
+    pub internet: bool,                         // 4
+    /// Additional Step Required for Access
+    pub asra: bool, // 5
+    /// Emergency Services Reachable
+    pub esr: bool, // 6
+    /// Unauthenticated Emergency Service Accessible
+    pub uesa: bool, // 7
+    pub venue_info: Option<VenueInfo>,
+    /// The Homogeneous ESS identifier.
+    pub hessid: Option<MacAddress>,
+}
+
+impl Interworking {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut options = self.access_network_type.encode() & 0x0f;
+        options |= (self.internet as u8) << 4;
+        options |= (self.asra as u8) << 5;
+        options |= (self.esr as u8) << 6;
+        options |= (self.uesa as u8) << 7;
+
+        let mut bytes = vec![options];
+        if let Some(venue_info) = &self.venue_info {
+            bytes.extend([venue_info.group, venue_info.venue_type]);
+        }
+        if let Some(hessid) = &self.hessid {
+            bytes.extend(hessid.encode());
+        }
+
+        bytes
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessNetworkType {
+    PrivateNetwork,
+    PrivateNetworkWithGuestAccess,
+    ChargeablePublicNetwork,
+    FreePublicNetwork,
+    PersonalDeviceNetwork,
+    EmergencyServicesOnlyNetwork,
+    TestOrExperimental,
+    Wildcard,
+    Unknown(u8),
+}
+
+impl From<u8> for AccessNetworkType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => AccessNetworkType::PrivateNetwork,
+            1 => AccessNetworkType::PrivateNetworkWithGuestAccess,
+            2 => AccessNetworkType::ChargeablePublicNetwork,
+            3 => AccessNetworkType::FreePublicNetwork,
+            4 => AccessNetworkType::PersonalDeviceNetwork,
+            5 => AccessNetworkType::EmergencyServicesOnlyNetwork,
+            14 => AccessNetworkType::TestOrExperimental,
+            15 => AccessNetworkType::Wildcard,
+            other => AccessNetworkType::Unknown(other),
+        }
+    }
+}
+
+impl AccessNetworkType {
+    pub fn encode(&self) -> u8 {
+        match self {
+            AccessNetworkType::PrivateNetwork => 0,
+            AccessNetworkType::PrivateNetworkWithGuestAccess => 1,
+            AccessNetworkType::ChargeablePublicNetwork => 2,
+            AccessNetworkType::FreePublicNetwork => 3,
+            AccessNetworkType::PersonalDeviceNetwork => 4,
+            AccessNetworkType::EmergencyServicesOnlyNetwork => 5,
+            AccessNetworkType::TestOrExperimental => 14,
+            AccessNetworkType::Wildcard => 15,
+            AccessNetworkType::Unknown(value) => *value,
+        }
+    }
+}
+
+/// The venue group and type, e.g. group `2` (Business) and type `1` (Doctor or Dentist office).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VenueInfo {
+    pub group: u8,
+    pub venue_type: u8,
+}
+
+/// The Advertisement Protocol element (Element ID 108).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AdvertisementProtocol {
+    pub tuples: Vec<AdvertisementProtocolTuple>,
+}
+
+impl AdvertisementProtocol {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for tuple in &self.tuples {
+            bytes.extend(tuple.encode());
+        }
+
+        bytes
+    }
+
+    /// Check whether ANQP is supported, which is used to query Passpoint information.
+    pub fn supports_anqp(&self) -> bool {
+        self.tuples
+            .iter()
+            .any(|tuple| tuple.protocol == AdvertisementProtocolId::Anqp)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdvertisementProtocolTuple {
+    pub query_response_length_limit: u8, // bit 0-6
+    /// Pre-Association Message Exchange BSSID Independent
+    pub pame_bi: bool, // 7
+    pub protocol: AdvertisementProtocolId,
+}
+
+impl AdvertisementProtocolTuple {
+    pub fn encode(&self) -> Vec<u8> {
+        let query_response_info =
+            (self.query_response_length_limit & 0x7f) | ((self.pame_bi as u8) << 7);
+
+        let mut bytes = vec![query_response_info];
+        match &self.protocol {
+            AdvertisementProtocolId::VendorSpecific(data) => {
+                bytes.extend([221, data.len() as u8]);
+                bytes.extend(data);
+            }
+            protocol => bytes.push(protocol.id()),
+        }
+
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AdvertisementProtocolId {
+    Anqp,
+    MihInformationService,
+    MihCommandAndEventServicesCapabilityDiscovery,
+    EmergencyAlertSystem,
+    RegisteredLocationQueryProtocol,
+    /// The payload of a vendor specific advertisement protocol, starting with the OUI.
+    VendorSpecific(Vec<u8>),
+    Unknown(u8),
+}
+
+impl AdvertisementProtocolId {
+    pub fn id(&self) -> u8 {
+        match self {
+            AdvertisementProtocolId::Anqp => 0,
+            AdvertisementProtocolId::MihInformationService => 1,
+            AdvertisementProtocolId::MihCommandAndEventServicesCapabilityDiscovery => 2,
+            AdvertisementProtocolId::EmergencyAlertSystem => 3,
+            AdvertisementProtocolId::RegisteredLocationQueryProtocol => 4,
+            AdvertisementProtocolId::VendorSpecific(_) => 221,
+            AdvertisementProtocolId::Unknown(id) => *id,
+        }
+    }
+}
+
+/// The Roaming Consortium element (Element ID 111).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RoamingConsortium {
+    /// The number of additional OIs that can be queried via ANQP.
+    pub anqp_oi_count: u8,
+    /// Up to three Organization Identifiers, which are usually 3 or 5 bytes long.
+    pub ois: Vec<Vec<u8>>,
+}
+
+impl RoamingConsortium {
+    pub fn encode(&self) -> Vec<u8> {
+        let oi_length = |index: usize| self.ois.get(index).map_or(0, |oi| oi.len() as u8);
+
+        let mut bytes = vec![
+            self.anqp_oi_count,
+            (oi_length(0) & 0x0f) | (oi_length(1) << 4),
+        ];
+        for oi in &self.ois {
+            bytes.extend(oi);
+        }
+
+        bytes
+    }
+}
+
+/// The WFA Hotspot 2.0 Indication vendor specific element (OUI 50:6f:9a, type 0x10).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Hs20Indication {
+    /// Downstream Group-Addressed Forwarding disabled
+    pub dgaf_disabled: bool, // bit 0
+    pub reserved: bool, // 3
+    /// The raw release number field, see [Hs20Indication::release].
+    pub release_number: u8, // 4-7
+    pub pps_mo_id: Option<u16>,
+    pub anqp_domain_id: Option<u16>,
+}
+
+impl Hs20Indication {
+    /// Encode the element payload, starting with the OUI.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut configuration = self.dgaf_disabled as u8;
+        configuration |= (self.pps_mo_id.is_some() as u8) << 1;
+        configuration |= (self.anqp_domain_id.is_some() as u8) << 2;
+        configuration |= (self.reserved as u8) << 3;
+        configuration |= self.release_number << 4;
+
+        let mut bytes = vec![0x50, 0x6f, 0x9a, 0x10, configuration];
+        if let Some(pps_mo_id) = self.pps_mo_id {
+            bytes.extend(pps_mo_id.to_le_bytes());
+        }
+        if let Some(anqp_domain_id) = self.anqp_domain_id {
+            bytes.extend(anqp_domain_id.to_le_bytes());
+        }
+
+        bytes
+    }
+
+    /// The Hotspot 2.0 release, e.g. `3` for Release 3.
+    pub fn release(&self) -> u8 {
+        self.release_number + 1
+    }
+}
@@ -0,0 +1,512 @@
+use super::MacAddress;
+
+/// The Mesh Configuration element (Element ID 113), which is sent by 802.11s mesh stations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MeshConfiguration {
+    pub path_selection_protocol: PathSelectionProtocol,
+    pub path_selection_metric: PathSelectionMetric,
+    pub congestion_control_mode: CongestionControlMode,
+    pub synchronization_method: SynchronizationMethod,
+    pub authentication_protocol: MeshAuthenticationProtocol,
+    pub formation_info: MeshFormationInfo,
+    pub capability: MeshCapability,
+}
+
+impl MeshConfiguration {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![
+            self.path_selection_protocol.encode(),
+            self.path_selection_metric.encode(),
+            self.congestion_control_mode.encode(),
+            self.synchronization_method.encode(),
+            self.authentication_protocol.encode(),
+            self.formation_info.encode(),
+            self.capability.encode(),
+        ]
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathSelectionProtocol {
+    /// Hybrid Wireless Mesh Protocol, the default path selection protocol.
+    Hwmp,
+    VendorSpecific,
+    Unknown(u8),
+}
+
+impl From<u8> for PathSelectionProtocol {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => PathSelectionProtocol::Hwmp,
+            255 => PathSelectionProtocol::VendorSpecific,
+            other => PathSelectionProtocol::Unknown(other),
+        }
+    }
+}
+
+impl PathSelectionProtocol {
+    pub fn encode(&self) -> u8 {
+        match self {
+            PathSelectionProtocol::Hwmp => 1,
+            PathSelectionProtocol::VendorSpecific => 255,
+            PathSelectionProtocol::Unknown(value) => *value,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathSelectionMetric {
+    /// The airtime link metric, the default path selection metric.
+    Airtime,
+    VendorSpecific,
+    Unknown(u8),
+}
+
+impl From<u8> for PathSelectionMetric {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => PathSelectionMetric::Airtime,
+            255 => PathSelectionMetric::VendorSpecific,
+            other => PathSelectionMetric::Unknown(other),
+        }
+    }
+}
+
+impl PathSelectionMetric {
+    pub fn encode(&self) -> u8 {
+        match self {
+            PathSelectionMetric::Airtime => 1,
+            PathSelectionMetric::VendorSpecific => 255,
+            PathSelectionMetric::Unknown(value) => *value,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CongestionControlMode {
+    /// Congestion control isn't active.
+    Null,
+    /// Congestion Control Signaling Protocol
+    Signaling,
+    VendorSpecific,
+    Unknown(u8),
+}
+
+impl From<u8> for CongestionControlMode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => CongestionControlMode::Null,
+            1 => CongestionControlMode::Signaling,
+            255 => CongestionControlMode::VendorSpecific,
+            other => CongestionControlMode::Unknown(other),
+        }
+    }
+}
+
+impl CongestionControlMode {
+    pub fn encode(&self) -> u8 {
+        match self {
+            CongestionControlMode::Null => 0,
+            CongestionControlMode::Signaling => 1,
+            CongestionControlMode::VendorSpecific => 255,
+            CongestionControlMode::Unknown(value) => *value,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SynchronizationMethod {
+    /// Neighbor offset synchronization, the default synchronization method.
+    NeighborOffset,
+    VendorSpecific,
+    Unknown(u8),
+}
+
+impl From<u8> for SynchronizationMethod {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => SynchronizationMethod::NeighborOffset,
+            255 => SynchronizationMethod::VendorSpecific,
+            other => SynchronizationMethod::Unknown(other),
+        }
+    }
+}
+
+impl SynchronizationMethod {
+    pub fn encode(&self) -> u8 {
+        match self {
+            SynchronizationMethod::NeighborOffset => 1,
+            SynchronizationMethod::VendorSpecific => 255,
+            SynchronizationMethod::Unknown(value) => *value,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MeshAuthenticationProtocol {
+    /// No authentication is required (open mesh).
+    None,
+    Sae,
+    Ieee8021X,
+    VendorSpecific,
+    Unknown(u8),
+}
+
+impl From<u8> for MeshAuthenticationProtocol {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => MeshAuthenticationProtocol::None,
+            1 => MeshAuthenticationProtocol::Sae,
+            2 => MeshAuthenticationProtocol::Ieee8021X,
+            255 => MeshAuthenticationProtocol::VendorSpecific,
+            other => MeshAuthenticationProtocol::Unknown(other),
+        }
+    }
+}
+
+impl MeshAuthenticationProtocol {
+    pub fn encode(&self) -> u8 {
+        match self {
+            MeshAuthenticationProtocol::None => 0,
+            MeshAuthenticationProtocol::Sae => 1,
+            MeshAuthenticationProtocol::Ieee8021X => 2,
+            MeshAuthenticationProtocol::VendorSpecific => 255,
+            MeshAuthenticationProtocol::Unknown(value) => *value,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MeshFormationInfo {
+    pub connected_to_mesh_gate: bool, // bit 0
+    pub number_of_peerings: u8,       // 1-6
+    pub connected_to_as: bool,        // 7
+}
+
+impl MeshFormationInfo {
+    pub fn from_bits(bits: u8) -> Self {
+        MeshFormationInfo {
+            connected_to_mesh_gate: bits & 1 != 0,
+            number_of_peerings: (bits >> 1) & 0b11_1111,
+            connected_to_as: bits & (1 << 7) != 0,
+        }
+    }
+
+    pub fn encode(&self) -> u8 {
+        let mut bits = self.connected_to_mesh_gate as u8;
+        bits |= (self.number_of_peerings & 0b11_1111) << 1;
+        bits |= (self.connected_to_as as u8) << 7;
+
+        bits
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MeshCapability {
+    pub accepting_additional_peerings: bool, // bit 0
+    pub mcca_supported: bool,                // 1
+    pub mcca_enabled: bool,                  // 2
+    pub forwarding: bool,                    // 3
+    pub mbca_enabled: bool,                  // 4
+    pub tbtt_adjusting: bool,                // 5
+    pub mesh_power_save_level: bool,         // 6
+    pub reserved: bool,                      // 7
+}
+
+impl MeshCapability {
+    pub fn from_bits(bits: u8) -> Self {
+        MeshCapability {
+            accepting_additional_peerings: bits & 1 != 0,
+            mcca_supported: bits & (1 << 1) != 0,
+            mcca_enabled: bits & (1 << 2) != 0,
+            forwarding: bits & (1 << 3) != 0,
+            mbca_enabled: bits & (1 << 4) != 0,
+            tbtt_adjusting: bits & (1 << 5) != 0,
+            mesh_power_save_level: bits & (1 << 6) != 0,
+            reserved: bits & (1 << 7) != 0,
+        }
+    }
+
+    pub fn encode(&self) -> u8 {
+        let mut bits = self.accepting_additional_peerings as u8;
+        bits |= (self.mcca_supported as u8) << 1;
+        bits |= (self.mcca_enabled as u8) << 2;
+        bits |= (self.forwarding as u8) << 3;
+        bits |= (self.mbca_enabled as u8) << 4;
+        bits |= (self.tbtt_adjusting as u8) << 5;
+        bits |= (self.mesh_power_save_level as u8) << 6;
+        bits |= (self.reserved as u8) << 7;
+
+        bits
+    }
+}
+
+/// The Mesh Peering Management element (Element ID 117), which is sent in Mesh Peering
+/// Open, Confirm and Close frames.
+///
+/// The layout depends on the frame it's sent in. A single trailing 2 byte field is the
+/// `peer_link_id` in Mesh Peering Open and Confirm frames, but the `reason_code` in Mesh Peering
+/// Close frames.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MeshPeeringManagement {
+    /// `0` for the Mesh Peering Management protocol, `1` for the Authenticated Mesh Peering
+    /// Exchange (AMPE) protocol.
+    pub protocol: u16,
+    pub local_link_id: u16,
+    pub peer_link_id: Option<u16>,
+    /// Only sent in Mesh Peering Close frames.
+    pub reason_code: Option<u16>,
+    /// Only sent when AMPE is used.
+    pub chosen_pmk: Option<[u8; 16]>,
+}
+
+impl MeshPeeringManagement {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.protocol.to_le_bytes().to_vec();
+        bytes.extend(self.local_link_id.to_le_bytes());
+        if let Some(peer_link_id) = self.peer_link_id {
+            bytes.extend(peer_link_id.to_le_bytes());
+        }
+        if let Some(reason_code) = self.reason_code {
+            bytes.extend(reason_code.to_le_bytes());
+        }
+        if let Some(chosen_pmk) = self.chosen_pmk {
+            bytes.extend(chosen_pmk);
+        }
+
+        bytes
+    }
+}
+
+/// The flags of the HWMP path selection elements.
+///
+/// Not every flag is used by every element, e.g. only the Path Request uses the addressing
+/// mode and proactive PREP flags.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HwmpFlags {
+    /// Set if the originator is a mesh gate.
+    pub gate_announcement: bool, // bit 0
+    /// Set if the element is sent to an individual address instead of a group address.
+    pub individual_addressing: bool, // 1
+    /// Set if the root requests a PREP in response to its proactive PREQ.
+    pub proactive_prep: bool, // 2
+    pub reserved: u8, // 3-5
+    /// Set if the element contains an external address.
+    pub address_extension: bool, // 6
+    pub reserved_2: bool, // 7
+}
+
+impl HwmpFlags {
+    pub fn from_bits(bits: u8) -> Self {
+        HwmpFlags {
+            gate_announcement: bits & 1 != 0,
+            individual_addressing: bits & (1 << 1) != 0,
+            proactive_prep: bits & (1 << 2) != 0,
+            reserved: (bits >> 3) & 0b111,
+            address_extension: bits & (1 << 6) != 0,
+            reserved_2: bits & (1 << 7) != 0,
+        }
+    }
+
+    pub fn encode(&self) -> u8 {
+        let mut bits = self.gate_announcement as u8;
+        bits |= (self.individual_addressing as u8) << 1;
+        bits |= (self.proactive_prep as u8) << 2;
+        bits |= (self.reserved & 0b111) << 3;
+        bits |= (self.address_extension as u8) << 6;
+        bits |= (self.reserved_2 as u8) << 7;
+
+        bits
+    }
+}
+
+/// The Gate Announcement (GANN) element (Element ID 125), which is flooded by mesh gates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GateAnnouncement {
+    pub flags: HwmpFlags,
+    pub hop_count: u8,
+    pub element_ttl: u8,
+    pub mesh_gate: MacAddress,
+    pub sequence_number: u32,
+    /// The interval between two announcements in TUs.
+    pub interval: u16,
+}
+
+impl GateAnnouncement {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.flags.encode(), self.hop_count, self.element_ttl];
+        bytes.extend(self.mesh_gate.encode());
+        bytes.extend(self.sequence_number.to_le_bytes());
+        bytes.extend(self.interval.to_le_bytes());
+        bytes
+    }
+}
+
+/// The Root Announcement (RANN) element (Element ID 126), which is flooded by the root mesh
+/// station of a proactive tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RootAnnouncement {
+    pub flags: HwmpFlags,
+    pub hop_count: u8,
+    pub element_ttl: u8,
+    pub root: MacAddress,
+    pub sequence_number: u32,
+    /// The interval between two announcements in TUs.
+    pub interval: u32,
+    /// The cumulative metric from the root to the sending mesh station.
+    pub metric: u32,
+}
+
+impl RootAnnouncement {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.flags.encode(), self.hop_count, self.element_ttl];
+        bytes.extend(self.root.encode());
+        bytes.extend(self.sequence_number.to_le_bytes());
+        bytes.extend(self.interval.to_le_bytes());
+        bytes.extend(self.metric.to_le_bytes());
+        bytes
+    }
+}
+
+/// The Path Request (PREQ) element (Element ID 130), which is used to discover a path to one
+/// or more targets.
+///
+/// The originator external address is only present if [HwmpFlags::address_extension] is set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PathRequest {
+    pub flags: HwmpFlags,
+    pub hop_count: u8,
+    pub element_ttl: u8,
+    pub path_discovery_id: u32,
+    pub originator: MacAddress,
+    pub originator_sequence_number: u32,
+    pub originator_external_address: Option<MacAddress>,
+    /// The time in TUs for which the path is valid.
+    pub lifetime: u32,
+    pub metric: u32,
+    pub targets: Vec<PathRequestTarget>,
+}
+
+impl PathRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.flags.encode(), self.hop_count, self.element_ttl];
+        bytes.extend(self.path_discovery_id.to_le_bytes());
+        bytes.extend(self.originator.encode());
+        bytes.extend(self.originator_sequence_number.to_le_bytes());
+        if let Some(external_address) = &self.originator_external_address {
+            bytes.extend(external_address.encode());
+        }
+        bytes.extend(self.lifetime.to_le_bytes());
+        bytes.extend(self.metric.to_le_bytes());
+        bytes.push(self.targets.len() as u8);
+        for target in &self.targets {
+            bytes.push(target.flags_bits());
+            bytes.extend(target.address.encode());
+            bytes.extend(target.sequence_number.to_le_bytes());
+        }
+
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PathRequestTarget {
+    /// Only the target may answer with a PREP, intermediate mesh stations must not.
+    pub target_only: bool, // bit 0
+    /// The target sequence number is unknown.
+    pub unknown_sequence_number: bool, // 2
+    /// The reserved bits 1 and 3-7 at their original positions.
+    pub reserved: u8,
+    pub address: MacAddress,
+    pub sequence_number: u32,
+}
+
+impl PathRequestTarget {
+    pub fn from_bits(bits: u8, address: MacAddress, sequence_number: u32) -> Self {
+        PathRequestTarget {
+            target_only: bits & 1 != 0,
+            unknown_sequence_number: bits & (1 << 2) != 0,
+            reserved: bits & !0b101,
+            address,
+            sequence_number,
+        }
+    }
+
+    /// The per target flags.
+    pub fn flags_bits(&self) -> u8 {
+        self.target_only as u8
+            | ((self.unknown_sequence_number as u8) << 2)
+            | (self.reserved & !0b101)
+    }
+}
+
+/// The Path Reply (PREP) element (Element ID 131), which is sent back to the originator of a
+/// [PathRequest].
+///
+/// The target external address is only present if [HwmpFlags::address_extension] is set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PathReply {
+    pub flags: HwmpFlags,
+    pub hop_count: u8,
+    pub element_ttl: u8,
+    pub target: MacAddress,
+    pub target_sequence_number: u32,
+    pub target_external_address: Option<MacAddress>,
+    /// The time in TUs for which the path is valid.
+    pub lifetime: u32,
+    pub metric: u32,
+    pub originator: MacAddress,
+    pub originator_sequence_number: u32,
+}
+
+impl PathReply {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.flags.encode(), self.hop_count, self.element_ttl];
+        bytes.extend(self.target.encode());
+        bytes.extend(self.target_sequence_number.to_le_bytes());
+        if let Some(external_address) = &self.target_external_address {
+            bytes.extend(external_address.encode());
+        }
+        bytes.extend(self.lifetime.to_le_bytes());
+        bytes.extend(self.metric.to_le_bytes());
+        bytes.extend(self.originator.encode());
+        bytes.extend(self.originator_sequence_number.to_le_bytes());
+        bytes
+    }
+}
+
+/// The Path Error (PERR) element (Element ID 132), which announces unreachable destinations.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PathError {
+    pub element_ttl: u8,
+    pub destinations: Vec<PathErrorDestination>,
+}
+
+impl PathError {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.element_ttl, self.destinations.len() as u8];
+        for destination in &self.destinations {
+            bytes.push(destination.flags.encode());
+            bytes.extend(destination.address.encode());
+            bytes.extend(destination.sequence_number.to_le_bytes());
+            if let Some(external_address) = &destination.external_address {
+                bytes.extend(external_address.encode());
+            }
+            bytes.extend(destination.reason_code.to_le_bytes());
+        }
+
+        bytes
+    }
+}
+
+/// An unreachable destination of a [PathError].
+///
+/// The external address is only present if [HwmpFlags::address_extension] is set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PathErrorDestination {
+    pub flags: HwmpFlags,
+    pub address: MacAddress,
+    pub sequence_number: u32,
+    pub external_address: Option<MacAddress>,
+    pub reason_code: u16,
+}
@@ -1,18 +1,102 @@
+mod anqp;
+mod bss_load;
+mod channel_switch;
+mod custom;
+mod eht;
+mod fast_transition;
 mod frame_control;
+mod ftm;
 mod header;
+mod information_element;
+mod interworking;
 mod mac_address;
+mod mesh;
+mod multiple_bssid;
+mod p2p;
+mod qos;
+mod radio_measurement;
+mod reduced_neighbor_report;
+mod security;
 mod sequence_control;
+mod spectrum_management;
 mod station_info;
+mod tdls;
+mod vendor;
+mod wnm;
 
+pub use anqp::{
+    AnqpElement, AuthenticationParameter, CellularNetwork, EapMethod, Hs20AnqpElement,
+    IpAddressTypeAvailability, LocalizedName, NaiRealm, NetworkAuthenticationIndicator,
+    NetworkAuthenticationType, Plmn, ProtocolPort, VenueName, WanMetrics,
+};
+pub use bss_load::{BssLoad, ErpInformation, ExtendedBssLoad};
+pub use channel_switch::{
+    ChannelSwitchTarget, ChannelSwitchWrapper, ChannelSwitchWrapperSubelement,
+    ExtendedChannelSwitchAnnouncement, WideBandwidthChannelSwitch, channel_to_frequency,
+    operating_class_bandwidth,
+};
+pub use custom::{CustomElement, CustomElements, ElementKey};
+pub use eht::{
+    BasicMultiLink, EhtCapabilities, EhtChannelWidth, EhtMacCapabilities, EhtOperation,
+    EhtOperationInformation, EhtPhyCapabilities, EmlCapabilities, MldCapabilities, MultiLink,
+    PerStaProfile,
+};
+pub use fast_transition::{
+    FastTransition, FtCapabilityPolicy, FtMicControl, FtSubelement, MobilityDomain,
+    TimeoutInterval, TimeoutIntervalType,
+};
 pub use frame_control::FrameControl;
+pub use ftm::FtmParameters;
 pub use header::*;
+pub use information_element::InformationElement;
+pub use interworking::{
+    AccessNetworkType, AdvertisementProtocol, AdvertisementProtocolId, AdvertisementProtocolTuple,
+    Hs20Indication, Interworking, RoamingConsortium, VenueInfo,
+};
 pub use mac_address::*;
+pub use mesh::{
+    CongestionControlMode, GateAnnouncement, HwmpFlags, MeshAuthenticationProtocol, MeshCapability,
+    MeshConfiguration, MeshFormationInfo, MeshPeeringManagement, PathError, PathErrorDestination,
+    PathReply, PathRequest, PathRequestTarget, PathSelectionMetric, PathSelectionProtocol,
+    RootAnnouncement, SynchronizationMethod,
+};
+pub use multiple_bssid::{MultipleBSSID, MultipleBssidIndex, NonInheritance, NontransmittedBss};
+pub use p2p::{
+    GroupOwnerIntent, NoticeOfAbsence, NoticeOfAbsenceDescriptor, P2pAttribute, P2pCapability,
+    P2pChannel, P2pChannelEntry, P2pChannelList, P2pClientInfo, P2pDeviceCapability, P2pDeviceInfo,
+    P2pGroupCapability,
+};
+pub use qos::{
+    AcParameters, AccessCategory, EdcaParameterSet, MuAcParameters, MuEdcaParameterSet, QosInfo,
+    Wmm,
+};
+pub use radio_measurement::{
+    ApReachability, BeaconMeasurementMode, BeaconReport, BeaconRequest, BssidInformation,
+    ChannelLoadReport, ChannelMeasurementRequest, LciReport, LciRequest, LocationCivicReport,
+    LocationCivicRequest, MeasurementReport, MeasurementReportMode, MeasurementReportType,
+    MeasurementRequest, MeasurementRequestMode, MeasurementRequestType, NeighborReport,
+    NoiseHistogramReport, StaStatisticsReport, StaStatisticsRequest, Subelement,
+};
+pub use reduced_neighbor_report::{
+    NeighborApInformation, ReducedNeighborReport, RnrBssParameters, RnrMldParameters,
+    TbttInformation,
+};
+pub use security::{RsnExtension, SaeCapabilities, SecurityProfile};
 pub use sequence_control::SequenceControl;
+pub use spectrum_management::{
+    ChannelSubband, PowerCapability, Quiet, SupportedChannels, TpcReport,
+};
 pub use station_info::{
     AudioDevices, Cameras, Category, ChannelSwitchAnnouncment, ChannelSwitchMode, Computers,
     Displays, DockingDevices, ExtendedCapabilities, GamingDevices, HTCapabilities, HTInformation,
-    InputDevices, MultimediaDevices, MultipleBSSID, NetworkInfrastructure, PrintersEtAl,
-    RsnAkmSuite, RsnCipherSuite, RsnInformation, RxStbc, SecondaryChannelOffset, SmPowerSave,
-    StationInfo, Storage, SupportedRate, Telephone, VHTCapabilities, VendorSpecificInfo,
-    WpaAkmSuite, WpaCipherSuite, WpaInformation, WpsInformation, WpsSetupState,
+    InputDevices, MultimediaDevices, NetworkInfrastructure, PrintersEtAl, RsnAkmSuite,
+    RsnCipherSuite, RsnInformation, RxStbc, SecondaryChannelOffset, SmPowerSave, StationInfo,
+    Storage, SupportedRate, Telephone, VHTCapabilities, VendorSpecificInfo, WpaAkmSuite,
+    WpaCipherSuite, WpaInformation, WpsInformation, WpsSetupState,
+};
+pub use tdls::LinkIdentifier;
+pub use vendor::{
+    APPLE_OUI, ARUBA_OUI, AppleDeviceInfo, CISCO_OUI, DecodedVendorElement, MultiAp,
+    OweTransitionMode, WFA_OUI, WfdDeviceInformation, WfdSubelement,
 };
+pub use wnm::{BssTerminationDuration, TfsRequestElement, TfsResponseElement, WnmSleepMode};
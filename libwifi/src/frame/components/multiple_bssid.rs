@@ -0,0 +1,114 @@
+use super::{MacAddress, StationInfo};
+
+/// The Multiple BSSID element (Element ID 71).
+///
+/// A single AP may host multiple virtual APs (BSSes), while only sending beacons for the
+/// transmitted BSSID. All other (nontransmitted) BSSes are described by profiles inside of
+/// this element.
+#[derive(Debug, Clone)]
+pub struct MultipleBSSID {
+    /// The maximum number of BSSIDs is `2^max_bssid_indicator`.
+    pub max_bssid_indicator: u8,
+    /// The Nontransmitted BSSID Profile subelements.
+    ///
+    /// Each profile contains the elements that differ from the transmitted BSS, usually
+    /// the Nontransmitted BSSID Capability, SSID and Multiple BSSID-Index elements.
+    pub profiles: Vec<StationInfo>,
+    /// All other subelements (e.g. vendor specific) in their raw form.
+    pub other_data: Vec<u8>,
+}
+
+impl MultipleBSSID {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.max_bssid_indicator);
+        for profile in &self.profiles {
+            let profile_data = profile.encode();
+            data.push(0); // Nontransmitted BSSID Profile subelement ID
+            data.push(profile_data.len() as u8);
+            data.extend(profile_data);
+        }
+        data.extend(&self.other_data);
+        data
+    }
+
+    /// Compute the BSSID of the nontransmitted BSS with the given BSSID index.
+    ///
+    /// The `n = max_bssid_indicator` least significant bits of the transmitted BSSID are
+    /// replaced by `(transmitted_lsbs + bssid_index) mod 2^n`.
+    pub fn bssid(&self, transmitted_bssid: &MacAddress, bssid_index: u8) -> MacAddress {
+        let transmitted = transmitted_bssid.to_u64();
+        let mask = (1u64 << self.max_bssid_indicator.min(48)) - 1;
+        let lsbs = (transmitted & mask).wrapping_add(bssid_index as u64) & mask;
+        let bssid = (transmitted & !mask) | lsbs;
+
+        let bytes = bssid.to_be_bytes();
+        MacAddress([bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]])
+    }
+}
+
+/// The Multiple BSSID-Index element (Element ID 85), which is sent inside of a
+/// Nontransmitted BSSID Profile.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MultipleBssidIndex {
+    pub bssid_index: u8,
+    /// Only present in beacons.
+    pub dtim_period: Option<u8>,
+    /// Only present in beacons.
+    pub dtim_count: Option<u8>,
+}
+
+impl MultipleBssidIndex {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = vec![self.bssid_index];
+        if let Some(dtim_period) = self.dtim_period {
+            data.push(dtim_period);
+            if let Some(dtim_count) = self.dtim_count {
+                data.push(dtim_count);
+            }
+        }
+        data
+    }
+}
+
+/// The Non-Inheritance element (Element ID 255, Extension ID 56).
+///
+/// It lists all elements of the transmitting frame that must **not** be inherited by a
+/// nontransmitted BSSID profile or a per-STA profile.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NonInheritance {
+    pub element_ids: Vec<u8>,
+    pub extension_element_ids: Vec<u8>,
+}
+
+impl NonInheritance {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = vec![self.element_ids.len() as u8];
+        data.extend(&self.element_ids);
+        data.push(self.extension_element_ids.len() as u8);
+        data.extend(&self.extension_element_ids);
+        data
+    }
+
+    /// Check whether an element (and extension element id for element 255) is excluded.
+    pub fn excludes(&self, element_id: u8, extension_element_id: Option<u8>) -> bool {
+        match extension_element_id {
+            Some(extension_id) if element_id == 255 => {
+                self.extension_element_ids.contains(&extension_id)
+            }
+            _ => self.element_ids.contains(&element_id),
+        }
+    }
+}
+
+/// A nontransmitted BSS (virtual AP) that has been expanded from a Multiple BSSID element.
+#[derive(Debug, Clone)]
+pub struct NontransmittedBss {
+    pub bssid: MacAddress,
+    pub bssid_index: u8,
+    /// The capability info from the Nontransmitted BSSID Capability element.
+    pub capability_info: Option<u16>,
+    /// The complete set of elements of this BSS, i.e. its own elements plus all inherited
+    /// elements of the transmitted BSS.
+    pub station_info: StationInfo,
+}
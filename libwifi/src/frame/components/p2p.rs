@@ -0,0 +1,355 @@
+use super::MacAddress;
+
+/// A Wi-Fi Direct (P2P) attribute, which is carried in WFA P2P vendor specific elements.
+///
+/// Attributes whose value can't be decoded losslessly are kept as [P2pAttribute::Unknown].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum P2pAttribute {
+    /// ID 0
+    Status(u8),
+    /// ID 2
+    Capability(P2pCapability),
+    /// The P2P Device Address of the target device (ID 3).
+    DeviceId(MacAddress),
+    /// ID 4
+    GroupOwnerIntent(GroupOwnerIntent),
+    /// ID 6
+    ListenChannel(P2pChannel),
+    /// ID 11
+    ChannelList(P2pChannelList),
+    /// ID 12
+    NoticeOfAbsence(NoticeOfAbsence),
+    /// ID 13
+    DeviceInfo(P2pDeviceInfo),
+    /// The clients of a P2P group (ID 14).
+    GroupInfo(Vec<P2pClientInfo>),
+    /// ID 17
+    OperatingChannel(P2pChannel),
+    Unknown {
+        id: u8,
+        data: Vec<u8>,
+    },
+}
+
+impl P2pAttribute {
+    pub fn id(&self) -> u8 {
+        match self {
+            P2pAttribute::Status(_) => 0,
+            P2pAttribute::Capability(_) => 2,
+            P2pAttribute::DeviceId(_) => 3,
+            P2pAttribute::GroupOwnerIntent(_) => 4,
+            P2pAttribute::ListenChannel(_) => 6,
+            P2pAttribute::ChannelList(_) => 11,
+            P2pAttribute::NoticeOfAbsence(_) => 12,
+            P2pAttribute::DeviceInfo(_) => 13,
+            P2pAttribute::GroupInfo(_) => 14,
+            P2pAttribute::OperatingChannel(_) => 17,
+            P2pAttribute::Unknown { id, .. } => *id,
+        }
+    }
+
+    /// Encode the attribute including its ID and length.
+    pub fn encode(&self) -> Vec<u8> {
+        let data = match self {
+            P2pAttribute::Status(status) => vec![*status],
+            P2pAttribute::Capability(capability) => capability.encode(),
+            P2pAttribute::DeviceId(address) => address.encode().to_vec(),
+            P2pAttribute::GroupOwnerIntent(intent) => vec![intent.encode()],
+            P2pAttribute::ListenChannel(channel) | P2pAttribute::OperatingChannel(channel) => {
+                channel.encode()
+            }
+            P2pAttribute::ChannelList(list) => list.encode(),
+            P2pAttribute::NoticeOfAbsence(noa) => noa.encode(),
+            P2pAttribute::DeviceInfo(info) => info.encode(),
+            P2pAttribute::GroupInfo(clients) => {
+                let mut bytes = Vec::new();
+                for client in clients {
+                    let client = client.encode();
+                    bytes.push(client.len() as u8);
+                    bytes.extend(client);
+                }
+                bytes
+            }
+            P2pAttribute::Unknown { data, .. } => data.clone(),
+        };
+
+        let mut bytes = vec![self.id()];
+        bytes.extend((data.len() as u16).to_le_bytes());
+        bytes.extend(data);
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct P2pCapability {
+    pub device_capability: P2pDeviceCapability,
+    pub group_capability: P2pGroupCapability,
+}
+
+impl P2pCapability {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![
+            self.device_capability.encode(),
+            self.group_capability.encode(),
+        ]
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct P2pDeviceCapability {
+    pub service_discovery: bool,      // bit 0
+    pub client_discoverability: bool, // 1
+    pub concurrent_operation: bool,   // 2
+    pub infrastructure_managed: bool, // 3
+    pub device_limit: bool,           // 4
+    pub invitation_procedure: bool,   // 5
+    pub reserved: u8,                 // 6-7
+}
+
+impl P2pDeviceCapability {
+    pub fn from_bits(bits: u8) -> P2pDeviceCapability {
+        P2pDeviceCapability {
+            service_discovery: bits & 0b1 != 0,
+            client_discoverability: bits & 0b10 != 0,
+            concurrent_operation: bits & 0b100 != 0,
+            infrastructure_managed: bits & 0b1000 != 0,
+            device_limit: bits & 0b1_0000 != 0,
+            invitation_procedure: bits & 0b10_0000 != 0,
+            reserved: bits >> 6,
+        }
+    }
+
+    pub fn encode(&self) -> u8 {
+        self.service_discovery as u8
+            | ((self.client_discoverability as u8) << 1)
+            | ((self.concurrent_operation as u8) << 2)
+            | ((self.infrastructure_managed as u8) << 3)
+            | ((self.device_limit as u8) << 4)
+            | ((self.invitation_procedure as u8) << 5)
+            | (self.reserved << 6)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct P2pGroupCapability {
+    pub group_owner: bool,            // bit 0
+    pub persistent_group: bool,       // 1
+    pub group_limit: bool,            // 2
+    pub intra_bss_distribution: bool, // 3
+    pub cross_connection: bool,       // 4
+    pub persistent_reconnect: bool,   // 5
+    pub group_formation: bool,        // 6
+    pub ip_address_allocation: bool,  // 7
+}
+
+impl P2pGroupCapability {
+    pub fn from_bits(bits: u8) -> P2pGroupCapability {
+        P2pGroupCapability {
+            group_owner: bits & 0b1 != 0,
+            persistent_group: bits & 0b10 != 0,
+            group_limit: bits & 0b100 != 0,
+            intra_bss_distribution: bits & 0b1000 != 0,
+            cross_connection: bits & 0b1_0000 != 0,
+            persistent_reconnect: bits & 0b10_0000 != 0,
+            group_formation: bits & 0b100_0000 != 0,
+            ip_address_allocation: bits & 0b1000_0000 != 0,
+        }
+    }
+
+    pub fn encode(&self) -> u8 {
+        self.group_owner as u8
+            | ((self.persistent_group as u8) << 1)
+            | ((self.group_limit as u8) << 2)
+            | ((self.intra_bss_distribution as u8) << 3)
+            | ((self.cross_connection as u8) << 4)
+            | ((self.persistent_reconnect as u8) << 5)
+            | ((self.group_formation as u8) << 6)
+            | ((self.ip_address_allocation as u8) << 7)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GroupOwnerIntent {
+    /// Decides which device becomes the group owner if both have the same intent.
+    pub tie_breaker: bool, // bit 0
+    /// The desire to become the group owner from `0` to `15`.
+    pub intent: u8, // 1-7
+}
+
+impl GroupOwnerIntent {
+    pub fn from_bits(bits: u8) -> GroupOwnerIntent {
+        GroupOwnerIntent {
+            tie_breaker: bits & 0b1 != 0,
+            intent: bits >> 1,
+        }
+    }
+
+    pub fn encode(&self) -> u8 {
+        self.tie_breaker as u8 | (self.intent << 1)
+    }
+}
+
+/// The Listen Channel or Operating Channel of a P2P device.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct P2pChannel {
+    /// The country string, e.g. `b"DE\x04"` for the global operating classes.
+    pub country: [u8; 3],
+    pub operating_class: u8,
+    pub channel: u8,
+}
+
+impl P2pChannel {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.country.to_vec();
+        bytes.extend([self.operating_class, self.channel]);
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct P2pChannelList {
+    pub country: [u8; 3],
+    pub entries: Vec<P2pChannelEntry>,
+}
+
+impl P2pChannelList {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.country.to_vec();
+        for entry in &self.entries {
+            bytes.extend([entry.operating_class, entry.channels.len() as u8]);
+            bytes.extend(&entry.channels);
+        }
+
+        bytes
+    }
+}
+
+/// The channels of a single operating class.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct P2pChannelEntry {
+    pub operating_class: u8,
+    pub channels: Vec<u8>,
+}
+
+/// The absence schedule of a P2P group owner.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NoticeOfAbsence {
+    /// Incremented whenever the schedule changes.
+    pub index: u8,
+    /// The client traffic window in TUs after each TBTT.
+    pub ct_window: u8, // bit 0-6
+    /// Opportunistic power save
+    pub opp_ps: bool, // 7
+    pub descriptors: Vec<NoticeOfAbsenceDescriptor>,
+}
+
+impl NoticeOfAbsence {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![
+            self.index,
+            (self.ct_window & 0x7f) | ((self.opp_ps as u8) << 7),
+        ];
+        for descriptor in &self.descriptors {
+            bytes.extend(descriptor.encode());
+        }
+
+        bytes
+    }
+}
+
+/// A single absence period, which is repeated [Self::count] times.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NoticeOfAbsenceDescriptor {
+    /// The number of absence intervals, `255` for a periodic schedule.
+    pub count: u8,
+    /// The duration of the absence in microseconds.
+    pub duration: u32,
+    /// The interval between the start of two absences in microseconds.
+    pub interval: u32,
+    /// The lower 4 bytes of the TSF at which the first absence starts.
+    pub start_time: u32,
+}
+
+impl NoticeOfAbsenceDescriptor {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.count];
+        bytes.extend(self.duration.to_le_bytes());
+        bytes.extend(self.interval.to_le_bytes());
+        bytes.extend(self.start_time.to_le_bytes());
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct P2pDeviceInfo {
+    pub device_address: MacAddress,
+    /// The WPS configuration methods, e.g. `0x0080` for push button.
+    pub config_methods: u16,
+    /// The WPS primary device type, i.e. category, OUI and subcategory.
+    pub primary_device_type: [u8; 8],
+    pub secondary_device_types: Vec<[u8; 8]>,
+    pub device_name: String,
+}
+
+impl P2pDeviceInfo {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.device_address.encode().to_vec();
+        bytes.extend(encode_device_description(
+            self.config_methods,
+            &self.primary_device_type,
+            &self.secondary_device_types,
+            &self.device_name,
+        ));
+        bytes
+    }
+}
+
+/// A client of a P2P group, as listed in the Group Info attribute.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct P2pClientInfo {
+    pub device_address: MacAddress,
+    pub interface_address: MacAddress,
+    pub device_capability: P2pDeviceCapability,
+    /// The WPS configuration methods, e.g. `0x0080` for push button.
+    pub config_methods: u16,
+    /// The WPS primary device type, i.e. category, OUI and subcategory.
+    pub primary_device_type: [u8; 8],
+    pub secondary_device_types: Vec<[u8; 8]>,
+    pub device_name: String,
+}
+
+impl P2pClientInfo {
+    /// Encode the client info descriptor without its length.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.device_address.encode().to_vec();
+        bytes.extend(self.interface_address.encode());
+        bytes.push(self.device_capability.encode());
+        bytes.extend(encode_device_description(
+            self.config_methods,
+            &self.primary_device_type,
+            &self.secondary_device_types,
+            &self.device_name,
+        ));
+        bytes
+    }
+}
+
+/// Encode the fields that are shared by the Device Info and client info descriptors.
+/// The device name is a WPS attribute with big endian type and length.
+fn encode_device_description(
+    config_methods: u16,
+    primary_device_type: &[u8; 8],
+    secondary_device_types: &[[u8; 8]],
+    device_name: &str,
+) -> Vec<u8> {
+    let mut bytes = config_methods.to_be_bytes().to_vec();
+    bytes.extend(primary_device_type);
+    bytes.push(secondary_device_types.len() as u8);
+    for device_type in secondary_device_types {
+        bytes.extend(device_type);
+    }
+    bytes.extend(0x1011u16.to_be_bytes());
+    bytes.extend((device_name.len() as u16).to_be_bytes());
+    bytes.extend(device_name.as_bytes());
+    bytes
+}
@@ -0,0 +1,285 @@
+/// The access categories (ACI) of EDCA.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AccessCategory {
+    #[default]
+    BestEffort,
+    Background,
+    Video,
+    Voice,
+}
+
+impl From<u8> for AccessCategory {
+    fn from(aci: u8) -> Self {
+        match aci & 0b11 {
+            0 => AccessCategory::BestEffort,
+            1 => AccessCategory::Background,
+            2 => AccessCategory::Video,
+            _ => AccessCategory::Voice,
+        }
+    }
+}
+
+impl AccessCategory {
+    pub fn encode(&self) -> u8 {
+        match self {
+            AccessCategory::BestEffort => 0,
+            AccessCategory::Background => 1,
+            AccessCategory::Video => 2,
+            AccessCategory::Voice => 3,
+        }
+    }
+}
+
+/// The QoS Info field as it's sent by APs.
+///
+/// Non-AP stations use a different layout, where bits 0-3 contain the U-APSD flags
+/// of each access category. Use [QosInfo::station_u_apsd] to interpret it that way.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QosInfo {
+    pub parameter_set_count: u8, // bit 0-3
+    pub q_ack: bool,             // 4
+    pub queue_request: bool,     // 5
+    pub txop_request: bool,      // 6
+    /// U-APSD support of the AP. Reserved in the standard QoS Info field.
+    pub u_apsd: bool, // 7
+}
+
+impl QosInfo {
+    pub fn from_bits(bits: u8) -> Self {
+        QosInfo {
+            parameter_set_count: bits & 0x0f,
+            q_ack: bits & (1 << 4) != 0,
+            queue_request: bits & (1 << 5) != 0,
+            txop_request: bits & (1 << 6) != 0,
+            u_apsd: bits & (1 << 7) != 0,
+        }
+    }
+
+    pub fn encode(&self) -> u8 {
+        let mut bits = self.parameter_set_count & 0x0f;
+        bits |= (self.q_ack as u8) << 4;
+        bits |= (self.queue_request as u8) << 5;
+        bits |= (self.txop_request as u8) << 6;
+        bits |= (self.u_apsd as u8) << 7;
+
+        bits
+    }
+
+    /// Interpret the lower 4 bits as the U-APSD flags of a non-AP station.
+    ///
+    /// Returns the flags in the order `[Voice, Video, Background, BestEffort]`.
+    pub fn station_u_apsd(&self) -> [bool; 4] {
+        [
+            self.parameter_set_count & 1 != 0,
+            self.parameter_set_count & (1 << 1) != 0,
+            self.parameter_set_count & (1 << 2) != 0,
+            self.parameter_set_count & (1 << 3) != 0,
+        ]
+    }
+}
+
+/// The EDCA parameters of a single access category.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AcParameters {
+    pub aifsn: u8,                       // bit 0-3
+    pub acm: bool,                       // 4
+    pub access_category: AccessCategory, // 5-6
+    pub ecw_min: u8,                     // 8-11
+    pub ecw_max: u8,                     // 12-15
+    /// The TXOP limit in units of 32 µs.
+    pub txop_limit: u16,
+}
+
+impl AcParameters {
+    /// Create the parameters from the ACI/AIFSN and ECWmin/ECWmax bytes.
+    pub fn from_bytes(aci_aifsn: u8, ecw: u8, txop_limit: u16) -> Self {
+        AcParameters {
+            aifsn: aci_aifsn & 0x0f,
+            acm: aci_aifsn & (1 << 4) != 0,
+            access_category: AccessCategory::from(aci_aifsn >> 5),
+            ecw_min: ecw & 0x0f,
+            ecw_max: ecw >> 4,
+            txop_limit,
+        }
+    }
+
+    /// Encode the ACI/AIFSN and ECWmin/ECWmax bytes.
+    /// The TXOP limit needs to be appended by the caller.
+    pub fn encode_header(&self) -> [u8; 2] {
+        let mut aci_aifsn = self.aifsn & 0x0f;
+        aci_aifsn |= (self.acm as u8) << 4;
+        aci_aifsn |= self.access_category.encode() << 5;
+
+        [aci_aifsn, (self.ecw_min & 0x0f) | (self.ecw_max << 4)]
+    }
+
+    pub fn encode(&self) -> [u8; 4] {
+        let [aci_aifsn, ecw] = self.encode_header();
+        let txop = self.txop_limit.to_le_bytes();
+        [aci_aifsn, ecw, txop[0], txop[1]]
+    }
+
+    /// CWmin = 2^ECWmin - 1
+    pub fn cw_min(&self) -> u16 {
+        (1u16 << self.ecw_min) - 1
+    }
+
+    /// CWmax = 2^ECWmax - 1
+    pub fn cw_max(&self) -> u16 {
+        (1u16 << self.ecw_max) - 1
+    }
+
+    /// The TXOP limit in µs, `0` means that a single MSDU/MMPDU may be sent.
+    pub fn txop_limit_us(&self) -> u32 {
+        self.txop_limit as u32 * 32
+    }
+}
+
+/// The EDCA Parameter Set element (Element ID 12).
+///
+/// The WMM Parameter element uses the same layout, where the Update EDCA Info field is reserved.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EdcaParameterSet {
+    pub qos_info: QosInfo,
+    pub update_edca_info: u8,
+    /// Usually contains the parameters of all four access categories.
+    pub ac_parameters: Vec<AcParameters>,
+}
+
+impl EdcaParameterSet {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.qos_info.encode(), self.update_edca_info];
+        for parameters in &self.ac_parameters {
+            bytes.extend(parameters.encode());
+        }
+
+        bytes
+    }
+
+    /// Get the parameters of a specific access category.
+    pub fn get(&self, access_category: AccessCategory) -> Option<&AcParameters> {
+        self.ac_parameters
+            .iter()
+            .find(|parameters| parameters.access_category == access_category)
+    }
+}
+
+/// The MU EDCA parameters of a single access category, which are used by HE stations for
+/// trigger based uplink transmissions.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MuAcParameters {
+    pub aifsn: u8,                       // bit 0-3
+    pub acm: bool,                       // 4
+    pub access_category: AccessCategory, // 5-6
+    pub ecw_min: u8,                     // 8-11
+    pub ecw_max: u8,                     // 12-15
+    /// The MU EDCA Timer in units of 8 TUs.
+    pub mu_edca_timer: u8,
+}
+
+impl MuAcParameters {
+    /// Create the parameters from the ACI/AIFSN, ECWmin/ECWmax and MU EDCA Timer bytes.
+    pub fn from_bytes(aci_aifsn: u8, ecw: u8, mu_edca_timer: u8) -> Self {
+        MuAcParameters {
+            aifsn: aci_aifsn & 0x0f,
+            acm: aci_aifsn & (1 << 4) != 0,
+            access_category: AccessCategory::from(aci_aifsn >> 5),
+            ecw_min: ecw & 0x0f,
+            ecw_max: ecw >> 4,
+            mu_edca_timer,
+        }
+    }
+
+    pub fn encode(&self) -> [u8; 3] {
+        let mut aci_aifsn = self.aifsn & 0x0f;
+        aci_aifsn |= (self.acm as u8) << 4;
+        aci_aifsn |= self.access_category.encode() << 5;
+
+        [
+            aci_aifsn,
+            (self.ecw_min & 0x0f) | (self.ecw_max << 4),
+            self.mu_edca_timer,
+        ]
+    }
+
+    /// CWmin = 2^ECWmin - 1
+    pub fn cw_min(&self) -> u16 {
+        (1u16 << self.ecw_min) - 1
+    }
+
+    /// CWmax = 2^ECWmax - 1
+    pub fn cw_max(&self) -> u16 {
+        (1u16 << self.ecw_max) - 1
+    }
+
+    /// The duration in TUs for which the MU EDCA parameters are used instead of the regular
+    /// EDCA parameters.
+    pub fn mu_edca_timer_tu(&self) -> u16 {
+        self.mu_edca_timer as u16 * 8
+    }
+}
+
+/// The MU EDCA Parameter Set element (Element ID 255, Extension ID 38).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MuEdcaParameterSet {
+    pub qos_info: QosInfo,
+    pub ac_parameters: Vec<MuAcParameters>,
+}
+
+impl MuEdcaParameterSet {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.qos_info.encode()];
+        for parameters in &self.ac_parameters {
+            bytes.extend(parameters.encode());
+        }
+
+        bytes
+    }
+
+    /// Get the parameters of a specific access category.
+    pub fn get(&self, access_category: AccessCategory) -> Option<&MuAcParameters> {
+        self.ac_parameters
+            .iter()
+            .find(|parameters| parameters.access_category == access_category)
+    }
+}
+
+/// The Microsoft WMM/WME vendor specific element (OUI 00:50:f2, type 2).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Wmm {
+    /// WMM Information element (subtype 0), sent by stations and in probe responses.
+    Information { version: u8, qos_info: QosInfo },
+    /// WMM Parameter element (subtype 1), sent by APs.
+    Parameter {
+        version: u8,
+        parameters: EdcaParameterSet,
+    },
+}
+
+impl Wmm {
+    /// Encode the element payload, starting with the OUI.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![0x00, 0x50, 0xf2, 2];
+        match self {
+            Wmm::Information { version, qos_info } => {
+                bytes.extend([0, *version, qos_info.encode()]);
+            }
+            Wmm::Parameter {
+                version,
+                parameters,
+            } => {
+                bytes.extend([1, *version]);
+                bytes.extend(parameters.encode());
+            }
+        }
+
+        bytes
+    }
+
+    pub fn qos_info(&self) -> &QosInfo {
+        match self {
+            Wmm::Information { qos_info, .. } => qos_info,
+            Wmm::Parameter { parameters, .. } => &parameters.qos_info,
+        }
+    }
+}
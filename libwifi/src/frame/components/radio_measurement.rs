@@ -0,0 +1,694 @@
+use super::{BssTerminationDuration, MacAddress};
+
+/// A generic subelement, as it's used in many elements, e.g. in Measurement Request/Report and
+/// Neighbor Report elements.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Subelement {
+    pub id: u8,
+    pub data: Vec<u8>,
+}
+
+impl Subelement {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.id, self.data.len() as u8];
+        bytes.extend(&self.data);
+        bytes
+    }
+}
+
+/// Encode a list of subelements.
+pub(crate) fn encode_subelements(bytes: &mut Vec<u8>, subelements: &[Subelement]) {
+    for subelement in subelements {
+        bytes.extend(subelement.encode());
+    }
+}
+
+/// Find the payload of the first subelement with the given id.
+pub(crate) fn find_subelement(subelements: &[Subelement], id: u8) -> Option<&[u8]> {
+    subelements
+        .iter()
+        .find(|subelement| subelement.id == id)
+        .map(|subelement| subelement.data.as_slice())
+}
+
+/// The Measurement Request element (Element ID 38).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MeasurementRequest {
+    /// Identifies the request, the same token is used in the respective report.
+    pub token: u8,
+    pub mode: MeasurementRequestMode,
+    pub request: MeasurementRequestType,
+}
+
+impl MeasurementRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![
+            self.token,
+            self.mode.encode(),
+            self.request.measurement_type(),
+        ];
+        bytes.extend(self.request.encode());
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MeasurementRequestMode {
+    pub parallel: bool,           // bit 0
+    pub enable: bool,             // 1
+    pub request: bool,            // 2
+    pub report: bool,             // 3
+    pub duration_mandatory: bool, // 4
+    pub reserved: u8,             // 5-7
+}
+
+impl MeasurementRequestMode {
+    pub fn from_bits(bits: u8) -> MeasurementRequestMode {
+        MeasurementRequestMode {
+            parallel: bits & 0b1 != 0,
+            enable: bits & 0b10 != 0,
+            request: bits & 0b100 != 0,
+            report: bits & 0b1000 != 0,
+            duration_mandatory: bits & 0b1_0000 != 0,
+            reserved: bits >> 5,
+        }
+    }
+
+    pub fn encode(&self) -> u8 {
+        self.parallel as u8
+            | (self.enable as u8) << 1
+            | (self.request as u8) << 2
+            | (self.report as u8) << 3
+            | (self.duration_mandatory as u8) << 4
+            | self.reserved << 5
+    }
+}
+
+/// The type specific part of a [MeasurementRequest].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MeasurementRequestType {
+    /// Type 3
+    ChannelLoad(ChannelMeasurementRequest),
+    /// Type 4
+    NoiseHistogram(ChannelMeasurementRequest),
+    /// Type 5
+    Beacon(BeaconRequest),
+    /// Type 7
+    StaStatistics(StaStatisticsRequest),
+    /// Type 8
+    Lci(LciRequest),
+    /// Type 11
+    LocationCivic(LocationCivicRequest),
+    Unknown {
+        measurement_type: u8,
+        data: Vec<u8>,
+    },
+}
+
+impl MeasurementRequestType {
+    pub fn measurement_type(&self) -> u8 {
+        match self {
+            MeasurementRequestType::ChannelLoad(_) => 3,
+            MeasurementRequestType::NoiseHistogram(_) => 4,
+            MeasurementRequestType::Beacon(_) => 5,
+            MeasurementRequestType::StaStatistics(_) => 7,
+            MeasurementRequestType::Lci(_) => 8,
+            MeasurementRequestType::LocationCivic(_) => 11,
+            MeasurementRequestType::Unknown {
+                measurement_type, ..
+            } => *measurement_type,
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        match self {
+            MeasurementRequestType::ChannelLoad(request)
+            | MeasurementRequestType::NoiseHistogram(request) => request.encode(),
+            MeasurementRequestType::Beacon(request) => request.encode(),
+            MeasurementRequestType::StaStatistics(request) => request.encode(),
+            MeasurementRequestType::Lci(request) => request.encode(),
+            MeasurementRequestType::LocationCivic(request) => request.encode(),
+            MeasurementRequestType::Unknown { data, .. } => data.clone(),
+        }
+    }
+}
+
+/// The request of a Channel Load or Noise Histogram measurement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChannelMeasurementRequest {
+    pub operating_class: u8,
+    pub channel: u8,
+    /// The upper bound of the random delay before the measurement starts in TUs.
+    pub randomization_interval: u16,
+    /// The measurement duration in TUs.
+    pub measurement_duration: u16,
+    pub subelements: Vec<Subelement>,
+}
+
+impl ChannelMeasurementRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.operating_class, self.channel];
+        bytes.extend(self.randomization_interval.to_le_bytes());
+        bytes.extend(self.measurement_duration.to_le_bytes());
+        encode_subelements(&mut bytes, &self.subelements);
+        bytes
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BeaconMeasurementMode {
+    Passive,
+    Active,
+    /// Report the results of previous scans.
+    BeaconTable,
+    Unknown(u8),
+}
+
+impl From<u8> for BeaconMeasurementMode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => BeaconMeasurementMode::Passive,
+            1 => BeaconMeasurementMode::Active,
+            2 => BeaconMeasurementMode::BeaconTable,
+            other => BeaconMeasurementMode::Unknown(other),
+        }
+    }
+}
+
+impl BeaconMeasurementMode {
+    pub fn encode(&self) -> u8 {
+        match self {
+            BeaconMeasurementMode::Passive => 0,
+            BeaconMeasurementMode::Active => 1,
+            BeaconMeasurementMode::BeaconTable => 2,
+            BeaconMeasurementMode::Unknown(value) => *value,
+        }
+    }
+}
+
+/// Requests a station to scan for beacons and report the found BSSes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BeaconRequest {
+    pub operating_class: u8,
+    /// `0` requests all channels of the operating class, `255` the channels of the AP Channel
+    /// Report subelements.
+    pub channel: u8,
+    pub randomization_interval: u16,
+    pub measurement_duration: u16,
+    pub measurement_mode: BeaconMeasurementMode,
+    /// The broadcast address requests all BSSIDs.
+    pub bssid: MacAddress,
+    pub subelements: Vec<Subelement>,
+}
+
+impl BeaconRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.operating_class, self.channel];
+        bytes.extend(self.randomization_interval.to_le_bytes());
+        bytes.extend(self.measurement_duration.to_le_bytes());
+        bytes.push(self.measurement_mode.encode());
+        bytes.extend(self.bssid.encode());
+        encode_subelements(&mut bytes, &self.subelements);
+        bytes
+    }
+
+    /// The SSID subelement (ID 0), which limits the scan to a specific SSID.
+    pub fn ssid(&self) -> Option<&[u8]> {
+        find_subelement(&self.subelements, 0)
+    }
+
+    /// The Reporting Detail subelement (ID 2).
+    ///
+    /// `0`: No frame body, `1`: Only the requested elements, `2`: All elements.
+    pub fn reporting_detail(&self) -> Option<u8> {
+        find_subelement(&self.subelements, 2).and_then(|data| data.first().copied())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StaStatisticsRequest {
+    pub peer_mac_address: MacAddress,
+    pub randomization_interval: u16,
+    pub measurement_duration: u16,
+    /// Identifies the group of statistics counters that is requested.
+    pub group_identity: u8,
+    pub subelements: Vec<Subelement>,
+}
+
+impl StaStatisticsRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.peer_mac_address.encode().to_vec();
+        bytes.extend(self.randomization_interval.to_le_bytes());
+        bytes.extend(self.measurement_duration.to_le_bytes());
+        bytes.push(self.group_identity);
+        encode_subelements(&mut bytes, &self.subelements);
+        bytes
+    }
+}
+
+/// Requests the Location Configuration Information (geospatial location).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LciRequest {
+    /// `0`: The location of the requesting station, `1`: The location of the reporting station,
+    /// `2`: A third party location.
+    pub location_subject: u8,
+    pub subelements: Vec<Subelement>,
+}
+
+impl LciRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.location_subject];
+        encode_subelements(&mut bytes, &self.subelements);
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocationCivicRequest {
+    /// The same values as [LciRequest::location_subject].
+    pub location_subject: u8,
+    /// `0` for the IETF RFC 4776 civic address format.
+    pub civic_location_type: u8,
+    /// `0`: seconds, `1`: minutes, `2`: hours.
+    pub location_service_interval_units: u8,
+    /// `0` for a single report.
+    pub location_service_interval: u16,
+    pub subelements: Vec<Subelement>,
+}
+
+impl LocationCivicRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![
+            self.location_subject,
+            self.civic_location_type,
+            self.location_service_interval_units,
+        ];
+        bytes.extend(self.location_service_interval.to_le_bytes());
+        encode_subelements(&mut bytes, &self.subelements);
+        bytes
+    }
+}
+
+/// The Measurement Report element (Element ID 39).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MeasurementReport {
+    /// The token of the respective [MeasurementRequest], `0` for autonomous reports.
+    pub token: u8,
+    pub mode: MeasurementReportMode,
+    pub report: MeasurementReportType,
+}
+
+impl MeasurementReport {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![
+            self.token,
+            self.mode.encode(),
+            self.report.measurement_type(),
+        ];
+        bytes.extend(self.report.encode());
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MeasurementReportMode {
+    pub late: bool,      // bit 0
+    pub incapable: bool, // 1
+    pub refused: bool,   // 2
+    pub reserved: u8,    // 3-7
+}
+
+impl MeasurementReportMode {
+    pub fn from_bits(bits: u8) -> MeasurementReportMode {
+        MeasurementReportMode {
+            late: bits & 0b1 != 0,
+            incapable: bits & 0b10 != 0,
+            refused: bits & 0b100 != 0,
+            reserved: bits >> 3,
+        }
+    }
+
+    pub fn encode(&self) -> u8 {
+        self.late as u8
+            | (self.incapable as u8) << 1
+            | (self.refused as u8) << 2
+            | self.reserved << 3
+    }
+}
+
+/// The type specific part of a [MeasurementReport].
+///
+/// Reports that are late, incapable or refused don't contain a report, which is represented
+/// by [MeasurementReportType::Unknown] with empty data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MeasurementReportType {
+    /// Type 3
+    ChannelLoad(ChannelLoadReport),
+    /// Type 4
+    NoiseHistogram(NoiseHistogramReport),
+    /// Type 5
+    Beacon(BeaconReport),
+    /// Type 7
+    StaStatistics(StaStatisticsReport),
+    /// Type 8
+    Lci(LciReport),
+    /// Type 11
+    LocationCivic(LocationCivicReport),
+    Unknown {
+        measurement_type: u8,
+        data: Vec<u8>,
+    },
+}
+
+impl MeasurementReportType {
+    pub fn measurement_type(&self) -> u8 {
+        match self {
+            MeasurementReportType::ChannelLoad(_) => 3,
+            MeasurementReportType::NoiseHistogram(_) => 4,
+            MeasurementReportType::Beacon(_) => 5,
+            MeasurementReportType::StaStatistics(_) => 7,
+            MeasurementReportType::Lci(_) => 8,
+            MeasurementReportType::LocationCivic(_) => 11,
+            MeasurementReportType::Unknown {
+                measurement_type, ..
+            } => *measurement_type,
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        match self {
+            MeasurementReportType::ChannelLoad(report) => report.encode(),
+            MeasurementReportType::NoiseHistogram(report) => report.encode(),
+            MeasurementReportType::Beacon(report) => report.encode(),
+            MeasurementReportType::StaStatistics(report) => report.encode(),
+            MeasurementReportType::Lci(report) => report.encode(),
+            MeasurementReportType::LocationCivic(report) => report.encode(),
+            MeasurementReportType::Unknown { data, .. } => data.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChannelLoadReport {
+    pub operating_class: u8,
+    pub channel: u8,
+    /// The TSF at which the measurement started.
+    pub actual_measurement_start_time: u64,
+    pub measurement_duration: u16,
+    /// The fraction of time the channel was busy, scaled to 0-255.
+    pub channel_load: u8,
+    pub subelements: Vec<Subelement>,
+}
+
+impl ChannelLoadReport {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.operating_class, self.channel];
+        bytes.extend(self.actual_measurement_start_time.to_le_bytes());
+        bytes.extend(self.measurement_duration.to_le_bytes());
+        bytes.push(self.channel_load);
+        encode_subelements(&mut bytes, &self.subelements);
+        bytes
+    }
+
+    /// The channel load in percent.
+    pub fn channel_load_percent(&self) -> f32 {
+        self.channel_load as f32 / 255.0 * 100.0
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoiseHistogramReport {
+    pub operating_class: u8,
+    pub channel: u8,
+    pub actual_measurement_start_time: u64,
+    pub measurement_duration: u16,
+    pub antenna_id: u8,
+    /// The average noise plus interference power indicator.
+    pub anpi: u8,
+    /// The densities of the 11 IPI (idle power indicator) levels, scaled to 0-255.
+    pub ipi_densities: [u8; 11],
+    pub subelements: Vec<Subelement>,
+}
+
+impl NoiseHistogramReport {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.operating_class, self.channel];
+        bytes.extend(self.actual_measurement_start_time.to_le_bytes());
+        bytes.extend(self.measurement_duration.to_le_bytes());
+        bytes.push(self.antenna_id);
+        bytes.push(self.anpi);
+        bytes.extend(self.ipi_densities);
+        encode_subelements(&mut bytes, &self.subelements);
+        bytes
+    }
+}
+
+/// A single BSS that has been found during a beacon measurement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BeaconReport {
+    pub operating_class: u8,
+    pub channel: u8,
+    pub actual_measurement_start_time: u64,
+    pub measurement_duration: u16,
+    /// Bit 0-6 contain the PHY type, bit 7 the type of the reported frame
+    /// (`0`: beacon or probe response, `1`: measurement pilot).
+    pub reported_frame_information: u8,
+    /// The received channel power indicator of the reported frame.
+    pub rcpi: u8,
+    /// The received signal to noise indicator of the reported frame.
+    pub rsni: u8,
+    pub bssid: MacAddress,
+    pub antenna_id: u8,
+    /// The lower 4 bytes of the TSF of the reporting station when the frame has been received.
+    pub parent_tsf: u32,
+    pub subelements: Vec<Subelement>,
+}
+
+impl BeaconReport {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.operating_class, self.channel];
+        bytes.extend(self.actual_measurement_start_time.to_le_bytes());
+        bytes.extend(self.measurement_duration.to_le_bytes());
+        bytes.extend([self.reported_frame_information, self.rcpi, self.rsni]);
+        bytes.extend(self.bssid.encode());
+        bytes.push(self.antenna_id);
+        bytes.extend(self.parent_tsf.to_le_bytes());
+        encode_subelements(&mut bytes, &self.subelements);
+        bytes
+    }
+
+    /// The RCPI in dBm, if it has been measured.
+    pub fn rcpi_dbm(&self) -> Option<f32> {
+        (self.rcpi <= 220).then(|| self.rcpi as f32 / 2.0 - 110.0)
+    }
+
+    /// The Reported Frame Body subelement (ID 1), which contains the (truncated) fixed fields
+    /// and elements of the reported frame.
+    pub fn reported_frame_body(&self) -> Option<&[u8]> {
+        find_subelement(&self.subelements, 1)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StaStatisticsReport {
+    pub measurement_duration: u16,
+    pub group_identity: u8,
+    /// The statistics counters, whose layout depends on the group identity, followed by
+    /// optional subelements.
+    pub statistics: Vec<u8>,
+}
+
+impl StaStatisticsReport {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.measurement_duration.to_le_bytes().to_vec();
+        bytes.push(self.group_identity);
+        bytes.extend(&self.statistics);
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LciReport {
+    /// The LCI subelement (ID 0) contains the location itself.
+    pub subelements: Vec<Subelement>,
+}
+
+impl LciReport {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        encode_subelements(&mut bytes, &self.subelements);
+        bytes
+    }
+
+    /// The raw location of the LCI subelement (ID 0).
+    pub fn lci(&self) -> Option<&[u8]> {
+        find_subelement(&self.subelements, 0)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocationCivicReport {
+    /// `0` for the IETF RFC 4776 civic address format.
+    pub civic_location_type: u8,
+    /// The Location Civic subelement (ID 0) contains the civic address itself.
+    pub subelements: Vec<Subelement>,
+}
+
+impl LocationCivicReport {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.civic_location_type];
+        encode_subelements(&mut bytes, &self.subelements);
+        bytes
+    }
+
+    /// The raw civic address of the Location Civic subelement (ID 0).
+    pub fn location_civic(&self) -> Option<&[u8]> {
+        find_subelement(&self.subelements, 0)
+    }
+}
+
+/// The Neighbor Report element (Element ID 52), which describes a neighboring AP.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NeighborReport {
+    pub bssid: MacAddress,
+    pub bssid_information: BssidInformation,
+    pub operating_class: u8,
+    pub channel: u8,
+    pub phy_type: u8,
+    pub subelements: Vec<Subelement>,
+}
+
+impl NeighborReport {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.bssid.encode().to_vec();
+        bytes.extend(self.bssid_information.encode());
+        bytes.extend([self.operating_class, self.channel, self.phy_type]);
+        encode_subelements(&mut bytes, &self.subelements);
+        bytes
+    }
+
+    /// The BSS Transition Candidate Preference subelement (ID 3).
+    /// Higher values are preferred, `0` excludes the AP.
+    pub fn preference(&self) -> Option<u8> {
+        find_subelement(&self.subelements, 3).and_then(|data| data.first().copied())
+    }
+
+    /// The BSS Termination Duration subelement (ID 4).
+    pub fn bss_termination_duration(&self) -> Option<BssTerminationDuration> {
+        let data = find_subelement(&self.subelements, 4)?;
+        Some(BssTerminationDuration {
+            bss_termination_tsf: u64::from_le_bytes(data.get(..8)?.try_into().ok()?),
+            duration: u16::from_le_bytes(data.get(8..10)?.try_into().ok()?),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ApReachability {
+    Reserved,
+    NotReachable,
+    Unknown,
+    Reachable,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BssidInformation {
+    pub ap_reachability: ApReachability, // bit 0-1
+    /// The AP supports the same security as the reporting AP.
+    pub security: bool, // 2
+    /// The AP has the same authenticator as the reporting AP.
+    pub key_scope: bool, // 3
+    pub spectrum_management: bool,       // 4
+    pub qos: bool,                       // 5
+    pub apsd: bool,                      // 6
+    pub radio_measurement: bool,         // 7
+    pub delayed_block_ack: bool,         // 8
+    pub immediate_block_ack: bool,       // 9
+    /// The AP is in the same mobility domain as the reporting AP.
+    pub mobility_domain: bool, // 10
+    pub high_throughput: bool,           // 11
+    pub very_high_throughput: bool,      // 12
+    pub ftm: bool,                       // 13
+    pub high_efficiency: bool,           // 14
+    pub er_bss: bool,                    // 15
+    pub colocated_ap: bool,              // 16
+    pub unsolicited_probe_responses_active: bool, // 17
+    pub member_of_colocated_ess: bool,   // 18
+    pub oct_supported_with_reporting_ap: bool, // 19
+    pub colocated_6ghz_ap: bool,         // 20
+    pub extremely_high_throughput: bool, // 21
+    pub reserved: u16,                   // 22-31
+}
+
+impl BssidInformation {
+    pub fn from_bits(bits: u32) -> BssidInformation {
+        let bit = |index: u32| bits & (1 << index) != 0;
+        BssidInformation {
+            ap_reachability: match bits & 0b11 {
+                0 => ApReachability::Reserved,
+                1 => ApReachability::NotReachable,
+                2 => ApReachability::Unknown,
+                _ => ApReachability::Reachable,
+            },
+            security: bit(2),
+            key_scope: bit(3),
+            spectrum_management: bit(4),
+            qos: bit(5),
+            apsd: bit(6),
+            radio_measurement: bit(7),
+            delayed_block_ack: bit(8),
+            immediate_block_ack: bit(9),
+            mobility_domain: bit(10),
+            high_throughput: bit(11),
+            very_high_throughput: bit(12),
+            ftm: bit(13),
+            high_efficiency: bit(14),
+            er_bss: bit(15),
+            colocated_ap: bit(16),
+            unsolicited_probe_responses_active: bit(17),
+            member_of_colocated_ess: bit(18),
+            oct_supported_with_reporting_ap: bit(19),
+            colocated_6ghz_ap: bit(20),
+            extremely_high_throughput: bit(21),
+            reserved: (bits >> 22) as u16,
+        }
+    }
+
+    pub fn encode(&self) -> [u8; 4] {
+        let flags = [
+            self.security,
+            self.key_scope,
+            self.spectrum_management,
+            self.qos,
+            self.apsd,
+            self.radio_measurement,
+            self.delayed_block_ack,
+            self.immediate_block_ack,
+            self.mobility_domain,
+            self.high_throughput,
+            self.very_high_throughput,
+            self.ftm,
+            self.high_efficiency,
+            self.er_bss,
+            self.colocated_ap,
+            self.unsolicited_probe_responses_active,
+            self.member_of_colocated_ess,
+            self.oct_supported_with_reporting_ap,
+            self.colocated_6ghz_ap,
+            self.extremely_high_throughput,
+        ];
+
+        let mut bits = match self.ap_reachability {
+            ApReachability::Reserved => 0,
+            ApReachability::NotReachable => 1,
+            ApReachability::Unknown => 2,
+            ApReachability::Reachable => 3,
+        };
+        for (index, flag) in flags.into_iter().enumerate() {
+            bits |= (flag as u32) << (index + 2);
+        }
+        bits |= (self.reserved as u32) << 22;
+
+        bits.to_le_bytes()
+    }
+}
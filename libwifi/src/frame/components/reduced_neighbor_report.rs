@@ -0,0 +1,177 @@
+use super::MacAddress;
+
+/// The Reduced Neighbor Report element (Element ID 201).
+///
+/// APs use it to announce neighboring and co-located APs, which allows stations to discover
+/// 6 GHz APs via beacons on the 2.4/5 GHz bands.
+#[derive(Clone, Debug, Default)]
+pub struct ReducedNeighborReport {
+    pub neighbor_ap_information: Vec<NeighborApInformation>,
+    /// Trailing bytes that couldn't be parsed as Neighbor AP Information fields.
+    pub other_data: Vec<u8>,
+}
+
+impl ReducedNeighborReport {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for info in &self.neighbor_ap_information {
+            bytes.extend(info.encode());
+        }
+        bytes.extend(&self.other_data);
+
+        bytes
+    }
+}
+
+/// A Neighbor AP Information field, which describes all APs on a single channel.
+#[derive(Clone, Debug)]
+pub struct NeighborApInformation {
+    pub tbtt_information_field_type: u8,
+    pub filtered_neighbor_ap: bool,
+    pub operating_class: u8,
+    pub channel: u8,
+    /// At least one TBTT Information field is required, as the count can't express an empty
+    /// set. Neighbor AP Information fields without any are skipped when encoding.
+    pub tbtt_information: Vec<TbttInformation>,
+}
+
+impl NeighborApInformation {
+    pub fn encode(&self) -> Vec<u8> {
+        if self.tbtt_information.is_empty() {
+            return Vec::new();
+        }
+
+        let encoded: Vec<Vec<u8>> = self.tbtt_information.iter().map(|t| t.encode()).collect();
+        // All TBTT Information fields share the same length.
+        let tbtt_information_length = encoded.first().map_or(0, |data| data.len() as u8);
+        // The count is stored as "number of fields - 1".
+        let count = (self.tbtt_information.len() - 1) as u8;
+
+        let mut header: u16 = (self.tbtt_information_field_type & 0b11) as u16;
+        header |= (self.filtered_neighbor_ap as u16) << 2;
+        header |= ((count & 0x0f) as u16) << 4;
+        header |= (tbtt_information_length as u16) << 8;
+
+        let mut bytes = header.to_le_bytes().to_vec();
+        bytes.push(self.operating_class);
+        bytes.push(self.channel);
+        for data in encoded {
+            bytes.extend(data);
+        }
+
+        bytes
+    }
+}
+
+/// A single TBTT Information field.
+///
+/// Which of the optional fields are present is determined by the TBTT Information Length
+/// of the [NeighborApInformation].
+#[derive(Clone, Debug, Default)]
+pub struct TbttInformation {
+    /// Offset in TUs to the next TBTT of this AP, `255` means unknown.
+    pub tbtt_offset: u8,
+    pub bssid: Option<MacAddress>,
+    pub short_ssid: Option<u32>,
+    pub bss_parameters: Option<RnrBssParameters>,
+    /// The maximum transmit power spectral density in units of 0.5 dBm/MHz.
+    pub psd_20mhz: Option<i8>,
+    pub mld_parameters: Option<RnrMldParameters>,
+    /// Any trailing bytes that aren't defined yet.
+    pub other_data: Vec<u8>,
+}
+
+impl TbttInformation {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.tbtt_offset];
+        if let Some(bssid) = &self.bssid {
+            bytes.extend_from_slice(&bssid.encode());
+        }
+        if let Some(short_ssid) = self.short_ssid {
+            bytes.extend_from_slice(&short_ssid.to_le_bytes());
+        }
+        if let Some(bss_parameters) = &self.bss_parameters {
+            bytes.push(bss_parameters.encode());
+        }
+        if let Some(psd) = self.psd_20mhz {
+            bytes.push(psd as u8);
+        }
+        if let Some(mld_parameters) = &self.mld_parameters {
+            bytes.extend_from_slice(&mld_parameters.encode());
+        }
+        bytes.extend(&self.other_data);
+
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RnrBssParameters {
+    pub oct_recommended: bool,                    // bit 0
+    pub same_ssid: bool,                          // 1
+    pub multiple_bssid: bool,                     // 2
+    pub transmitted_bssid: bool,                  // 3
+    pub member_of_ess_with_colocated_ap: bool,    // 4
+    pub unsolicited_probe_responses_active: bool, // 5
+    pub colocated_ap: bool,                       // 6
+}
+
+impl RnrBssParameters {
+    pub fn from_bits(bits: u8) -> Self {
+        RnrBssParameters {
+            oct_recommended: bits & 1 != 0,
+            same_ssid: bits & (1 << 1) != 0,
+            multiple_bssid: bits & (1 << 2) != 0,
+            transmitted_bssid: bits & (1 << 3) != 0,
+            member_of_ess_with_colocated_ap: bits & (1 << 4) != 0,
+            unsolicited_probe_responses_active: bits & (1 << 5) != 0,
+            colocated_ap: bits & (1 << 6) != 0,
+        }
+    }
+
+    pub fn encode(&self) -> u8 {
+        let mut bits = self.oct_recommended as u8;
+        bits |= (self.same_ssid as u8) << 1;
+        bits |= (self.multiple_bssid as u8) << 2;
+        bits |= (self.transmitted_bssid as u8) << 3;
+        bits |= (self.member_of_ess_with_colocated_ap as u8) << 4;
+        bits |= (self.unsolicited_probe_responses_active as u8) << 5;
+        bits |= (self.colocated_ap as u8) << 6;
+
+        bits
+    }
+}
+
+/// The MLD Parameters of a TBTT Information field, which are sent by APs affiliated with an AP MLD.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RnrMldParameters {
+    pub ap_mld_id: u8,                   // bit 0-7
+    pub link_id: u8,                     // 8-11
+    pub bss_parameters_change_count: u8, // 12-19
+    pub all_updates_included: bool,      // 20
+    pub disabled_link_indication: bool,  // 21
+}
+
+impl RnrMldParameters {
+    pub fn from_bytes(bytes: [u8; 3]) -> Self {
+        let bits = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]);
+        RnrMldParameters {
+            ap_mld_id: bits as u8,
+            link_id: ((bits >> 8) & 0x0f) as u8,
+            bss_parameters_change_count: (bits >> 12) as u8,
+            all_updates_included: bits & (1 << 20) != 0,
+            disabled_link_indication: bits & (1 << 21) != 0,
+        }
+    }
+
+    pub fn encode(&self) -> [u8; 3] {
+        let mut bits = self.ap_mld_id as u32;
+        bits |= ((self.link_id & 0x0f) as u32) << 8;
+        bits |= (self.bss_parameters_change_count as u32) << 12;
+        bits |= (self.all_updates_included as u32) << 20;
+        bits |= (self.disabled_link_indication as u32) << 21;
+
+        let bytes = bits.to_le_bytes();
+        [bytes[0], bytes[1], bytes[2]]
+    }
+}
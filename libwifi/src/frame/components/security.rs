@@ -0,0 +1,110 @@
+use std::fmt;
+
+/// The RSN Extension element (RSNXE, Element ID 244).
+///
+/// WPA3 networks use it to announce additional security capabilities, most notably
+/// support for the SAE hash-to-element method.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RsnExtension {
+    pub protected_twt_operations: bool, // bit 4
+    pub sae_hash_to_element: bool,      // 5
+    pub sae_pk: bool,                   // 6
+    pub protected_wur_frames: bool,     // 7
+    /// Secure LTF support, also known as protected HE-LTF.
+    pub secure_ltf: bool, // 8
+    pub secure_rtt: bool,               // 9
+    pub urnm_mfpr_x20: bool,            // 10
+    pub protected_announce: bool,       // 11
+    pub pbac: bool,                     // 12
+    /// The length of the capability field in bytes, which is announced in its lower 4 bits.
+    ///
+    /// The field is extended to the bytes needed by the set capabilities if this is too short.
+    pub length: u8,
+    /// Any further capability bytes within `length` that aren't handled yet.
+    pub other_data: Vec<u8>,
+    /// Bytes after the capability field, which are reserved for future extensions.
+    pub trailing_data: Vec<u8>,
+}
+
+impl RsnExtension {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut first = 0u8;
+        first |= (self.protected_twt_operations as u8) << 4;
+        first |= (self.sae_hash_to_element as u8) << 5;
+        first |= (self.sae_pk as u8) << 6;
+        first |= (self.protected_wur_frames as u8) << 7;
+
+        let mut second = 0u8;
+        second |= self.secure_ltf as u8;
+        second |= (self.secure_rtt as u8) << 1;
+        second |= (self.urnm_mfpr_x20 as u8) << 2;
+        second |= (self.protected_announce as u8) << 3;
+        second |= (self.pbac as u8) << 4;
+
+        let mut bytes = vec![first];
+        if second != 0 || !self.other_data.is_empty() || self.length > 1 {
+            bytes.push(second);
+            bytes.extend(&self.other_data);
+        }
+        bytes.resize(bytes.len().max(self.length as usize), 0);
+
+        // The lower 4 bits contain the length of the capability field minus one.
+        bytes[0] |= (bytes.len() as u8 - 1) & 0x0f;
+        bytes.extend(&self.trailing_data);
+
+        bytes
+    }
+}
+
+/// The security configuration of a network, as derived from the RSN, RSNXE, WPA and
+/// Extended Capabilities elements by [StationInfo::security_profile].
+///
+/// [StationInfo::security_profile]: super::StationInfo::security_profile
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SecurityProfile {
+    /// No RSN or WPA element is present.
+    Open,
+    /// Opportunistic Wireless Encryption (Enhanced Open).
+    Owe,
+    /// Legacy WPA without an RSN element.
+    Wpa,
+    Wpa2Psk,
+    Wpa3Sae(SaeCapabilities),
+    /// SAE and PSK are both offered (WPA2/WPA3 mixed mode).
+    Wpa3Transition(SaeCapabilities),
+    /// 802.1X authentication (WPA2/WPA3-Enterprise).
+    Enterprise,
+    /// WPA3-Enterprise 192-bit mode (Suite B).
+    Enterprise192,
+    /// An RSN element without any known AKM suite.
+    Unknown,
+}
+
+impl fmt::Display for SecurityProfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SecurityProfile::Open => write!(f, "Open"),
+            SecurityProfile::Owe => write!(f, "OWE"),
+            SecurityProfile::Wpa => write!(f, "WPA"),
+            SecurityProfile::Wpa2Psk => write!(f, "WPA2-PSK"),
+            SecurityProfile::Wpa3Sae(_) => write!(f, "WPA3-SAE"),
+            SecurityProfile::Wpa3Transition(_) => write!(f, "WPA3-Transition"),
+            SecurityProfile::Enterprise => write!(f, "Enterprise"),
+            SecurityProfile::Enterprise192 => write!(f, "Enterprise-192"),
+            SecurityProfile::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// SAE related capabilities of a WPA3 network.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SaeCapabilities {
+    /// Taken from the RSNXE.
+    pub hash_to_element: bool,
+    /// Taken from the RSNXE.
+    pub sae_pk: bool,
+    /// Taken from the Extended Capabilities.
+    pub password_identifiers_in_use: bool,
+    /// Taken from the Extended Capabilities.
+    pub password_identifiers_used_exclusively: bool,
+}
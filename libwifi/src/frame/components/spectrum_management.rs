@@ -0,0 +1,90 @@
+/// The Power Capability element (Element ID 33), which is sent by stations in association requests.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PowerCapability {
+    /// The minimum transmit power in dBm.
+    pub min_tx_power: i8,
+    /// The maximum transmit power in dBm.
+    pub max_tx_power: i8,
+}
+
+impl PowerCapability {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![self.min_tx_power as u8, self.max_tx_power as u8]
+    }
+}
+
+/// The TPC Report element (Element ID 35).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TpcReport {
+    /// The transmit power used to send the frame containing this element in dBm.
+    pub tx_power: i8,
+    /// The link margin in dB. Reserved in beacons and probe responses.
+    pub link_margin: i8,
+}
+
+impl TpcReport {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![self.tx_power as u8, self.link_margin as u8]
+    }
+}
+
+/// The Supported Channels element (Element ID 36), which is sent by stations in association requests.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SupportedChannels {
+    pub subbands: Vec<ChannelSubband>,
+}
+
+impl SupportedChannels {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for subband in &self.subbands {
+            bytes.extend([subband.first_channel, subband.number_of_channels]);
+        }
+
+        bytes
+    }
+
+    /// Get all supported channel numbers.
+    ///
+    /// Channels are spaced by 1 on 2.4 GHz (channels 1-14) and by 4 on all other bands.
+    /// Channel numbers above 255, which can only result from malformed subbands, are skipped.
+    pub fn channels(&self) -> Vec<u8> {
+        self.subbands
+            .iter()
+            .flat_map(|subband| {
+                let spacing = if subband.first_channel <= 14 { 1 } else { 4 };
+                (0..subband.number_of_channels as u16)
+                    .map(move |index| subband.first_channel as u16 + index * spacing)
+                    .map_while(|channel| u8::try_from(channel).ok())
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChannelSubband {
+    pub first_channel: u8,
+    pub number_of_channels: u8,
+}
+
+/// The Quiet element (Element ID 40), which schedules an interval during which no station may transmit.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Quiet {
+    /// The number of TBTTs until the quiet interval starts.
+    pub count: u8,
+    /// The number of beacon intervals between quiet intervals, `0` means no periodic quiet interval.
+    pub period: u8,
+    /// The duration of the quiet interval in TUs.
+    pub duration: u16,
+    /// The offset of the quiet interval from the TBTT in TUs.
+    pub offset: u16,
+}
+
+impl Quiet {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.count, self.period];
+        bytes.extend(self.duration.to_le_bytes());
+        bytes.extend(self.offset.to_le_bytes());
+        bytes
+    }
+}
@@ -2,6 +2,25 @@ use std::fmt::{self, Display};
 
 use bitvec::{field::BitField, order::Lsb0, vec::BitVec};
 
+use super::{
+    AdvertisementProtocol, BssLoad, ChannelSwitchTarget, ChannelSwitchWrapper, EdcaParameterSet,
+    EhtCapabilities, EhtOperation, ErpInformation, ExtendedBssLoad,
+    ExtendedChannelSwitchAnnouncement, FastTransition, FtmParameters, GateAnnouncement,
+    Hs20Indication, Interworking, LinkIdentifier, MacAddress, MeasurementReport,
+    MeasurementRequest, MeshConfiguration, MeshPeeringManagement, MobilityDomain,
+    MuEdcaParameterSet, MultiLink, MultipleBSSID, MultipleBssidIndex, NeighborReport,
+    NonInheritance, NontransmittedBss, P2pAttribute, PathError, PathReply, PathRequest,
+    PowerCapability, Quiet, ReducedNeighborReport, RoamingConsortium, RootAnnouncement,
+    RsnExtension, SaeCapabilities, SecurityProfile, SupportedChannels, TfsRequestElement,
+    TfsResponseElement, TimeoutInterval, TpcReport, WFA_OUI, WideBandwidthChannelSwitch, Wmm,
+    WnmSleepMode, channel_to_frequency, operating_class_bandwidth,
+};
+use super::{CustomElements, DecodedVendorElement, InformationElement};
+use crate::error::Error;
+use crate::parsers::{
+    ElementRegistry, IeIter, parse_element_list, parse_non_inheritance, parse_p2p_attributes,
+};
+
 #[derive(Clone, Debug, Default)]
 /// StationInfo is used to parse and store variable length fields that are often sent
 /// with management frames.
@@ -27,28 +46,119 @@ pub struct StationInfo {
     pub tim: Option<Vec<u8>>,
     pub country_info: Option<Vec<u8>>,
     pub power_constraint: Option<u8>,
+    pub power_capability: Option<PowerCapability>,
+    pub tpc_report: Option<TpcReport>,
+    pub supported_channels: Option<SupportedChannels>,
+    /// APs may schedule multiple quiet intervals with one Quiet element each.
+    pub quiet: Vec<Quiet>,
+    pub bss_load: Option<BssLoad>,
+    pub erp_information: Option<ErpInformation>,
     pub ht_capabilities: Option<HTCapabilities>,
     pub ht_information: Option<HTInformation>,
-    pub multiple_bssid: Option<MultipleBSSID>,
+    pub edca_parameter_set: Option<EdcaParameterSet>,
+    /// APs with many virtual APs often send one Multiple BSSID element per nontransmitted BSS.
+    pub multiple_bssids: Vec<MultipleBSSID>,
+    /// Only sent inside of Nontransmitted BSSID Profiles.
+    pub nontransmitted_bssid_capability: Option<u16>,
+    /// Only sent inside of Nontransmitted BSSID Profiles.
+    pub multiple_bssid_index: Option<MultipleBssidIndex>,
     pub vht_capabilities: Option<VHTCapabilities>,
+    pub extended_bss_load: Option<ExtendedBssLoad>,
     pub rsn_information: Option<RsnInformation>,
+    pub rsn_extension: Option<RsnExtension>,
+    pub mobility_domain: Option<MobilityDomain>,
+    pub fast_transition: Option<FastTransition>,
+    pub timeout_interval: Option<TimeoutInterval>,
     pub wpa_info: Option<WpaInformation>,
     pub wps_info: Option<WpsInformation>,
+    /// The WMM Information or WMM Parameter vendor specific element.
+    pub wmm: Option<Wmm>,
     pub vendor_specific: Vec<VendorSpecificInfo>,
     pub extended_capabilities: Option<ExtendedCapabilities>,
+    pub interworking: Option<Interworking>,
+    pub advertisement_protocol: Option<AdvertisementProtocol>,
+    pub roaming_consortium: Option<RoamingConsortium>,
+    /// The WFA Hotspot 2.0 Indication vendor specific element.
+    pub hs20_indication: Option<Hs20Indication>,
+    /// The raw Mesh ID, which is the SSID equivalent of 802.11s mesh networks.
+    pub mesh_id: Option<Vec<u8>>,
+    pub mesh_configuration: Option<MeshConfiguration>,
+    pub mesh_peering_management: Option<MeshPeeringManagement>,
+    /// The mesh awake window in TUs.
+    pub mesh_awake_window: Option<u16>,
+    /// Only sent in Gate Announcement frames.
+    pub gate_announcement: Option<GateAnnouncement>,
+    /// The following HWMP elements are only sent in HWMP Mesh Path Selection frames.
+    pub root_announcement: Option<RootAnnouncement>,
+    pub path_requests: Vec<PathRequest>,
+    pub path_replies: Vec<PathReply>,
+    pub path_error: Option<PathError>,
     pub channel_switch: Option<ChannelSwitchAnnouncment>,
+    pub extended_channel_switch: Option<ExtendedChannelSwitchAnnouncement>,
+    pub secondary_channel_offset: Option<SecondaryChannelOffset>,
+    pub wide_bandwidth_channel_switch: Option<WideBandwidthChannelSwitch>,
+    pub channel_switch_wrapper: Option<ChannelSwitchWrapper>,
+    /// The maximum time in TUs until the channel switch is completed.
+    pub max_channel_switch_time: Option<u32>,
     pub he_capabilities: Option<Vec<u8>>,
+    pub mu_edca_parameter_set: Option<MuEdcaParameterSet>,
+    pub eht_capabilities: Option<EhtCapabilities>,
+    pub eht_operation: Option<EhtOperation>,
+    pub multi_link: Option<MultiLink>,
+    /// APs may send multiple Reduced Neighbor Report elements, if a single one is too small.
+    pub reduced_neighbor_reports: Vec<ReducedNeighborReport>,
+    /// Only sent inside of Nontransmitted BSSID Profiles and per-STA profiles.
+    pub non_inheritance: Option<NonInheritance>,
+    /// Only sent in Radio Measurement Request frames.
+    pub measurement_requests: Vec<MeasurementRequest>,
+    /// Only sent in Radio Measurement Report frames.
+    pub measurement_reports: Vec<MeasurementReport>,
+    /// Sent in Neighbor Report Response and BSS Transition Management Request frames.
+    pub neighbor_reports: Vec<NeighborReport>,
+    /// Only sent in WNM Sleep Mode Request and Response frames.
+    pub wnm_sleep_mode: Option<WnmSleepMode>,
+    /// Sent in TFS Request and WNM Sleep Mode Request frames.
+    pub tfs_requests: Vec<TfsRequestElement>,
+    /// Sent in TFS Response and WNM Sleep Mode Response frames.
+    pub tfs_responses: Vec<TfsResponseElement>,
+    /// Only sent in FTM Request and FTM frames.
+    pub ftm_parameters: Option<FtmParameters>,
+    /// The TSF Sync Info of the FTM Synchronization Information element, which is only sent in
+    /// FTM frames.
+    pub ftm_synchronization_information: Option<u32>,
+    /// Only sent in TDLS frames.
+    pub link_identifier: Option<LinkIdentifier>,
     /// Contains all fields that aren't explicitly parsed by us.
     /// The format is Vec<(FieldId, PayloadBytes)>.
     ///
     /// Fields may occur multiple times.
     pub data: Vec<(u8, Vec<u8>)>,
+    /// All elements that have been decoded by custom parsers.
+    /// See [ElementRegistry::register_element_parser].
+    pub custom_elements: CustomElements,
+    /// All received elements in their original order and raw form, including those that
+    /// have been decoded into the fields above.
+    ///
+    /// This is only populated by the parsers, unless disabled via
+    /// [ElementRegistry::set_keep_elements].
+    pub elements: Vec<InformationElement>,
 }
 
 impl StationInfo {
+    /// Encode all elements from the typed fields in a fixed order.
+    ///
+    /// Elements without a typed encoder, like unknown extension elements, are lost.
+    /// Use [StationInfo::encode_elements] to re-encode the received elements as they are.
     pub fn encode(&self) -> Vec<u8> {
         let mut bytes = Vec::new();
 
+        // Encode Nontransmitted BSSID Capability (if present) - Tag Number: 83
+        if let Some(capability) = self.nontransmitted_bssid_capability {
+            bytes.push(83);
+            bytes.push(2);
+            bytes.extend(capability.to_le_bytes());
+        }
+
         // Encode SSID (if present)
         if let Some(ssid) = &self.ssid {
             bytes.push(0); // ID
@@ -56,6 +166,14 @@ impl StationInfo {
             bytes.extend_from_slice(ssid.as_bytes()); // SSID as bytes
         }
 
+        // Encode Multiple BSSID-Index (if present) - Tag Number: 85
+        if let Some(index) = &self.multiple_bssid_index {
+            let data = index.encode();
+            bytes.push(85);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
         if !self.supported_rates.is_empty() {
             // Encode Supported Rates
             bytes.push(1); // ID
@@ -123,6 +241,53 @@ impl StationInfo {
             bytes.push(power_constraint);
         }
 
+        // Encode Power Capability (if present) - Tag Number: 33
+        if let Some(power_capability) = &self.power_capability {
+            let data = power_capability.encode();
+            bytes.push(33);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode TPC Report (if present) - Tag Number: 35
+        if let Some(tpc_report) = &self.tpc_report {
+            let data = tpc_report.encode();
+            bytes.push(35);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Supported Channels (if present) - Tag Number: 36
+        if let Some(supported_channels) = &self.supported_channels {
+            let data = supported_channels.encode();
+            bytes.push(36);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Quiet elements - Tag Number: 40
+        for quiet in &self.quiet {
+            let data = quiet.encode();
+            bytes.push(40);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode BSS Load (if present) - Tag Number: 11
+        if let Some(bss_load) = &self.bss_load {
+            let data = bss_load.encode();
+            bytes.push(11);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode ERP Information (if present) - Tag Number: 42
+        if let Some(erp_information) = &self.erp_information {
+            bytes.push(42);
+            bytes.push(1);
+            bytes.push(erp_information.encode());
+        }
+
         // Encode HT Capabilities (if present) - Tag Number: 45
         if let Some(ht_capabilities) = &self.ht_capabilities {
             bytes.push(45); // HT Capabilities tag number
@@ -139,8 +304,16 @@ impl StationInfo {
             bytes.extend(ht_info_data);
         }
 
-        // Encode Multiple BSSID (if present) - Tag Number: 71
-        if let Some(multiple_bssid) = &self.multiple_bssid {
+        // Encode EDCA Parameter Set (if present) - Tag Number: 12
+        if let Some(edca_parameter_set) = &self.edca_parameter_set {
+            let data = edca_parameter_set.encode();
+            bytes.push(12);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Multiple BSSIDs - Tag Number: 71
+        for multiple_bssid in &self.multiple_bssids {
             let multiple_bssid_data = multiple_bssid.encode();
             bytes.push(71);
             bytes.push(multiple_bssid_data.len() as u8);
@@ -154,6 +327,14 @@ impl StationInfo {
             bytes.extend(&vht_capabilities.data);
         }
 
+        // Encode Extended BSS Load (if present) - Tag Number: 193
+        if let Some(extended_bss_load) = &self.extended_bss_load {
+            let data = extended_bss_load.encode();
+            bytes.push(193);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
         // Encode RSN Information (if present) - Tag Number: 48
         if let Some(rsn_info) = &self.rsn_information {
             bytes.push(48); // RSN Information tag number
@@ -162,6 +343,38 @@ impl StationInfo {
             bytes.extend(rsn_encoded);
         }
 
+        // Encode RSN Extension (if present) - Tag Number: 244
+        if let Some(rsn_extension) = &self.rsn_extension {
+            let data = rsn_extension.encode();
+            bytes.push(244);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Mobility Domain (if present) - Tag Number: 54
+        if let Some(mobility_domain) = &self.mobility_domain {
+            let data = mobility_domain.encode();
+            bytes.push(54);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Fast BSS Transition (if present) - Tag Number: 55
+        if let Some(fast_transition) = &self.fast_transition {
+            let data = fast_transition.encode();
+            bytes.push(55);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Timeout Interval (if present) - Tag Number: 56
+        if let Some(timeout_interval) = &self.timeout_interval {
+            let data = timeout_interval.encode();
+            bytes.push(56);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
         // Encode WPA Information (if present) - This is usually vendor-specific
         // WPA Information uses the vendor-specific tag number (221) with the specific OUI for WPA
         if let Some(wpa_info) = &self.wpa_info {
@@ -171,6 +384,22 @@ impl StationInfo {
             bytes.extend(wpa_encoded);
         }
 
+        // Encode WMM (if present) - Vendor specific with OUI 00:50:f2 and type 2
+        if let Some(wmm) = &self.wmm {
+            let data = wmm.encode();
+            bytes.push(221);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Hotspot 2.0 Indication (if present) - Vendor specific with OUI 50:6f:9a and type 0x10
+        if let Some(hs20_indication) = &self.hs20_indication {
+            let data = hs20_indication.encode();
+            bytes.push(221);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
         // Encode Vendor Specific Info
         for vendor_info in &self.vendor_specific {
             bytes.push(vendor_info.element_id);
@@ -188,6 +417,100 @@ impl StationInfo {
             bytes.extend(data);
         }
 
+        // Encode Interworking (if present) - Tag Number: 107
+        if let Some(interworking) = &self.interworking {
+            let data = interworking.encode();
+            bytes.push(107);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Advertisement Protocol (if present) - Tag Number: 108
+        if let Some(advertisement_protocol) = &self.advertisement_protocol {
+            let data = advertisement_protocol.encode();
+            bytes.push(108);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Roaming Consortium (if present) - Tag Number: 111
+        if let Some(roaming_consortium) = &self.roaming_consortium {
+            let data = roaming_consortium.encode();
+            bytes.push(111);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Mesh ID (if present) - Tag Number: 114
+        if let Some(mesh_id) = &self.mesh_id {
+            bytes.push(114);
+            bytes.push(mesh_id.len() as u8);
+            bytes.extend(mesh_id);
+        }
+
+        // Encode Mesh Configuration (if present) - Tag Number: 113
+        if let Some(mesh_configuration) = &self.mesh_configuration {
+            let data = mesh_configuration.encode();
+            bytes.push(113);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Mesh Peering Management (if present) - Tag Number: 117
+        if let Some(mesh_peering_management) = &self.mesh_peering_management {
+            let data = mesh_peering_management.encode();
+            bytes.push(117);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Mesh Awake Window (if present) - Tag Number: 119
+        if let Some(mesh_awake_window) = self.mesh_awake_window {
+            bytes.push(119);
+            bytes.push(2);
+            bytes.extend(mesh_awake_window.to_le_bytes());
+        }
+
+        // Encode Gate Announcement (if present) - Tag Number: 125
+        if let Some(gate_announcement) = &self.gate_announcement {
+            let data = gate_announcement.encode();
+            bytes.push(125);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Root Announcement (if present) - Tag Number: 126
+        if let Some(root_announcement) = &self.root_announcement {
+            let data = root_announcement.encode();
+            bytes.push(126);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Path Requests - Tag Number: 130
+        for path_request in &self.path_requests {
+            let data = path_request.encode();
+            bytes.push(130);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Path Replies - Tag Number: 131
+        for path_reply in &self.path_replies {
+            let data = path_reply.encode();
+            bytes.push(131);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Path Error (if present) - Tag Number: 132
+        if let Some(path_error) = &self.path_error {
+            let data = path_error.encode();
+            bytes.push(132);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
         if let Some(chan_switch) = &self.channel_switch {
             let encoded = chan_switch.encode();
             bytes.push(37);
@@ -195,6 +518,142 @@ impl StationInfo {
             bytes.extend(encoded);
         }
 
+        // Encode Extended Channel Switch Announcement (if present) - Tag Number: 60
+        if let Some(extended_channel_switch) = &self.extended_channel_switch {
+            let data = extended_channel_switch.encode();
+            bytes.push(60);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Secondary Channel Offset (if present) - Tag Number: 62
+        if let Some(secondary_channel_offset) = self.secondary_channel_offset {
+            bytes.push(62);
+            bytes.push(1);
+            bytes.push(secondary_channel_offset as u8);
+        }
+
+        // Encode Wide Bandwidth Channel Switch (if present) - Tag Number: 194
+        if let Some(wide_bandwidth_channel_switch) = &self.wide_bandwidth_channel_switch {
+            let data = wide_bandwidth_channel_switch.encode();
+            bytes.push(194);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Channel Switch Wrapper (if present) - Tag Number: 196
+        if let Some(channel_switch_wrapper) = &self.channel_switch_wrapper {
+            let data = channel_switch_wrapper.encode();
+            bytes.push(196);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode MU EDCA Parameter Set (if present) - Extension ID: 38
+        if let Some(mu_edca_parameter_set) = &self.mu_edca_parameter_set {
+            encode_extension_element(&mut bytes, 38, &mu_edca_parameter_set.encode());
+        }
+
+        // Encode Max Channel Switch Time (if present) - Extension ID: 52
+        if let Some(max_channel_switch_time) = self.max_channel_switch_time {
+            encode_extension_element(&mut bytes, 52, &max_channel_switch_time.to_le_bytes()[..3]);
+        }
+
+        // Encode EHT Capabilities (if present) - Extension ID: 108
+        if let Some(eht_capabilities) = &self.eht_capabilities {
+            encode_extension_element(&mut bytes, 108, &eht_capabilities.encode());
+        }
+
+        // Encode EHT Operation (if present) - Extension ID: 106
+        if let Some(eht_operation) = &self.eht_operation {
+            encode_extension_element(&mut bytes, 106, &eht_operation.encode());
+        }
+
+        // Encode Multi-Link (if present) - Extension ID: 107
+        if let Some(multi_link) = &self.multi_link {
+            encode_extension_element(&mut bytes, 107, &multi_link.encode());
+        }
+
+        // Encode Reduced Neighbor Reports - Tag Number: 201
+        for report in &self.reduced_neighbor_reports {
+            let data = report.encode();
+            bytes.push(201);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Measurement Requests - Tag Number: 38
+        for request in &self.measurement_requests {
+            let data = request.encode();
+            bytes.push(38);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Measurement Reports - Tag Number: 39
+        for report in &self.measurement_reports {
+            let data = report.encode();
+            bytes.push(39);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode Neighbor Reports - Tag Number: 52
+        for report in &self.neighbor_reports {
+            let data = report.encode();
+            bytes.push(52);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode WNM Sleep Mode (if present) - Tag Number: 93
+        if let Some(sleep_mode) = &self.wnm_sleep_mode {
+            let data = sleep_mode.encode();
+            bytes.push(93);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode TFS Requests - Tag Number: 91
+        for request in &self.tfs_requests {
+            let data = request.encode();
+            bytes.push(91);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode TFS Responses - Tag Number: 92
+        for response in &self.tfs_responses {
+            let data = response.encode();
+            bytes.push(92);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode FTM Parameters (if present) - Tag Number: 206
+        if let Some(ftm_parameters) = &self.ftm_parameters {
+            let data = ftm_parameters.encode();
+            bytes.push(206);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode FTM Synchronization Information (if present) - Extension ID: 9
+        if let Some(tsf_sync_info) = self.ftm_synchronization_information {
+            encode_extension_element(&mut bytes, 9, &tsf_sync_info.to_le_bytes());
+        }
+
+        // Encode Link Identifier (if present) - Tag Number: 101
+        if let Some(link_identifier) = &self.link_identifier {
+            let data = link_identifier.encode();
+            bytes.push(101);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        // Encode elements of custom parsers
+        bytes.extend(self.custom_elements.encode());
+
         // Encode additional data
         for (id, data) in &self.data {
             bytes.push(*id);
@@ -202,9 +661,131 @@ impl StationInfo {
             bytes.extend(data);
         }
 
+        // Encode Non-Inheritance (if present) - Extension ID: 56
+        // This has to be the last element of a profile.
+        if let Some(non_inheritance) = &self.non_inheritance {
+            encode_extension_element(&mut bytes, 56, &non_inheritance.encode());
+        }
+
         bytes
     }
 
+    /// Encode the received [StationInfo::elements] in their original order, which results in
+    /// the exact same bytes as they've been parsed from.
+    ///
+    /// Modifications of the typed fields are ignored.
+    /// This is empty, unless the elements have been kept while parsing.
+    pub fn encode_elements(&self) -> Vec<u8> {
+        self.elements.iter().flat_map(|e| e.encode()).collect()
+    }
+
+    /// Encode the elements of a frame, which is used when encoding parsed frames.
+    ///
+    /// If the received [StationInfo::elements] have been kept and the typed fields haven't
+    /// been modified since, they're encoded as they are, which results in the exact same bytes.
+    /// Otherwise, the typed fields are encoded via [StationInfo::encode].
+    ///
+    /// Modifications are detected by comparing the typed encoding with the one of the received
+    /// elements, parsed with the built-in parsers.
+    /// If there are elements of custom parsers, the typed fields are therefore always encoded.
+    pub fn encode_lossless(&self) -> Vec<u8> {
+        let encoded = self.encode();
+        if self.elements.is_empty() {
+            return encoded;
+        }
+
+        let received = self.encode_elements();
+        match parse_element_list(&received, ElementRegistry::builtin()) {
+            Ok((_, parsed)) if parsed.encode() == encoded => received,
+            _ => encoded,
+        }
+    }
+
+    /// Build the complete set of elements of a nontransmitted BSSID profile or a per-STA
+    /// profile.
+    ///
+    /// All elements of `parent` (the frame that carries the profile) are inherited, unless
+    /// the profile contains an element with the same id or the element is listed in the
+    /// profile's Non-Inheritance element.
+    ///
+    /// The elements are merged in their raw form, i.e. the received [StationInfo::elements]
+    /// if they have been kept, and the combined elements are parsed with `registry`.
+    /// Fails if they can't be parsed, e.g. because an element is too long.
+    pub fn with_inherited(
+        &self,
+        parent: &StationInfo,
+        registry: &ElementRegistry,
+    ) -> Result<StationInfo, Error> {
+        inherit_elements(&self.raw_elements(), &parent.raw_elements(), registry)
+    }
+
+    /// Expand all Nontransmitted BSSID Profiles of the Multiple BSSID elements into
+    /// their respective virtual APs.
+    ///
+    /// `transmitted_bssid` is the BSSID of the frame that carries this StationInfo.
+    /// Profiles that don't fit into a single Multiple BSSID element are continued in the first
+    /// profile of the next one, which is joined with the previous profile as it doesn't
+    /// contain a Nontransmitted BSSID Capability element.
+    /// Profiles without a Multiple BSSID-Index element are skipped, as their BSSID can't
+    /// be determined, as well as profiles that can't be combined with the inherited elements.
+    ///
+    /// The profiles are taken from the received [StationInfo::elements] if they have been
+    /// kept, and are parsed with `registry`, see [StationInfo::with_inherited].
+    pub fn nontransmitted_bsses(
+        &self,
+        transmitted_bssid: &MacAddress,
+        registry: &ElementRegistry,
+    ) -> Vec<NontransmittedBss> {
+        let raw_elements = self.raw_elements();
+
+        // The raw profiles with the MaxBSSID Indicator of their Multiple BSSID element.
+        let mut profiles: Vec<(u8, Vec<u8>)> = Vec::new();
+        for (_, _, data) in IeIter::new(&raw_elements).filter(|(id, _, _)| *id == 71) {
+            let Some((max_bssid_indicator, subelements)) = data.split_first() else {
+                continue;
+            };
+            let profile_data = IeIter::new(subelements)
+                .filter(|(subelement_id, _, _)| *subelement_id == 0)
+                .map(|(_, _, profile)| profile);
+            for (position, profile) in profile_data.enumerate() {
+                let continued = position == 0 && !IeIter::new(profile).any(|(id, _, _)| id == 83);
+                match profiles.last_mut() {
+                    Some((_, bytes)) if continued => bytes.extend_from_slice(profile),
+                    _ => profiles.push((*max_bssid_indicator, profile.to_vec())),
+                }
+            }
+        }
+
+        profiles
+            .into_iter()
+            .filter_map(|(max_bssid_indicator, bytes)| {
+                let (_, profile) = parse_element_list(&bytes, registry).ok()?;
+                let index = profile.multiple_bssid_index.as_ref()?;
+                let multiple_bssid = MultipleBSSID {
+                    max_bssid_indicator,
+                    profiles: Vec::new(),
+                    other_data: Vec::new(),
+                };
+
+                Some(NontransmittedBss {
+                    bssid: multiple_bssid.bssid(transmitted_bssid, index.bssid_index),
+                    bssid_index: index.bssid_index,
+                    capability_info: profile.nontransmitted_bssid_capability,
+                    station_info: inherit_elements(&bytes, &raw_elements, registry).ok()?,
+                })
+            })
+            .collect()
+    }
+
+    /// The received elements if they have been kept, otherwise the encoded typed fields.
+    fn raw_elements(&self) -> Vec<u8> {
+        if self.elements.is_empty() {
+            self.encode()
+        } else {
+            self.encode_elements()
+        }
+    }
+
     // Get the SSID from the station_info
     pub fn ssid(&self) -> String {
         match &self.ssid {
@@ -244,6 +825,199 @@ impl StationInfo {
     pub fn wpa_info(&self) -> Option<&WpaInformation> {
         self.wpa_info.as_ref()
     }
+
+    /// Decode the P2P attributes of all WFA P2P vendor specific elements.
+    ///
+    /// Attributes may be fragmented across multiple elements, so the payloads are concatenated
+    /// before they're parsed.
+    /// Returns `None` if there's no P2P element.
+    pub fn p2p_attributes(&self) -> Option<Vec<P2pAttribute>> {
+        let mut payload = Vec::new();
+        let mut found = false;
+        for vendor_info in &self.vendor_specific {
+            if vendor_info.oui == WFA_OUI && vendor_info.oui_type == 0x09 {
+                payload.extend(&vendor_info.data);
+                found = true;
+            }
+        }
+        if !found {
+            return None;
+        }
+
+        parse_p2p_attributes(&payload)
+            .ok()
+            .map(|(_, attributes)| attributes)
+    }
+
+    /// Compute the channel this BSS is going to switch to.
+    ///
+    /// The new channel is taken from the (Extended) Channel Switch Announcement, the new
+    /// bandwidth from the Wide Bandwidth Channel Switch (which may be wrapped in a Channel
+    /// Switch Wrapper), the new operating class or the Secondary Channel Offset.
+    /// Returns `None` if no channel switch is announced.
+    pub fn channel_switch_target(&self) -> Option<ChannelSwitchTarget> {
+        let (channel, operating_class) = match (&self.extended_channel_switch, &self.channel_switch)
+        {
+            (Some(ecsa), _) => (ecsa.new_channel, Some(ecsa.new_operating_class)),
+            (None, Some(csa)) => (csa.new_channel, None),
+            (None, None) => return None,
+        };
+
+        let wide_bandwidth = self
+            .wide_bandwidth_channel_switch
+            .as_ref()
+            .or_else(|| {
+                self.channel_switch_wrapper
+                    .as_ref()
+                    .and_then(|wrapper| wrapper.wide_bandwidth_channel_switch())
+            })
+            .and_then(|switch| switch.bandwidth());
+
+        let bandwidth = wide_bandwidth
+            .or_else(|| operating_class.and_then(operating_class_bandwidth))
+            .unwrap_or(match self.secondary_channel_offset {
+                Some(SecondaryChannelOffset::Above | SecondaryChannelOffset::Below) => 40,
+                _ => 20,
+            });
+
+        Some(ChannelSwitchTarget {
+            channel,
+            operating_class,
+            primary_frequency: channel_to_frequency(channel, operating_class),
+            bandwidth,
+        })
+    }
+
+    /// Determine the security configuration of this network.
+    ///
+    /// The AKM suites of the RSN element determine the profile, the RSNXE and the
+    /// Extended Capabilities add the SAE specific details.
+    /// If multiple AKM suites are offered, the strongest one wins, except for networks that
+    /// offer both SAE and PSK, which are reported as [SecurityProfile::Wpa3Transition].
+    pub fn security_profile(&self) -> SecurityProfile {
+        let Some(rsn) = &self.rsn_information else {
+            if self.wpa_info.is_some() {
+                return SecurityProfile::Wpa;
+            }
+            return SecurityProfile::Open;
+        };
+
+        let has = |suites: &[RsnAkmSuite]| rsn.akm_suites.iter().any(|akm| suites.contains(akm));
+
+        if has(&[RsnAkmSuite::SUITEBEAP384, RsnAkmSuite::EAPFT384]) {
+            return SecurityProfile::Enterprise192;
+        }
+        if has(&[
+            RsnAkmSuite::EAP,
+            RsnAkmSuite::EAPFT,
+            RsnAkmSuite::EAP256,
+            RsnAkmSuite::SUITEBEAP256,
+        ]) {
+            return SecurityProfile::Enterprise;
+        }
+
+        let sae = has(&[
+            RsnAkmSuite::SAE,
+            RsnAkmSuite::SAEFT,
+            RsnAkmSuite::SAEEXTKEY,
+            RsnAkmSuite::SAEEXTKEYFT,
+        ]);
+        let psk = has(&[RsnAkmSuite::PSK, RsnAkmSuite::PSKFT, RsnAkmSuite::PSK256]);
+
+        if sae {
+            let rsnxe = self.rsn_extension.clone().unwrap_or_default();
+            let capabilities = SaeCapabilities {
+                hash_to_element: rsnxe.sae_hash_to_element,
+                sae_pk: rsnxe.sae_pk,
+                password_identifiers_in_use: self
+                    .extended_capabilities
+                    .as_ref()
+                    .is_some_and(|caps| caps.sae_password_in_use),
+                password_identifiers_used_exclusively: self
+                    .extended_capabilities
+                    .as_ref()
+                    .is_some_and(|caps| caps.sae_password_used_exclusively),
+            };
+
+            if psk {
+                return SecurityProfile::Wpa3Transition(capabilities);
+            }
+            return SecurityProfile::Wpa3Sae(capabilities);
+        }
+
+        if psk {
+            SecurityProfile::Wpa2Psk
+        } else if has(&[RsnAkmSuite::OWE]) {
+            SecurityProfile::Owe
+        } else {
+            SecurityProfile::Unknown
+        }
+    }
+}
+
+/// Encode an element with the Element ID Extension (255).
+/// The length includes the extension id.
+pub(crate) fn encode_extension_element(bytes: &mut Vec<u8>, extension_id: u8, data: &[u8]) {
+    bytes.push(255);
+    bytes.push(data.len() as u8 + 1);
+    bytes.push(extension_id);
+    bytes.extend_from_slice(data);
+}
+
+/// Merge the raw elements of a profile with the inherited raw elements of its parent and
+/// parse them, see [StationInfo::with_inherited].
+fn inherit_elements(
+    own: &[u8],
+    parent: &[u8],
+    registry: &ElementRegistry,
+) -> Result<StationInfo, Error> {
+    let own_elements: Vec<_> = IeIter::new(own).collect();
+    let non_inheritance = own_elements
+        .iter()
+        .find(|(id, extension_id, _)| *id == 255 && *extension_id == Some(56))
+        .and_then(|(_, _, data)| parse_non_inheritance(data).ok())
+        .map(|(_, non_inheritance)| non_inheritance);
+
+    let mut bytes = Vec::new();
+    for (element_id, extension_id, data) in &own_elements {
+        // The Non-Inheritance element only applies to this profile.
+        if *element_id == 255 && *extension_id == Some(56) {
+            continue;
+        }
+        encode_raw_element(&mut bytes, *element_id, *extension_id, data);
+    }
+
+    for (element_id, extension_id, data) in IeIter::new(parent) {
+        let overridden = own_elements
+            .iter()
+            .any(|(id, ext_id, _)| *id == element_id && *ext_id == extension_id);
+        let excluded = non_inheritance
+            .as_ref()
+            .is_some_and(|non_inheritance| non_inheritance.excludes(element_id, extension_id));
+        // The Multiple BSSID element, as well as all profile specific elements are never
+        // inherited.
+        let profile_specific =
+            matches!(element_id, 71 | 83 | 85) || (element_id == 255 && extension_id == Some(56));
+
+        if !overridden && !excluded && !profile_specific {
+            encode_raw_element(&mut bytes, element_id, extension_id, data);
+        }
+    }
+
+    let (_, station_info) = parse_element_list(&bytes, registry)?;
+    Ok(station_info)
+}
+
+/// Encode an element as it's yielded by [IeIter].
+fn encode_raw_element(bytes: &mut Vec<u8>, element_id: u8, extension_id: Option<u8>, data: &[u8]) {
+    match extension_id {
+        Some(extension_id) => encode_extension_element(bytes, extension_id, data),
+        None => {
+            bytes.push(element_id);
+            bytes.push(data.len() as u8);
+            bytes.extend_from_slice(data);
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -546,6 +1320,19 @@ pub struct VendorSpecificInfo {
 }
 
 impl VendorSpecificInfo {
+    /// Decode this element with the built-in decoders of [ElementRegistry].
+    ///
+    /// Use [VendorSpecificInfo::decode_with] to decode with your own decoders.
+    pub fn decode(&self) -> Option<DecodedVendorElement> {
+        self.decode_with(ElementRegistry::builtin())
+    }
+
+    /// Decode this element like [VendorSpecificInfo::decode], using the vendor decoders of
+    /// `registry`.
+    pub fn decode_with(&self, registry: &ElementRegistry) -> Option<DecodedVendorElement> {
+        registry.decode_vendor(self)
+    }
+
     pub fn encode(&self) -> Vec<u8> {
         let mut bytes = Vec::new();
 
@@ -760,9 +1547,15 @@ pub enum RsnAkmSuite {
     PSKFT,
     EAPFT,
     SAE,
+    SAEFT,
     SUITEBEAP256,
+    SUITEBEAP384,
+    EAPFT384,
     PSK256,
     EAP256,
+    OWE,
+    SAEEXTKEY,
+    SAEEXTKEYFT,
     Unknown(Vec<u8>),
 }
 
@@ -776,7 +1569,13 @@ impl RsnAkmSuite {
             RsnAkmSuite::EAP256 => vec![0x00, 0x0F, 0xAC, 0x05],
             RsnAkmSuite::PSK256 => vec![0x00, 0x0F, 0xAC, 0x06],
             RsnAkmSuite::SAE => vec![0x00, 0x0F, 0xAC, 0x08],
+            RsnAkmSuite::SAEFT => vec![0x00, 0x0F, 0xAC, 0x09],
             RsnAkmSuite::SUITEBEAP256 => vec![0x00, 0x0F, 0xAC, 0x0b],
+            RsnAkmSuite::SUITEBEAP384 => vec![0x00, 0x0F, 0xAC, 0x0c],
+            RsnAkmSuite::EAPFT384 => vec![0x00, 0x0F, 0xAC, 0x0d],
+            RsnAkmSuite::OWE => vec![0x00, 0x0F, 0xAC, 0x12],
+            RsnAkmSuite::SAEEXTKEY => vec![0x00, 0x0F, 0xAC, 0x18],
+            RsnAkmSuite::SAEEXTKEYFT => vec![0x00, 0x0F, 0xAC, 0x19],
             RsnAkmSuite::Unknown(data) => data.clone(),
         }
     }
@@ -846,8 +1645,7 @@ impl HTCapabilities {
         b.set(14, self.forty_mhz_intolerant);
         b.set(15, self.l_sig_tx_op_protection);
 
-        // Remove trailing zeros and convert to [u8], then vec
-        vec![b[0..b.len() - b.trailing_zeros()].load_le::<u8>()]
+        b.load_le::<u16>().to_le_bytes().to_vec()
     }
 }
 
@@ -913,8 +1711,8 @@ impl HTInformation {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SecondaryChannelOffset {
     None = 0,
-    Below = 1,
-    Above = 3,
+    Above = 1,
+    Below = 3,
 }
 
 impl From<u8> for SecondaryChannelOffset {
@@ -936,21 +1734,6 @@ pub struct VHTCapabilities {
     pub data: Vec<u8>, // TODO
 }
 
-#[derive(Debug, Clone)]
-pub struct MultipleBSSID {
-    pub max_bssid_indicator: u8,
-    pub other_data: Vec<u8>,
-}
-
-impl MultipleBSSID {
-    pub fn encode(&self) -> Vec<u8> {
-        let mut data: Vec<u8> = Vec::new();
-        data.push(self.max_bssid_indicator);
-        data.extend(&self.other_data);
-        data
-    }
-}
-
 #[derive(Debug, Clone)]
 pub struct ChannelSwitchAnnouncment {
     pub mode: ChannelSwitchMode,
@@ -1163,7 +1946,11 @@ impl ExtendedCapabilities {
         //b.set(88]=self.reserved88);
         b.set(89, self.twt_parameters_range_support);
 
-        // Remove trailing zeros and convert to [u8], then vec
-        vec![b[0..b.len() - b.trailing_zeros()].load_le::<u8>()]
+        // Convert to bytes and remove trailing zero bytes
+        let mut bytes: Vec<u8> = b.chunks(8).map(|byte| byte.load_le::<u8>()).collect();
+        while bytes.last() == Some(&0) {
+            bytes.pop();
+        }
+        bytes
     }
 }
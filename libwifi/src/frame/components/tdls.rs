@@ -0,0 +1,20 @@
+use super::MacAddress;
+
+/// The Link Identifier element (Element ID 101), which identifies a TDLS direct link.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinkIdentifier {
+    /// The BSSID of the BSS both stations are associated with.
+    pub bssid: MacAddress,
+    /// The station that sent the TDLS Setup Request or TDLS Discovery Request.
+    pub initiator: MacAddress,
+    pub responder: MacAddress,
+}
+
+impl LinkIdentifier {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.bssid.encode().to_vec();
+        bytes.extend(self.initiator.encode());
+        bytes.extend(self.responder.encode());
+        bytes
+    }
+}
@@ -0,0 +1,102 @@
+use std::{any::Any, sync::Arc};
+
+use super::{MacAddress, P2pAttribute};
+
+/// The Wi-Fi Alliance OUI, which is used by P2P, Wi-Fi Display, Multi-AP, DPP, OWE and Hotspot 2.0.
+pub const WFA_OUI: [u8; 3] = [0x50, 0x6f, 0x9a];
+pub const CISCO_OUI: [u8; 3] = [0x00, 0x40, 0x96];
+pub const ARUBA_OUI: [u8; 3] = [0x00, 0x0b, 0x86];
+pub const APPLE_OUI: [u8; 3] = [0x00, 0x17, 0xf2];
+
+/// The typed value of a vendor specific element, as produced by a vendor decoder.
+///
+/// See [VendorSpecificInfo::decode](super::VendorSpecificInfo::decode) and
+/// [ElementRegistry::decode_vendor](crate::parsers::ElementRegistry::decode_vendor).
+#[derive(Clone, Debug)]
+pub enum DecodedVendorElement {
+    /// WFA Peer-to-Peer (type 0x09)
+    P2p(Vec<P2pAttribute>),
+    /// WFA Wi-Fi Display (type 0x0a)
+    WifiDisplay(Vec<WfdSubelement>),
+    /// WFA Multi-AP (type 0x1b), which is used by EasyMesh.
+    MultiAp(MultiAp),
+    /// WFA OWE Transition Mode (type 0x1c)
+    OweTransitionMode(OweTransitionMode),
+    /// WFA DPP Configurator Connectivity (type 0x1e), which has no payload.
+    DppConfiguratorConnectivity,
+    /// The AP name of Cisco's CCX element.
+    CiscoApName(String),
+    /// The AP name of Aruba's vendor element.
+    ArubaApName(String),
+    AppleDeviceInfo(AppleDeviceInfo),
+    /// The value of a decoder that has been registered by a downstream crate.
+    /// Use `downcast_ref` to get the concrete type.
+    Custom(Arc<dyn Any + Send + Sync>),
+}
+
+/// A single Wi-Fi Display subelement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WfdSubelement {
+    pub id: u8,
+    pub data: Vec<u8>,
+}
+
+impl WfdSubelement {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.id];
+        // Wi-Fi Display uses big endian lengths.
+        bytes.extend((self.data.len() as u16).to_be_bytes());
+        bytes.extend(&self.data);
+        bytes
+    }
+
+    /// Interpret the WFD Device Information subelement (ID 0).
+    pub fn device_information(&self) -> Option<WfdDeviceInformation> {
+        if self.id != 0 || self.data.len() < 6 {
+            return None;
+        }
+
+        let field = |index: usize| u16::from_be_bytes([self.data[index], self.data[index + 1]]);
+        Some(WfdDeviceInformation {
+            device_information: field(0),
+            session_management_control_port: field(2),
+            maximum_throughput: field(4),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WfdDeviceInformation {
+    /// Bit 0-1 contain the device type (source, primary sink, secondary sink, dual-role).
+    pub device_information: u16,
+    pub session_management_control_port: u16,
+    /// The maximum average throughput in Mbps.
+    pub maximum_throughput: u16,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MultiAp {
+    pub backhaul_sta: bool,
+    pub backhaul_bss: bool,
+    pub fronthaul_bss: bool,
+    pub tear_down: bool,
+    pub profile: Option<u8>,
+    pub default_vlan_id: Option<u16>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OweTransitionMode {
+    /// The BSSID of the other (open or OWE) BSS.
+    pub bssid: MacAddress,
+    pub ssid: Vec<u8>,
+    pub operating_class: Option<u8>,
+    pub channel: Option<u8>,
+}
+
+/// Apple's device information element.
+///
+/// Its content isn't documented publicly, so only the flags are exposed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AppleDeviceInfo {
+    pub flags: Vec<u8>,
+}
@@ -0,0 +1,83 @@
+use super::{Subelement, radio_measurement::encode_subelements};
+
+/// The BSS Termination Duration subelement (ID 4), which announces when and for how long a BSS
+/// is shut down.
+///
+/// It's sent in BSS Transition Management Requests and in Neighbor Report elements.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BssTerminationDuration {
+    /// The TSF at which the BSS is terminated.
+    pub bss_termination_tsf: u64,
+    /// The number of minutes for which the BSS isn't available.
+    pub duration: u16,
+}
+
+impl BssTerminationDuration {
+    /// Encode the payload of the subelement.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.bss_termination_tsf.to_le_bytes().to_vec();
+        bytes.extend(self.duration.to_le_bytes());
+        bytes
+    }
+}
+
+/// The WNM Sleep Mode element (Element ID 93).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WnmSleepMode {
+    /// `0`: Enter the sleep mode, `1`: Exit the sleep mode.
+    pub action_type: u8,
+    /// The status of the response, e.g. `0` for accepted.
+    pub status: u8,
+    /// The number of DTIM intervals the station sleeps.
+    pub interval: u16,
+}
+
+impl WnmSleepMode {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.action_type, self.status];
+        bytes.extend(self.interval.to_le_bytes());
+        bytes
+    }
+}
+
+/// The TFS (traffic filtering service) Request element (Element ID 91).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TfsRequestElement {
+    pub tfs_id: u8,
+    /// Bit 0: Delete the filter after a match, bit 1: Notify the station about matches.
+    pub action_code: u8,
+    /// The TFS subelements (ID 1), which contain the TCLAS elements of a filter.
+    pub subelements: Vec<Subelement>,
+}
+
+impl TfsRequestElement {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.tfs_id, self.action_code];
+        encode_subelements(&mut bytes, &self.subelements);
+        bytes
+    }
+}
+
+/// The TFS Response element (Element ID 92).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TfsResponseElement {
+    /// The TFS Status subelements (ID 1) contain a status and the respective TFS ID.
+    pub subelements: Vec<Subelement>,
+}
+
+impl TfsResponseElement {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        encode_subelements(&mut bytes, &self.subelements);
+        bytes
+    }
+
+    /// Get the `(status, TFS ID)` tuples of all TFS Status subelements.
+    pub fn statuses(&self) -> Vec<(u8, u8)> {
+        self.subelements
+            .iter()
+            .filter(|subelement| subelement.id == 1 && subelement.data.len() >= 2)
+            .map(|subelement| (subelement.data[0], subelement.data[1]))
+            .collect()
+    }
+}
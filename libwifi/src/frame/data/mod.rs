@@ -5,12 +5,25 @@ mod qos_data;
 pub use data::*;
 pub use qos_data::*;
 
+use super::TdlsFrame;
 use super::components::DataHeader;
+use crate::parsers::parse_tdls_frame;
 
 pub trait DataFrame {
     fn header(&self) -> &DataHeader;
     fn eapol_key(&self) -> &Option<EapolKey>;
     fn data(&self) -> &Vec<u8>;
+
+    /// Decode the TDLS frame that's encapsulated in the data (EtherType 0x890d).
+    ///
+    /// Returns `None` for other data and for protected frames, whose data is encrypted.
+    fn tdls_frame(&self) -> Option<TdlsFrame> {
+        if self.header().frame_control.protected() {
+            return None;
+        }
+
+        parse_tdls_frame(self.data()).ok().map(|(_, frame)| frame)
+    }
 }
 
 pub trait NullDataFrame {
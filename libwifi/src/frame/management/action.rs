@@ -1,5 +1,16 @@
 use libwifi_macros::AddressHeader;
 
+use super::{
+    AddbaRequest, AddbaResponse, BssTransitionQuery, BssTransitionRequest, BssTransitionResponse,
+    Delba, DppFrame, Ftm, FtmRequest, GasComebackRequest, GasComebackResponse, GasInitialRequest,
+    GasInitialResponse, LinkMeasurementReport, LinkMeasurementRequest, MeshPeeringConfirm,
+    MeshPeeringOpen, NeighborReportRequest, NeighborReportResponse, P2pAction, P2pPublicAction,
+    RadioMeasurementReport, RadioMeasurementRequest, SaQueryRequest, SaQueryResponse,
+    TdlsChannelSwitchRequest, TdlsChannelSwitchResponse, TdlsDiscoveryRequest,
+    TdlsDiscoveryResponse, TdlsPeerTrafficIndication, TdlsSetupConfirm, TdlsSetupRequest,
+    TdlsSetupResponse, TdlsTeardown, TfsRequest, TfsResponse, WnmSleepModeRequest,
+    WnmSleepModeResponse,
+};
 use crate::frame::components::*;
 
 #[derive(Clone, Debug, AddressHeader)]
@@ -7,43 +18,167 @@ pub struct Action {
     pub header: ManagementHeader,
     pub category: ActionCategory,
     pub action: u8,
+    /// The fixed fields of actions that are known to this library.
+    pub body: Option<ActionBody>,
+    /// The elements that follow the fixed fields.
+    ///
+    /// If the action isn't known, the whole frame body is parsed as elements.
+    /// This is empty for protected frames, see [ActionBody::Encrypted].
     pub station_info: StationInfo,
 }
 
+/// The fixed fields of an action frame, which depend on its category and action.
 #[derive(Clone, Debug)]
+pub enum ActionBody {
+    AddbaRequest(AddbaRequest),
+    AddbaResponse(AddbaResponse),
+    Delba(Delba),
+    RadioMeasurementRequest(RadioMeasurementRequest),
+    RadioMeasurementReport(RadioMeasurementReport),
+    LinkMeasurementRequest(LinkMeasurementRequest),
+    LinkMeasurementReport(LinkMeasurementReport),
+    NeighborReportRequest(NeighborReportRequest),
+    NeighborReportResponse(NeighborReportResponse),
+    BssTransitionQuery(BssTransitionQuery),
+    BssTransitionRequest(BssTransitionRequest),
+    BssTransitionResponse(BssTransitionResponse),
+    TfsRequest(TfsRequest),
+    TfsResponse(TfsResponse),
+    WnmSleepModeRequest(WnmSleepModeRequest),
+    WnmSleepModeResponse(WnmSleepModeResponse),
+    SaQueryRequest(SaQueryRequest),
+    SaQueryResponse(SaQueryResponse),
+    GasInitialRequest(GasInitialRequest),
+    GasInitialResponse(GasInitialResponse),
+    GasComebackRequest(GasComebackRequest),
+    GasComebackResponse(GasComebackResponse),
+    FtmRequest(FtmRequest),
+    Ftm(Ftm),
+    Dpp(DppFrame),
+    P2pPublicAction(P2pPublicAction),
+    P2pAction(P2pAction),
+    TdlsSetupRequest(TdlsSetupRequest),
+    TdlsSetupResponse(TdlsSetupResponse),
+    TdlsSetupConfirm(TdlsSetupConfirm),
+    TdlsTeardown(TdlsTeardown),
+    TdlsPeerTrafficIndication(TdlsPeerTrafficIndication),
+    TdlsChannelSwitchRequest(TdlsChannelSwitchRequest),
+    TdlsChannelSwitchResponse(TdlsChannelSwitchResponse),
+    TdlsDiscoveryRequest(TdlsDiscoveryRequest),
+    TdlsDiscoveryResponse(TdlsDiscoveryResponse),
+    MeshPeeringOpen(MeshPeeringOpen),
+    MeshPeeringConfirm(MeshPeeringConfirm),
+    /// The Mesh Peering Close action has no fixed fields.
+    MeshPeeringClose,
+    /// The Mesh Group Key Inform action has no fixed fields.
+    MeshGroupKeyInform,
+    /// The Mesh Group Key Acknowledge action has no fixed fields.
+    MeshGroupKeyAcknowledge,
+    /// The Link Metric Report action has no fixed fields, the Mesh Link Metric Report element is
+    /// stored in [Action::station_info].
+    LinkMetricReport,
+    /// The HWMP Mesh Path Selection action has no fixed fields, the path selection elements are
+    /// stored in [Action::station_info].
+    HwmpMeshPathSelection,
+    /// The Gate Announcement action has no fixed fields, the Gate Announcement element is stored
+    /// in [Action::station_info].
+    GateAnnouncement,
+    /// The encrypted remainder of a protected frame, which isn't parsed any further.
+    ///
+    /// The frame body of protected frames starts with the CCMP/GCMP header, so
+    /// [Action::category] and [Action::action] hold its first two bytes in that case.
+    Encrypted(Vec<u8>),
+}
+
+impl ActionBody {
+    pub fn encode(&self) -> Vec<u8> {
+        match self {
+            ActionBody::AddbaRequest(request) => request.encode(),
+            ActionBody::AddbaResponse(response) => response.encode(),
+            ActionBody::Delba(delba) => delba.encode(),
+            ActionBody::RadioMeasurementRequest(request) => request.encode(),
+            ActionBody::RadioMeasurementReport(report) => report.encode(),
+            ActionBody::LinkMeasurementRequest(request) => request.encode(),
+            ActionBody::LinkMeasurementReport(report) => report.encode(),
+            ActionBody::NeighborReportRequest(request) => request.encode(),
+            ActionBody::NeighborReportResponse(response) => response.encode(),
+            ActionBody::BssTransitionQuery(query) => query.encode(),
+            ActionBody::BssTransitionRequest(request) => request.encode(),
+            ActionBody::BssTransitionResponse(response) => response.encode(),
+            ActionBody::TfsRequest(request) => request.encode(),
+            ActionBody::TfsResponse(response) => response.encode(),
+            ActionBody::WnmSleepModeRequest(request) => request.encode(),
+            ActionBody::WnmSleepModeResponse(response) => response.encode(),
+            ActionBody::SaQueryRequest(request) => request.encode(),
+            ActionBody::SaQueryResponse(response) => response.encode(),
+            ActionBody::GasInitialRequest(request) => request.encode(),
+            ActionBody::GasInitialResponse(response) => response.encode(),
+            ActionBody::GasComebackRequest(request) => request.encode(),
+            ActionBody::GasComebackResponse(response) => response.encode(),
+            ActionBody::FtmRequest(request) => request.encode(),
+            ActionBody::Ftm(ftm) => ftm.encode(),
+            ActionBody::Dpp(frame) => frame.encode(),
+            ActionBody::P2pPublicAction(action) => action.encode(),
+            ActionBody::P2pAction(action) => action.encode(),
+            ActionBody::TdlsSetupRequest(request) => request.encode(),
+            ActionBody::TdlsSetupResponse(response) => response.encode(),
+            ActionBody::TdlsSetupConfirm(confirm) => confirm.encode(),
+            ActionBody::TdlsTeardown(teardown) => teardown.encode(),
+            ActionBody::TdlsPeerTrafficIndication(indication) => indication.encode(),
+            ActionBody::TdlsChannelSwitchRequest(request) => request.encode(),
+            ActionBody::TdlsChannelSwitchResponse(response) => response.encode(),
+            ActionBody::TdlsDiscoveryRequest(request) => request.encode(),
+            ActionBody::TdlsDiscoveryResponse(response) => response.encode(),
+            ActionBody::MeshPeeringOpen(open) => open.encode(),
+            ActionBody::MeshPeeringConfirm(confirm) => confirm.encode(),
+            ActionBody::MeshPeeringClose
+            | ActionBody::MeshGroupKeyInform
+            | ActionBody::MeshGroupKeyAcknowledge
+            | ActionBody::LinkMetricReport
+            | ActionBody::HwmpMeshPathSelection
+            | ActionBody::GateAnnouncement => Vec::new(),
+            ActionBody::Encrypted(data) => data.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ActionCategory {
-    SpectrumManagement,
-    Qos,
-    Reserved,
-    BlockAck,
-    Public,
-    RadioMeasurement,
-    FastBssTransition,
-    HighThroughput,
-    SaQuery,
-    ProtectedDualOfPublicAction,
-    Wnm,
-    UnprotectedWNM,
-    Tdls,
-    Mesh,
-    Multihop,
-    SelfProtected,
-    Dmg,
-    FastSessionTransfer,
-    RobustAVStreaming,
-    UnprotectedDMG,
-    Vht,
-    UnprotectedS1G,
-    S1G,
-    FlowControl,
-    ControlResponseMCSNegotiation,
-    Fils,
-    Cdmg,
-    Dmmg,
-    Glk,
-    VendorSpecificProtected,
-    VendorSpecific,
-    Error,
+    // The discriminants are the category codes.
+    // `Reserved` and `Error` cover multiple codes and are encoded as 17 and 128.
+    SpectrumManagement = 0,
+    Qos = 1,
+    Dls = 2,
+    BlockAck = 3,
+    Public = 4,
+    RadioMeasurement = 5,
+    FastBssTransition = 6,
+    HighThroughput = 7,
+    SaQuery = 8,
+    ProtectedDualOfPublicAction = 9,
+    Wnm = 10,
+    UnprotectedWNM = 11,
+    Tdls = 12,
+    Mesh = 13,
+    Multihop = 14,
+    SelfProtected = 15,
+    Dmg = 16,
+    Reserved = 17,
+    FastSessionTransfer = 18,
+    RobustAVStreaming = 19,
+    UnprotectedDMG = 20,
+    Vht = 21,
+    UnprotectedS1G = 22,
+    S1G = 23,
+    FlowControl = 24,
+    ControlResponseMCSNegotiation = 25,
+    Fils = 26,
+    Cdmg = 27,
+    Dmmg = 28,
+    Glk = 29,
+    VendorSpecificProtected = 126,
+    VendorSpecific = 127,
+    Error = 128,
 }
 
 impl Action {
@@ -54,22 +189,67 @@ impl Action {
         encoded.extend(self.header.encode());
 
         // Encode the ActionCategory and action
-        encoded.push(self.category.clone() as u8);
-        encoded.push(self.action);
+        // The action of vendor specific actions is the first byte of the OUI, which is part of
+        // the fixed fields or elements.
+        encoded.push(self.category as u8);
+        if !self.category.is_vendor_specific()
+            || matches!(self.body, Some(ActionBody::Encrypted(_)))
+        {
+            encoded.push(self.action);
+        }
+
+        // Encode the fixed fields of known actions
+        if let Some(body) = &self.body {
+            encoded.extend(body.encode());
+        }
 
         // Encode StationInfo if necessary
-        encoded.extend(self.station_info.encode());
+        encoded.extend(self.station_info.encode_lossless());
 
         encoded
     }
 }
 
+impl ActionCategory {
+    /// Whether action frames of this category are robust management frames, which are
+    /// protected once management frame protection (802.11w) has been negotiated.
+    ///
+    /// Robust action frames without the protected flag are suspicious in networks that require
+    /// management frame protection, see [RsnInformation::mfp_required].
+    pub fn is_robust(&self) -> bool {
+        !matches!(
+            self,
+            ActionCategory::Public
+                | ActionCategory::HighThroughput
+                | ActionCategory::UnprotectedWNM
+                | ActionCategory::Tdls
+                | ActionCategory::SelfProtected
+                | ActionCategory::UnprotectedDMG
+                | ActionCategory::Vht
+                | ActionCategory::UnprotectedS1G
+                | ActionCategory::Fils
+                | ActionCategory::VendorSpecific
+                | ActionCategory::Reserved
+                | ActionCategory::Error
+        )
+    }
+
+    /// Whether this is a vendor specific category, whose actions start with an OUI instead of
+    /// an action field.
+    pub fn is_vendor_specific(&self) -> bool {
+        matches!(
+            self,
+            ActionCategory::VendorSpecific | ActionCategory::VendorSpecificProtected
+        )
+    }
+}
+
 impl From<u8> for ActionCategory {
     fn from(value: u8) -> Self {
         match value {
             0 => ActionCategory::SpectrumManagement,
             1 => ActionCategory::Qos,
-            2 => ActionCategory::Reserved,
+            2 => ActionCategory::Dls,
             3 => ActionCategory::BlockAck,
             4 => ActionCategory::Public,
             5 => ActionCategory::RadioMeasurement,
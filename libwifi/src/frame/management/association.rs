@@ -26,7 +26,7 @@ impl AssociationRequest {
         bytes.extend_from_slice(&self.beacon_interval.to_le_bytes());
 
         // Encode Station Info
-        bytes.extend(self.station_info.encode());
+        bytes.extend(self.station_info.encode_lossless());
 
         bytes
     }
@@ -58,7 +58,7 @@ impl AssociationResponse {
         bytes.extend_from_slice(&self.association_id.to_le_bytes());
 
         // Encode Station Info
-        bytes.extend(self.station_info.encode());
+        bytes.extend(self.station_info.encode_lossless());
 
         bytes
     }
@@ -90,7 +90,7 @@ impl ReassociationRequest {
         bytes.extend_from_slice(&self.current_ap_address.encode());
 
         // Encode Station Info
-        bytes.extend(self.station_info.encode());
+        bytes.extend(self.station_info.encode_lossless());
 
         bytes
     }
@@ -34,7 +34,7 @@ impl Authentication {
         }
 
         if let Some(info) = &self.station_info {
-            bytes.extend_from_slice(&info.encode());
+            bytes.extend_from_slice(&info.encode_lossless());
         }
 
         bytes
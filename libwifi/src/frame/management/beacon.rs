@@ -1,6 +1,7 @@
 use libwifi_macros::AddressHeader;
 
 use crate::frame::components::*;
+use crate::parsers::ElementRegistry;
 
 #[derive(Clone, Debug, AddressHeader)]
 pub struct Beacon {
@@ -28,8 +29,17 @@ impl Beacon {
         bytes.extend_from_slice(&self.capability_info.to_le_bytes());
 
         // Encode Station Info
-        bytes.extend(self.station_info.encode());
+        bytes.extend(self.station_info.encode_lossless());
 
         bytes
     }
+
+    /// Expand the Multiple BSSID elements into all nontransmitted BSSes (virtual APs) that
+    /// are announced by this frame.
+    ///
+    /// See [StationInfo::nontransmitted_bsses] for more details.
+    pub fn nontransmitted_bsses(&self, registry: &ElementRegistry) -> Vec<NontransmittedBss> {
+        self.station_info
+            .nontransmitted_bsses(&self.header.address_3, registry)
+    }
 }
@@ -0,0 +1,102 @@
+use crate::frame::components::SequenceControl;
+
+/// The Block Ack Policy of a Block Ack agreement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockAckPolicy {
+    Delayed,
+    Immediate,
+}
+
+/// The Block Ack Parameter Set field of ADDBA Request and Response frames.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockAckParameterSet {
+    pub amsdu_supported: bool,  // bit 0
+    pub policy: BlockAckPolicy, // 1
+    /// The 4 bit traffic identifier.
+    pub tid: u8, // 2-5
+    /// The number of buffers for this agreement.
+    /// `0` lets the recipient choose the buffer size.
+    pub buffer_size: u16, // 6-15
+}
+
+impl BlockAckParameterSet {
+    pub fn from_bits(bits: u16) -> BlockAckParameterSet {
+        BlockAckParameterSet {
+            amsdu_supported: bits & 0b1 != 0,
+            policy: if bits & 0b10 != 0 {
+                BlockAckPolicy::Immediate
+            } else {
+                BlockAckPolicy::Delayed
+            },
+            tid: ((bits >> 2) & 0b1111) as u8,
+            buffer_size: bits >> 6,
+        }
+    }
+
+    pub fn encode(&self) -> [u8; 2] {
+        let mut bits = self.amsdu_supported as u16;
+        bits |= ((self.policy == BlockAckPolicy::Immediate) as u16) << 1;
+        bits |= (self.tid as u16 & 0b1111) << 2;
+        bits |= (self.buffer_size & 0b11_1111_1111) << 6;
+        bits.to_le_bytes()
+    }
+}
+
+/// Sent by the originator of a Block Ack agreement to set it up.
+#[derive(Clone, Debug)]
+pub struct AddbaRequest {
+    pub dialog_token: u8,
+    pub parameters: BlockAckParameterSet,
+    /// The timeout in TUs after which an idle agreement is torn down. `0` disables the timeout.
+    pub timeout: u16,
+    /// The sequence number of the first MSDU that's covered by the agreement.
+    pub starting_sequence_control: SequenceControl,
+}
+
+impl AddbaRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.dialog_token];
+        bytes.extend(self.parameters.encode());
+        bytes.extend(self.timeout.to_le_bytes());
+        bytes.extend(self.starting_sequence_control.encode());
+        bytes
+    }
+}
+
+/// The recipient's answer to an [AddbaRequest].
+#[derive(Clone, Debug)]
+pub struct AddbaResponse {
+    pub dialog_token: u8,
+    /// `0` if the agreement has been accepted.
+    pub status_code: u16,
+    pub parameters: BlockAckParameterSet,
+    pub timeout: u16,
+}
+
+impl AddbaResponse {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.dialog_token];
+        bytes.extend(self.status_code.to_le_bytes());
+        bytes.extend(self.parameters.encode());
+        bytes.extend(self.timeout.to_le_bytes());
+        bytes
+    }
+}
+
+/// Tears down a Block Ack agreement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Delba {
+    /// Whether the frame is sent by the originator of the agreement.
+    pub initiator: bool, // bit 11
+    pub tid: u8, // 12-15
+    pub reason_code: u16,
+}
+
+impl Delba {
+    pub fn encode(&self) -> Vec<u8> {
+        let parameters = ((self.initiator as u16) << 11) | ((self.tid as u16 & 0b1111) << 12);
+        let mut bytes = parameters.to_le_bytes().to_vec();
+        bytes.extend(self.reason_code.to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,290 @@
+use crate::frame::components::WFA_OUI;
+
+/// A Device Provisioning Protocol (Wi-Fi Easy Connect) frame, which is sent as WFA vendor
+/// specific public action (OUI 50:6f:9a, type 0x1a).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DppFrame {
+    /// `1` for the currently defined crypto suite.
+    pub crypto_suite: u8,
+    pub frame_type: DppFrameType,
+    pub attributes: Vec<DppAttribute>,
+}
+
+impl DppFrame {
+    /// Encode the frame, starting with the OUI.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = WFA_OUI.to_vec();
+        bytes.extend([0x1a, self.crypto_suite, self.frame_type.encode()]);
+        for attribute in &self.attributes {
+            bytes.extend(attribute.encode());
+        }
+
+        bytes
+    }
+
+    /// Get the first attribute with the given ID.
+    pub fn attribute(&self, id: u16) -> Option<&DppAttribute> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.id() == id)
+    }
+
+    /// The status of response, confirm and result frames.
+    pub fn status(&self) -> Option<DppStatus> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                DppAttribute::Status(status) => Some(*status),
+                _ => None,
+            })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DppFrameType {
+    AuthenticationRequest,
+    AuthenticationResponse,
+    AuthenticationConfirm,
+    PeerDiscoveryRequest,
+    PeerDiscoveryResponse,
+    PkexV1ExchangeRequest,
+    PkexExchangeResponse,
+    PkexCommitRevealRequest,
+    PkexCommitRevealResponse,
+    ConfigurationResult,
+    ConnectionStatusResult,
+    PresenceAnnouncement,
+    ReconfigurationAnnouncement,
+    ReconfigurationAuthenticationRequest,
+    ReconfigurationAuthenticationResponse,
+    ReconfigurationAuthenticationConfirm,
+    PkexExchangeRequest,
+    Unknown(u8),
+}
+
+impl From<u8> for DppFrameType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => DppFrameType::AuthenticationRequest,
+            1 => DppFrameType::AuthenticationResponse,
+            2 => DppFrameType::AuthenticationConfirm,
+            5 => DppFrameType::PeerDiscoveryRequest,
+            6 => DppFrameType::PeerDiscoveryResponse,
+            7 => DppFrameType::PkexV1ExchangeRequest,
+            8 => DppFrameType::PkexExchangeResponse,
+            9 => DppFrameType::PkexCommitRevealRequest,
+            10 => DppFrameType::PkexCommitRevealResponse,
+            11 => DppFrameType::ConfigurationResult,
+            12 => DppFrameType::ConnectionStatusResult,
+            13 => DppFrameType::PresenceAnnouncement,
+            14 => DppFrameType::ReconfigurationAnnouncement,
+            15 => DppFrameType::ReconfigurationAuthenticationRequest,
+            16 => DppFrameType::ReconfigurationAuthenticationResponse,
+            17 => DppFrameType::ReconfigurationAuthenticationConfirm,
+            18 => DppFrameType::PkexExchangeRequest,
+            other => DppFrameType::Unknown(other),
+        }
+    }
+}
+
+impl DppFrameType {
+    pub fn encode(&self) -> u8 {
+        match self {
+            DppFrameType::AuthenticationRequest => 0,
+            DppFrameType::AuthenticationResponse => 1,
+            DppFrameType::AuthenticationConfirm => 2,
+            DppFrameType::PeerDiscoveryRequest => 5,
+            DppFrameType::PeerDiscoveryResponse => 6,
+            DppFrameType::PkexV1ExchangeRequest => 7,
+            DppFrameType::PkexExchangeResponse => 8,
+            DppFrameType::PkexCommitRevealRequest => 9,
+            DppFrameType::PkexCommitRevealResponse => 10,
+            DppFrameType::ConfigurationResult => 11,
+            DppFrameType::ConnectionStatusResult => 12,
+            DppFrameType::PresenceAnnouncement => 13,
+            DppFrameType::ReconfigurationAnnouncement => 14,
+            DppFrameType::ReconfigurationAuthenticationRequest => 15,
+            DppFrameType::ReconfigurationAuthenticationResponse => 16,
+            DppFrameType::ReconfigurationAuthenticationConfirm => 17,
+            DppFrameType::PkexExchangeRequest => 18,
+            DppFrameType::Unknown(value) => *value,
+        }
+    }
+}
+
+/// A TLV attribute of a [DppFrame].
+///
+/// Attributes whose value can't be decoded losslessly are kept as [DppAttribute::Unknown].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DppAttribute {
+    /// ID 0x1000
+    Status(DppStatus),
+    /// The SHA-256 hash of the initiator's bootstrapping key (ID 0x1001).
+    InitiatorBootstrappingKeyHash(Vec<u8>),
+    /// The SHA-256 hash of the responder's bootstrapping key (ID 0x1002).
+    ResponderBootstrappingKeyHash(Vec<u8>),
+    /// The x and y coordinates of the initiator's ephemeral key (ID 0x1003).
+    InitiatorProtocolKey(Vec<u8>),
+    /// Attributes that are encrypted with AES-SIV (ID 0x1004).
+    WrappedData(Vec<u8>),
+    /// ID 0x1005
+    InitiatorNonce(Vec<u8>),
+    /// ID 0x1006
+    InitiatorCapabilities(DppCapabilities),
+    /// ID 0x1007
+    ResponderNonce(Vec<u8>),
+    /// ID 0x1008
+    ResponderCapabilities(DppCapabilities),
+    /// The x and y coordinates of the responder's ephemeral key (ID 0x1009).
+    ResponderProtocolKey(Vec<u8>),
+    /// The JWS compact serialization of the connector (ID 0x100d).
+    Connector(String),
+    /// The channel the responder should switch to (ID 0x1018).
+    Channel {
+        operating_class: u8,
+        channel: u8,
+    },
+    /// ID 0x1019
+    ProtocolVersion(u8),
+    Unknown {
+        id: u16,
+        data: Vec<u8>,
+    },
+}
+
+impl DppAttribute {
+    pub fn id(&self) -> u16 {
+        match self {
+            DppAttribute::Status(_) => 0x1000,
+            DppAttribute::InitiatorBootstrappingKeyHash(_) => 0x1001,
+            DppAttribute::ResponderBootstrappingKeyHash(_) => 0x1002,
+            DppAttribute::InitiatorProtocolKey(_) => 0x1003,
+            DppAttribute::WrappedData(_) => 0x1004,
+            DppAttribute::InitiatorNonce(_) => 0x1005,
+            DppAttribute::InitiatorCapabilities(_) => 0x1006,
+            DppAttribute::ResponderNonce(_) => 0x1007,
+            DppAttribute::ResponderCapabilities(_) => 0x1008,
+            DppAttribute::ResponderProtocolKey(_) => 0x1009,
+            DppAttribute::Connector(_) => 0x100d,
+            DppAttribute::Channel { .. } => 0x1018,
+            DppAttribute::ProtocolVersion(_) => 0x1019,
+            DppAttribute::Unknown { id, .. } => *id,
+        }
+    }
+
+    /// Encode the attribute including its ID and length.
+    pub fn encode(&self) -> Vec<u8> {
+        let value = match self {
+            DppAttribute::Status(status) => vec![status.encode()],
+            DppAttribute::InitiatorBootstrappingKeyHash(data)
+            | DppAttribute::ResponderBootstrappingKeyHash(data)
+            | DppAttribute::InitiatorProtocolKey(data)
+            | DppAttribute::WrappedData(data)
+            | DppAttribute::InitiatorNonce(data)
+            | DppAttribute::ResponderNonce(data)
+            | DppAttribute::ResponderProtocolKey(data)
+            | DppAttribute::Unknown { data, .. } => data.clone(),
+            DppAttribute::InitiatorCapabilities(capabilities)
+            | DppAttribute::ResponderCapabilities(capabilities) => vec![capabilities.encode()],
+            DppAttribute::Connector(connector) => connector.as_bytes().to_vec(),
+            DppAttribute::Channel {
+                operating_class,
+                channel,
+            } => vec![*operating_class, *channel],
+            DppAttribute::ProtocolVersion(version) => vec![*version],
+        };
+
+        let mut bytes = self.id().to_le_bytes().to_vec();
+        bytes.extend((value.len() as u16).to_le_bytes());
+        bytes.extend(value);
+        bytes
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DppStatus {
+    Ok,
+    NotCompatible,
+    AuthenticationFailure,
+    BadCode,
+    BadGroup,
+    ConfigurationFailure,
+    ResponsePending,
+    InvalidConnector,
+    NoMatch,
+    ConfigurationRejected,
+    NoAp,
+    ConfigurePending,
+    CsrNeeded,
+    CsrBad,
+    NewKeyNeeded,
+    Unknown(u8),
+}
+
+impl From<u8> for DppStatus {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => DppStatus::Ok,
+            1 => DppStatus::NotCompatible,
+            2 => DppStatus::AuthenticationFailure,
+            3 => DppStatus::BadCode,
+            4 => DppStatus::BadGroup,
+            5 => DppStatus::ConfigurationFailure,
+            6 => DppStatus::ResponsePending,
+            7 => DppStatus::InvalidConnector,
+            8 => DppStatus::NoMatch,
+            9 => DppStatus::ConfigurationRejected,
+            10 => DppStatus::NoAp,
+            11 => DppStatus::ConfigurePending,
+            12 => DppStatus::CsrNeeded,
+            13 => DppStatus::CsrBad,
+            14 => DppStatus::NewKeyNeeded,
+            other => DppStatus::Unknown(other),
+        }
+    }
+}
+
+impl DppStatus {
+    pub fn encode(&self) -> u8 {
+        match self {
+            DppStatus::Ok => 0,
+            DppStatus::NotCompatible => 1,
+            DppStatus::AuthenticationFailure => 2,
+            DppStatus::BadCode => 3,
+            DppStatus::BadGroup => 4,
+            DppStatus::ConfigurationFailure => 5,
+            DppStatus::ResponsePending => 6,
+            DppStatus::InvalidConnector => 7,
+            DppStatus::NoMatch => 8,
+            DppStatus::ConfigurationRejected => 9,
+            DppStatus::NoAp => 10,
+            DppStatus::ConfigurePending => 11,
+            DppStatus::CsrNeeded => 12,
+            DppStatus::CsrBad => 13,
+            DppStatus::NewKeyNeeded => 14,
+            DppStatus::Unknown(value) => *value,
+        }
+    }
+}
+
+/// The role of a device in the DPP exchange.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DppCapabilities {
+    pub enrollee: bool,     // bit 0
+    pub configurator: bool, // 1
+    pub reserved: u8,       // 2-7
+}
+
+impl DppCapabilities {
+    pub fn from_bits(bits: u8) -> DppCapabilities {
+        DppCapabilities {
+            enrollee: bits & 0b1 != 0,
+            configurator: bits & 0b10 != 0,
+            reserved: bits >> 2,
+        }
+    }
+
+    pub fn encode(&self) -> u8 {
+        self.enrollee as u8 | ((self.configurator as u8) << 1) | (self.reserved << 2)
+    }
+}
@@ -0,0 +1,158 @@
+/// The speed of light in m/s.
+const SPEED_OF_LIGHT: f64 = 299_792_458.0;
+
+/// TOD and TOA are 48 bit timestamps.
+const TIMESTAMP_MASK: u64 = (1 << 48) - 1;
+
+/// Sent by the initiating station to start or stop a Fine Timing Measurement session.
+///
+/// The optional LCI and Location Civic Measurement Requests are stored in
+/// [StationInfo::measurement_requests](crate::frame::components::StationInfo::measurement_requests)
+/// and the FTM Parameters in [StationInfo::ftm_parameters](crate::frame::components::StationInfo::ftm_parameters).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FtmRequest {
+    /// `1` to start or continue the session, `0` to stop it.
+    pub trigger: u8,
+}
+
+impl FtmRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![self.trigger]
+    }
+}
+
+/// A Fine Timing Measurement frame, which is sent by the responding station.
+///
+/// Each frame contains the timestamps of the previous FTM frame, which is identified by
+/// [Ftm::follow_up_dialog_token], and its acknowledgment.
+/// The optional LCI and Location Civic Reports are stored in
+/// [StationInfo::measurement_reports](crate::frame::components::StationInfo::measurement_reports).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ftm {
+    /// `0` for the last frame of the session.
+    pub dialog_token: u8,
+    /// The dialog token of the previous FTM frame, whose timestamps are contained in this frame.
+    /// `0` if there are no timestamps.
+    pub follow_up_dialog_token: u8,
+    /// The time of departure of the previous FTM frame in picoseconds (t1).
+    pub tod: u64,
+    /// The time of arrival of its acknowledgment in picoseconds (t4).
+    pub toa: u64,
+    pub tod_error: FtmError,
+    pub toa_error: FtmError,
+}
+
+impl Ftm {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.dialog_token, self.follow_up_dialog_token];
+        bytes.extend(&self.tod.to_le_bytes()[..6]);
+        bytes.extend(&self.toa.to_le_bytes()[..6]);
+        bytes.extend(self.tod_error.encode().to_le_bytes());
+        bytes.extend(self.toa_error.encode().to_le_bytes());
+        bytes
+    }
+
+    /// Combine the timestamps of this frame with the timestamps of the initiating station.
+    ///
+    /// `toa` is the time of arrival of the previous FTM frame (t2) and `tod` the time of departure
+    /// of its acknowledgment (t3), both in picoseconds.
+    /// Returns `None` if this frame doesn't contain timestamps or the responder marked them
+    /// as invalid.
+    pub fn measurement(&self, toa: u64, tod: u64) -> Option<FtmMeasurement> {
+        if self.follow_up_dialog_token == 0 || self.tod_error.invalid_measurement {
+            return None;
+        }
+
+        Some(FtmMeasurement {
+            t1: self.tod,
+            t2: toa,
+            t3: tod,
+            t4: self.toa,
+        })
+    }
+}
+
+/// The TOD Error or TOA Error field of an FTM frame.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FtmError {
+    pub max_error_exponent: u8, // bit 0-4
+    pub reserved: u16,          // 5-14
+    /// Set if the timestamps are invalid, which is only used in the TOD Error field.
+    pub invalid_measurement: bool, // 15
+}
+
+impl FtmError {
+    pub fn from_bits(bits: u16) -> FtmError {
+        FtmError {
+            max_error_exponent: (bits & 0x1f) as u8,
+            reserved: (bits >> 5) & 0x3ff,
+            invalid_measurement: bits & (1 << 15) != 0,
+        }
+    }
+
+    pub fn encode(&self) -> u16 {
+        (self.max_error_exponent as u16 & 0x1f)
+            | ((self.reserved & 0x3ff) << 5)
+            | ((self.invalid_measurement as u16) << 15)
+    }
+
+    /// The upper bound of the error in picoseconds, or `None` if it's unknown.
+    ///
+    /// An exponent of `31` means that the error is at least `2^30` picoseconds.
+    pub fn max_error(&self) -> Option<u64> {
+        match self.max_error_exponent {
+            1..=31 => Some(1 << (self.max_error_exponent - 1)),
+            _ => None,
+        }
+    }
+}
+
+/// The four timestamps of a single FTM exchange in picoseconds.
+///
+/// - t1: The responder sends an FTM frame
+/// - t2: The initiator receives it
+/// - t3: The initiator sends the acknowledgment
+/// - t4: The responder receives the acknowledgment
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FtmMeasurement {
+    pub t1: u64,
+    pub t2: u64,
+    pub t3: u64,
+    pub t4: u64,
+}
+
+impl FtmMeasurement {
+    /// The round trip time in picoseconds, i.e. `(t4 - t1) - (t3 - t2)`.
+    ///
+    /// Wraparounds of the 48 bit timestamps are handled.
+    pub fn rtt(&self) -> i64 {
+        let responder = self.t4.wrapping_sub(self.t1) & TIMESTAMP_MASK;
+        let initiator = self.t3.wrapping_sub(self.t2) & TIMESTAMP_MASK;
+
+        responder as i64 - initiator as i64
+    }
+
+    /// The estimated distance between both stations in meters.
+    pub fn distance(&self) -> f64 {
+        rtt_to_distance(self.rtt() as f64)
+    }
+}
+
+/// Estimate the distance in meters from the mean round trip time of multiple FTM exchanges.
+///
+/// Returns `None` if no measurements are given.
+pub fn estimate_distance(measurements: &[FtmMeasurement]) -> Option<f64> {
+    if measurements.is_empty() {
+        return None;
+    }
+
+    let total: i64 = measurements.iter().map(FtmMeasurement::rtt).sum();
+    let mean_rtt = total as f64 / measurements.len() as f64;
+
+    Some(rtt_to_distance(mean_rtt))
+}
+
+/// Convert a round trip time in picoseconds to a one-way distance in meters.
+fn rtt_to_distance(rtt: f64) -> f64 {
+    rtt * 1e-12 * SPEED_OF_LIGHT / 2.0
+}
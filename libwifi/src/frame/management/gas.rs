@@ -0,0 +1,132 @@
+use nom::{Parser, combinator::all_consuming};
+
+use crate::frame::components::{AdvertisementProtocol, AnqpElement};
+use crate::parsers::parse_anqp_elements;
+
+/// A Generic Advertisement Service request, which is used to query e.g. ANQP information
+/// of an AP before associating.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasInitialRequest {
+    pub dialog_token: u8,
+    pub advertisement_protocol: AdvertisementProtocol,
+    /// The query request, whose length is encoded as the Query Request Length field.
+    pub query: Vec<u8>,
+}
+
+impl GasInitialRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.dialog_token];
+        bytes.extend(encode_query(&self.advertisement_protocol, &self.query));
+        bytes
+    }
+
+    /// Decode the query as ANQP elements.
+    ///
+    /// Returns `None` if the query isn't an ANQP query.
+    pub fn anqp_elements(&self) -> Option<Vec<AnqpElement>> {
+        decode_anqp_elements(&self.advertisement_protocol, &self.query)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasInitialResponse {
+    pub dialog_token: u8,
+    pub status_code: u16,
+    /// The delay in TUs after which the response can be requested via GAS Comeback Requests.
+    /// `0` if the response is contained in this frame.
+    pub comeback_delay: u16,
+    pub advertisement_protocol: AdvertisementProtocol,
+    /// The query response, whose length is encoded as the Query Response Length field.
+    pub query_response: Vec<u8>,
+}
+
+impl GasInitialResponse {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.dialog_token];
+        bytes.extend(self.status_code.to_le_bytes());
+        bytes.extend(self.comeback_delay.to_le_bytes());
+        bytes.extend(encode_query(
+            &self.advertisement_protocol,
+            &self.query_response,
+        ));
+        bytes
+    }
+
+    /// Decode the query response as ANQP elements.
+    ///
+    /// Returns `None` if the response isn't an ANQP response.
+    pub fn anqp_elements(&self) -> Option<Vec<AnqpElement>> {
+        decode_anqp_elements(&self.advertisement_protocol, &self.query_response)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasComebackRequest {
+    pub dialog_token: u8,
+}
+
+impl GasComebackRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![self.dialog_token]
+    }
+}
+
+/// A fragment of a GAS query response.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasComebackResponse {
+    pub dialog_token: u8,
+    pub status_code: u16,
+    pub fragment_id: u8,      // bit 0-6
+    pub more_fragments: bool, // 7
+    pub comeback_delay: u16,
+    pub advertisement_protocol: AdvertisementProtocol,
+    /// This fragment of the query response.
+    pub query_response: Vec<u8>,
+}
+
+impl GasComebackResponse {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.dialog_token];
+        bytes.extend(self.status_code.to_le_bytes());
+        bytes.push((self.fragment_id & 0x7f) | ((self.more_fragments as u8) << 7));
+        bytes.extend(self.comeback_delay.to_le_bytes());
+        bytes.extend(encode_query(
+            &self.advertisement_protocol,
+            &self.query_response,
+        ));
+        bytes
+    }
+
+    /// Decode the query response fragment as ANQP elements.
+    ///
+    /// Returns `None` if the response isn't an ANQP response or the fragment doesn't end
+    /// on an element boundary. In this case the fragments have to be reassembled and passed to
+    /// [parse_anqp_elements].
+    pub fn anqp_elements(&self) -> Option<Vec<AnqpElement>> {
+        decode_anqp_elements(&self.advertisement_protocol, &self.query_response)
+    }
+}
+
+/// Encode the Advertisement Protocol element, followed by the length prefixed query.
+fn encode_query(advertisement_protocol: &AdvertisementProtocol, query: &[u8]) -> Vec<u8> {
+    let advertisement_protocol = advertisement_protocol.encode();
+    let mut bytes = vec![108, advertisement_protocol.len() as u8];
+    bytes.extend(advertisement_protocol);
+    bytes.extend((query.len() as u16).to_le_bytes());
+    bytes.extend(query);
+    bytes
+}
+
+fn decode_anqp_elements(
+    advertisement_protocol: &AdvertisementProtocol,
+    query: &[u8],
+) -> Option<Vec<AnqpElement>> {
+    if !advertisement_protocol.supports_anqp() {
+        return None;
+    }
+
+    all_consuming(parse_anqp_elements)
+        .parse(query)
+        .ok()
+        .map(|(_, elements)| elements)
+}
@@ -0,0 +1,30 @@
+/// Sent to open a mesh peering with a neighboring mesh station.
+///
+/// The Mesh ID, Mesh Configuration and Mesh Peering Management elements are stored in the
+/// [StationInfo](crate::frame::components::StationInfo) of the action frame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MeshPeeringOpen {
+    pub capability_info: u16,
+}
+
+impl MeshPeeringOpen {
+    pub fn encode(&self) -> Vec<u8> {
+        self.capability_info.to_le_bytes().to_vec()
+    }
+}
+
+/// Sent to confirm a [MeshPeeringOpen] of the peer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MeshPeeringConfirm {
+    pub capability_info: u16,
+    /// The association ID that has been assigned to the peer.
+    pub aid: u16,
+}
+
+impl MeshPeeringConfirm {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.capability_info.to_le_bytes().to_vec();
+        bytes.extend(self.aid.to_le_bytes());
+        bytes
+    }
+}
@@ -2,9 +2,19 @@ mod action;
 mod association;
 mod authentication;
 mod beacon;
+mod block_ack;
+mod dpp;
+mod ftm;
+mod gas;
+mod mesh;
+mod p2p;
 mod probe;
+mod radio_measurement;
+mod sa_query;
+mod tdls;
+mod wnm;
 
-pub use action::{Action, ActionCategory};
+pub use action::{Action, ActionBody, ActionCategory};
 pub use association::{
     AssociationRequest, AssociationResponse, Disassociation, ReassociationRequest,
     ReassociationResponse,
@@ -13,4 +23,24 @@ pub use authentication::{
     Authentication, DEAUTHENTICATION_REASON_MAX, Deauthentication, DeauthenticationReason,
 };
 pub use beacon::Beacon;
+pub use block_ack::{AddbaRequest, AddbaResponse, BlockAckParameterSet, BlockAckPolicy, Delba};
+pub use dpp::{DppAttribute, DppCapabilities, DppFrame, DppFrameType, DppStatus};
+pub use ftm::{Ftm, FtmError, FtmMeasurement, FtmRequest, estimate_distance};
+pub use gas::{GasComebackRequest, GasComebackResponse, GasInitialRequest, GasInitialResponse};
+pub use mesh::{MeshPeeringConfirm, MeshPeeringOpen};
+pub use p2p::{P2pAction, P2pActionType, P2pPublicAction, P2pPublicActionType};
 pub use probe::{ProbeRequest, ProbeResponse};
+pub use radio_measurement::{
+    LinkMeasurementReport, LinkMeasurementRequest, NeighborReportRequest, NeighborReportResponse,
+    RadioMeasurementReport, RadioMeasurementRequest,
+};
+pub use sa_query::{SaQueryRequest, SaQueryResponse};
+pub use tdls::{
+    TDLS_LLC_HEADER, TdlsChannelSwitchRequest, TdlsChannelSwitchResponse, TdlsDiscoveryRequest,
+    TdlsDiscoveryResponse, TdlsFrame, TdlsPeerTrafficIndication, TdlsSetupConfirm,
+    TdlsSetupRequest, TdlsSetupResponse, TdlsTeardown,
+};
+pub use wnm::{
+    BssTransitionQuery, BssTransitionRequest, BssTransitionResponse, BtmRequestMode, BtmStatusCode,
+    TfsRequest, TfsResponse, WnmSleepModeRequest, WnmSleepModeResponse,
+};
@@ -0,0 +1,125 @@
+use crate::frame::components::WFA_OUI;
+
+/// A Wi-Fi Direct public action frame, which is sent as WFA vendor specific public action
+/// (OUI 50:6f:9a, type 0x09).
+///
+/// The P2P and WSC elements that follow the fixed fields are stored in the
+/// [StationInfo](crate::frame::components::StationInfo) of the action frame, use
+/// [StationInfo::p2p_attributes](crate::frame::components::StationInfo::p2p_attributes) to
+/// decode the P2P attributes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct P2pPublicAction {
+    pub subtype: P2pPublicActionType,
+    pub dialog_token: u8,
+}
+
+impl P2pPublicAction {
+    /// Encode the fixed fields, starting with the OUI.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = WFA_OUI.to_vec();
+        bytes.extend([0x09, self.subtype.encode(), self.dialog_token]);
+        bytes
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum P2pPublicActionType {
+    GoNegotiationRequest,
+    GoNegotiationResponse,
+    GoNegotiationConfirmation,
+    InvitationRequest,
+    InvitationResponse,
+    DeviceDiscoverabilityRequest,
+    DeviceDiscoverabilityResponse,
+    ProvisionDiscoveryRequest,
+    ProvisionDiscoveryResponse,
+    Unknown(u8),
+}
+
+impl From<u8> for P2pPublicActionType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => P2pPublicActionType::GoNegotiationRequest,
+            1 => P2pPublicActionType::GoNegotiationResponse,
+            2 => P2pPublicActionType::GoNegotiationConfirmation,
+            3 => P2pPublicActionType::InvitationRequest,
+            4 => P2pPublicActionType::InvitationResponse,
+            5 => P2pPublicActionType::DeviceDiscoverabilityRequest,
+            6 => P2pPublicActionType::DeviceDiscoverabilityResponse,
+            7 => P2pPublicActionType::ProvisionDiscoveryRequest,
+            8 => P2pPublicActionType::ProvisionDiscoveryResponse,
+            other => P2pPublicActionType::Unknown(other),
+        }
+    }
+}
+
+impl P2pPublicActionType {
+    pub fn encode(&self) -> u8 {
+        match self {
+            P2pPublicActionType::GoNegotiationRequest => 0,
+            P2pPublicActionType::GoNegotiationResponse => 1,
+            P2pPublicActionType::GoNegotiationConfirmation => 2,
+            P2pPublicActionType::InvitationRequest => 3,
+            P2pPublicActionType::InvitationResponse => 4,
+            P2pPublicActionType::DeviceDiscoverabilityRequest => 5,
+            P2pPublicActionType::DeviceDiscoverabilityResponse => 6,
+            P2pPublicActionType::ProvisionDiscoveryRequest => 7,
+            P2pPublicActionType::ProvisionDiscoveryResponse => 8,
+            P2pPublicActionType::Unknown(value) => *value,
+        }
+    }
+}
+
+/// A Wi-Fi Direct action frame, which is sent as WFA vendor specific action (category 127).
+///
+/// Vendor specific actions have no action field, so [Action::action](super::Action::action)
+/// holds the first byte of the OUI (0x50), which is encoded as part of this body.
+/// The P2P element with the Notice of Absence attribute is stored in the
+/// [StationInfo](crate::frame::components::StationInfo) of the action frame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct P2pAction {
+    pub subtype: P2pActionType,
+    pub dialog_token: u8,
+}
+
+impl P2pAction {
+    /// Encode the fixed fields, starting with the OUI.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = WFA_OUI.to_vec();
+        bytes.extend([0x09, self.subtype.encode(), self.dialog_token]);
+        bytes
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum P2pActionType {
+    NoticeOfAbsence,
+    PresenceRequest,
+    PresenceResponse,
+    GoDiscoverabilityRequest,
+    Unknown(u8),
+}
+
+impl From<u8> for P2pActionType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => P2pActionType::NoticeOfAbsence,
+            1 => P2pActionType::PresenceRequest,
+            2 => P2pActionType::PresenceResponse,
+            3 => P2pActionType::GoDiscoverabilityRequest,
+            other => P2pActionType::Unknown(other),
+        }
+    }
+}
+
+impl P2pActionType {
+    pub fn encode(&self) -> u8 {
+        match self {
+            P2pActionType::NoticeOfAbsence => 0,
+            P2pActionType::PresenceRequest => 1,
+            P2pActionType::PresenceResponse => 2,
+            P2pActionType::GoDiscoverabilityRequest => 3,
+            P2pActionType::Unknown(value) => *value,
+        }
+    }
+}
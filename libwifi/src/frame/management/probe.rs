@@ -1,6 +1,7 @@
 use libwifi_macros::AddressHeader;
 
 use crate::frame::components::*;
+use crate::parsers::ElementRegistry;
 
 #[derive(Clone, Debug, AddressHeader)]
 pub struct ProbeRequest {
@@ -16,7 +17,7 @@ impl ProbeRequest {
         bytes.extend(self.header.encode());
 
         // Encode Station Info
-        bytes.extend(self.station_info.encode());
+        bytes.extend(self.station_info.encode_lossless());
 
         bytes
     }
@@ -48,8 +49,17 @@ impl ProbeResponse {
         bytes.extend_from_slice(&self.capability_info.to_le_bytes());
 
         // Encode Station Info
-        bytes.extend(self.station_info.encode());
+        bytes.extend(self.station_info.encode_lossless());
 
         bytes
     }
+
+    /// Expand the Multiple BSSID elements into all nontransmitted BSSes (virtual APs) that
+    /// are announced by this frame.
+    ///
+    /// See [StationInfo::nontransmitted_bsses] for more details.
+    pub fn nontransmitted_bsses(&self, registry: &ElementRegistry) -> Vec<NontransmittedBss> {
+        self.station_info
+            .nontransmitted_bsses(&self.header.address_3, registry)
+    }
 }
@@ -0,0 +1,109 @@
+use crate::frame::components::TpcReport;
+
+/// The fixed fields of a Radio Measurement Request frame.
+///
+/// The Measurement Request elements are stored in [StationInfo::measurement_requests](crate::frame::components::StationInfo::measurement_requests).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RadioMeasurementRequest {
+    pub dialog_token: u8,
+    /// How often the measurements are repeated, `65535` repeats them until cancelled.
+    pub repetitions: u16,
+}
+
+impl RadioMeasurementRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.dialog_token];
+        bytes.extend(self.repetitions.to_le_bytes());
+        bytes
+    }
+}
+
+/// The fixed fields of a Radio Measurement Report frame.
+///
+/// The Measurement Report elements are stored in [StationInfo::measurement_reports](crate::frame::components::StationInfo::measurement_reports).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RadioMeasurementReport {
+    pub dialog_token: u8,
+}
+
+impl RadioMeasurementReport {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![self.dialog_token]
+    }
+}
+
+/// Requests a Link Measurement Report, which is used to estimate the path loss of a link.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinkMeasurementRequest {
+    pub dialog_token: u8,
+    /// The transmit power used to send this frame in dBm.
+    pub transmit_power: i8,
+    /// The maximum transmit power of the sender in dBm.
+    pub max_transmit_power: i8,
+}
+
+impl LinkMeasurementRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![
+            self.dialog_token,
+            self.transmit_power as u8,
+            self.max_transmit_power as u8,
+        ]
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinkMeasurementReport {
+    pub dialog_token: u8,
+    /// The TPC Report element, which is part of the fixed fields of this frame.
+    pub tpc_report: TpcReport,
+    pub receive_antenna_id: u8,
+    pub transmit_antenna_id: u8,
+    /// The received channel power indicator of the Link Measurement Request.
+    pub rcpi: u8,
+    /// The received signal to noise indicator of the Link Measurement Request.
+    pub rsni: u8,
+}
+
+impl LinkMeasurementReport {
+    pub fn encode(&self) -> Vec<u8> {
+        let tpc_report = self.tpc_report.encode();
+        let mut bytes = vec![self.dialog_token, 35, tpc_report.len() as u8];
+        bytes.extend(tpc_report);
+        bytes.extend([
+            self.receive_antenna_id,
+            self.transmit_antenna_id,
+            self.rcpi,
+            self.rsni,
+        ]);
+        bytes
+    }
+}
+
+/// The fixed fields of a Neighbor Report Request frame.
+///
+/// An optional SSID element is stored in the frame's [StationInfo](crate::frame::components::StationInfo).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NeighborReportRequest {
+    pub dialog_token: u8,
+}
+
+impl NeighborReportRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![self.dialog_token]
+    }
+}
+
+/// The fixed fields of a Neighbor Report Response frame.
+///
+/// The Neighbor Report elements are stored in [StationInfo::neighbor_reports](crate::frame::components::StationInfo::neighbor_reports).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NeighborReportResponse {
+    pub dialog_token: u8,
+}
+
+impl NeighborReportResponse {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![self.dialog_token]
+    }
+}
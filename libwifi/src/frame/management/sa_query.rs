@@ -0,0 +1,24 @@
+/// Sent to verify that an associated station is still present and has the same keys, e.g. when
+/// it tries to associate again while management frame protection is used.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SaQueryRequest {
+    pub transaction_id: u16,
+}
+
+impl SaQueryRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        self.transaction_id.to_le_bytes().to_vec()
+    }
+}
+
+/// The answer to a [SaQueryRequest], which echoes its transaction ID.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SaQueryResponse {
+    pub transaction_id: u16,
+}
+
+impl SaQueryResponse {
+    pub fn encode(&self) -> Vec<u8> {
+        self.transaction_id.to_le_bytes().to_vec()
+    }
+}
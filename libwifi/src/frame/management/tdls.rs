@@ -0,0 +1,158 @@
+use super::ActionBody;
+use crate::frame::components::StationInfo;
+
+/// The LLC/SNAP header of data frames that carry TDLS frames (EtherType 0x890d).
+pub const TDLS_LLC_HEADER: [u8; 8] = [0xaa, 0xaa, 0x03, 0x00, 0x00, 0x00, 0x89, 0x0d];
+
+/// A TDLS action frame, which is encapsulated in a data frame and sent via the AP.
+///
+/// Only the TDLS Discovery Response is sent as public action frame directly to the peer.
+/// See [DataFrame::tdls_frame](crate::frame::DataFrame::tdls_frame).
+#[derive(Clone, Debug)]
+pub struct TdlsFrame {
+    pub action: u8,
+    /// The fixed fields, which are `None` if the action isn't known.
+    pub body: Option<ActionBody>,
+    /// The elements after the fixed fields, e.g. the
+    /// [LinkIdentifier](crate::frame::components::LinkIdentifier).
+    pub station_info: StationInfo,
+}
+
+impl TdlsFrame {
+    /// Encode the frame, starting with the LLC/SNAP header.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = TDLS_LLC_HEADER.to_vec();
+        // Payload type TDLS and category TDLS
+        bytes.extend([2, 12, self.action]);
+        if let Some(body) = &self.body {
+            bytes.extend(body.encode());
+        }
+        bytes.extend(self.station_info.encode_lossless());
+        bytes
+    }
+}
+
+/// Sent by the initiator to set up a direct link with the responder.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TdlsSetupRequest {
+    pub dialog_token: u8,
+    pub capability_info: u16,
+}
+
+impl TdlsSetupRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.dialog_token];
+        bytes.extend(self.capability_info.to_le_bytes());
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TdlsSetupResponse {
+    pub status_code: u16,
+    pub dialog_token: u8,
+    /// Only present if the setup was successful.
+    pub capability_info: Option<u16>,
+}
+
+impl TdlsSetupResponse {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.status_code.to_le_bytes().to_vec();
+        bytes.push(self.dialog_token);
+        if let Some(capability_info) = self.capability_info {
+            bytes.extend(capability_info.to_le_bytes());
+        }
+        bytes
+    }
+}
+
+/// Sent by the initiator to complete the setup of the direct link.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TdlsSetupConfirm {
+    pub status_code: u16,
+    pub dialog_token: u8,
+}
+
+impl TdlsSetupConfirm {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.status_code.to_le_bytes().to_vec();
+        bytes.push(self.dialog_token);
+        bytes
+    }
+}
+
+/// Tears down the direct link, either directly or via the AP.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TdlsTeardown {
+    pub reason_code: u16,
+}
+
+impl TdlsTeardown {
+    pub fn encode(&self) -> Vec<u8> {
+        self.reason_code.to_le_bytes().to_vec()
+    }
+}
+
+/// Sent via the AP to a peer in power save mode, to indicate that there's buffered traffic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TdlsPeerTrafficIndication {
+    pub dialog_token: u8,
+}
+
+impl TdlsPeerTrafficIndication {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![self.dialog_token]
+    }
+}
+
+/// Requests the peer to move the direct link to an off-channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TdlsChannelSwitchRequest {
+    pub target_channel: u8,
+    pub operating_class: u8,
+}
+
+impl TdlsChannelSwitchRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![self.target_channel, self.operating_class]
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TdlsChannelSwitchResponse {
+    pub status_code: u16,
+}
+
+impl TdlsChannelSwitchResponse {
+    pub fn encode(&self) -> Vec<u8> {
+        self.status_code.to_le_bytes().to_vec()
+    }
+}
+
+/// Sent via the AP to discover TDLS capable stations in the same BSS.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TdlsDiscoveryRequest {
+    pub dialog_token: u8,
+}
+
+impl TdlsDiscoveryRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![self.dialog_token]
+    }
+}
+
+/// The answer to a [TdlsDiscoveryRequest], which is sent directly to the requesting station
+/// as public action frame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TdlsDiscoveryResponse {
+    pub dialog_token: u8,
+    pub capability_info: u16,
+}
+
+impl TdlsDiscoveryResponse {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.dialog_token];
+        bytes.extend(self.capability_info.to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,229 @@
+use crate::frame::components::{BssTerminationDuration, MacAddress};
+
+/// Sent by a station to ask its AP for a BSS Transition Management Request.
+///
+/// An optional candidate list is stored in [StationInfo::neighbor_reports](crate::frame::components::StationInfo::neighbor_reports).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BssTransitionQuery {
+    pub dialog_token: u8,
+    /// The BSS Transition Query Reason, e.g. `16` for a low RSSI.
+    pub reason: u8,
+}
+
+impl BssTransitionQuery {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![self.dialog_token, self.reason]
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BtmRequestMode {
+    pub preferred_candidate_list_included: bool, // bit 0
+    pub abridged: bool,                          // 1
+    pub disassociation_imminent: bool,           // 2
+    pub bss_termination_included: bool,          // 3
+    pub ess_disassociation_imminent: bool,       // 4
+    pub link_removal_imminent: bool,             // 5
+    pub reserved: u8,                            // 6-7
+}
+
+impl BtmRequestMode {
+    pub fn from_bits(bits: u8) -> BtmRequestMode {
+        BtmRequestMode {
+            preferred_candidate_list_included: bits & 0b1 != 0,
+            abridged: bits & 0b10 != 0,
+            disassociation_imminent: bits & 0b100 != 0,
+            bss_termination_included: bits & 0b1000 != 0,
+            ess_disassociation_imminent: bits & 0b1_0000 != 0,
+            link_removal_imminent: bits & 0b10_0000 != 0,
+            reserved: bits >> 6,
+        }
+    }
+
+    pub fn encode(&self) -> u8 {
+        self.preferred_candidate_list_included as u8
+            | (self.abridged as u8) << 1
+            | (self.disassociation_imminent as u8) << 2
+            | (self.bss_termination_included as u8) << 3
+            | (self.ess_disassociation_imminent as u8) << 4
+            | (self.link_removal_imminent as u8) << 5
+            | self.reserved << 6
+    }
+}
+
+/// Sent by an AP to steer a station to another BSS.
+///
+/// The candidate list is stored in [StationInfo::neighbor_reports](crate::frame::components::StationInfo::neighbor_reports).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BssTransitionRequest {
+    pub dialog_token: u8,
+    pub request_mode: BtmRequestMode,
+    /// The number of TBTTs until the AP disassociates the station, `0` if unknown.
+    pub disassociation_timer: u16,
+    /// The number of beacon intervals for which the candidate list is valid.
+    pub validity_interval: u8,
+    /// Only present if [BtmRequestMode::bss_termination_included] is set.
+    pub bss_termination_duration: Option<BssTerminationDuration>,
+    /// Only present if [BtmRequestMode::ess_disassociation_imminent] is set.
+    pub session_information_url: Option<Vec<u8>>,
+}
+
+impl BssTransitionRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.dialog_token, self.request_mode.encode()];
+        bytes.extend(self.disassociation_timer.to_le_bytes());
+        bytes.push(self.validity_interval);
+
+        if let Some(duration) = &self.bss_termination_duration {
+            let data = duration.encode();
+            bytes.push(4);
+            bytes.push(data.len() as u8);
+            bytes.extend(data);
+        }
+
+        if let Some(url) = &self.session_information_url {
+            bytes.push(url.len() as u8);
+            bytes.extend(url);
+        }
+
+        bytes
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BtmStatusCode {
+    Accept,
+    RejectUnspecified,
+    RejectInsufficientBeacon,
+    RejectInsufficientCapacity,
+    RejectBssTerminationUndesired,
+    RejectBssTerminationDelayRequest,
+    RejectStaBssTransitionCandidateListProvided,
+    RejectNoSuitableBssTransitionCandidates,
+    RejectLeavingEss,
+    Unknown(u8),
+}
+
+impl From<u8> for BtmStatusCode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => BtmStatusCode::Accept,
+            1 => BtmStatusCode::RejectUnspecified,
+            2 => BtmStatusCode::RejectInsufficientBeacon,
+            3 => BtmStatusCode::RejectInsufficientCapacity,
+            4 => BtmStatusCode::RejectBssTerminationUndesired,
+            5 => BtmStatusCode::RejectBssTerminationDelayRequest,
+            6 => BtmStatusCode::RejectStaBssTransitionCandidateListProvided,
+            7 => BtmStatusCode::RejectNoSuitableBssTransitionCandidates,
+            8 => BtmStatusCode::RejectLeavingEss,
+            other => BtmStatusCode::Unknown(other),
+        }
+    }
+}
+
+impl BtmStatusCode {
+    pub fn encode(&self) -> u8 {
+        match self {
+            BtmStatusCode::Accept => 0,
+            BtmStatusCode::RejectUnspecified => 1,
+            BtmStatusCode::RejectInsufficientBeacon => 2,
+            BtmStatusCode::RejectInsufficientCapacity => 3,
+            BtmStatusCode::RejectBssTerminationUndesired => 4,
+            BtmStatusCode::RejectBssTerminationDelayRequest => 5,
+            BtmStatusCode::RejectStaBssTransitionCandidateListProvided => 6,
+            BtmStatusCode::RejectNoSuitableBssTransitionCandidates => 7,
+            BtmStatusCode::RejectLeavingEss => 8,
+            BtmStatusCode::Unknown(value) => *value,
+        }
+    }
+}
+
+/// The station's answer to a [BssTransitionRequest].
+///
+/// An optional candidate list is stored in [StationInfo::neighbor_reports](crate::frame::components::StationInfo::neighbor_reports).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BssTransitionResponse {
+    pub dialog_token: u8,
+    pub status_code: BtmStatusCode,
+    /// The number of minutes the AP is asked to delay the BSS termination.
+    pub bss_termination_delay: u8,
+    /// The BSS the station transitions to. Only present if the request has been accepted.
+    pub target_bssid: Option<MacAddress>,
+}
+
+impl BssTransitionResponse {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![
+            self.dialog_token,
+            self.status_code.encode(),
+            self.bss_termination_delay,
+        ];
+        if let Some(bssid) = &self.target_bssid {
+            bytes.extend(bssid.encode());
+        }
+        bytes
+    }
+}
+
+/// The fixed fields of a WNM Sleep Mode Request frame.
+///
+/// The WNM Sleep Mode and TFS Request elements are stored in the frame's
+/// [StationInfo](crate::frame::components::StationInfo).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WnmSleepModeRequest {
+    pub dialog_token: u8,
+}
+
+impl WnmSleepModeRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![self.dialog_token]
+    }
+}
+
+/// The fixed fields of a WNM Sleep Mode Response frame.
+///
+/// The WNM Sleep Mode and TFS Response elements are stored in the frame's
+/// [StationInfo](crate::frame::components::StationInfo).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WnmSleepModeResponse {
+    pub dialog_token: u8,
+    /// The GTK and IGTK subelements, which are sent when the station exits the sleep mode.
+    pub key_data: Vec<u8>,
+}
+
+impl WnmSleepModeResponse {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.dialog_token];
+        bytes.extend((self.key_data.len() as u16).to_le_bytes());
+        bytes.extend(&self.key_data);
+        bytes
+    }
+}
+
+/// The fixed fields of a TFS Request frame.
+///
+/// The TFS Request elements are stored in [StationInfo::tfs_requests](crate::frame::components::StationInfo::tfs_requests).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TfsRequest {
+    pub dialog_token: u8,
+}
+
+impl TfsRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![self.dialog_token]
+    }
+}
+
+/// The fixed fields of a TFS Response frame.
+///
+/// The TFS Response elements are stored in [StationInfo::tfs_responses](crate::frame::components::StationInfo::tfs_responses).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TfsResponse {
+    pub dialog_token: u8,
+}
+
+impl TfsResponse {
+    pub fn encode(&self) -> Vec<u8> {
+        vec![self.dialog_token]
+    }
+}
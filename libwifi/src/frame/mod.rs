@@ -61,3 +61,23 @@ pub enum Frame {
     QosNull(QosNull),
     NullData(NullData),
 }
+
+impl Frame {
+    /// Whether this is a robust management frame, i.e. a frame that is protected once management
+    /// frame protection (802.11w) has been negotiated.
+    ///
+    /// An IDS can flag robust frames whose
+    /// [FrameControl::protected](components::FrameControl::protected) flag isn't set in networks
+    /// that advertise [RsnInformation::mfp_required](components::RsnInformation::mfp_required),
+    /// as these are likely spoofed.
+    pub fn is_robust(&self) -> bool {
+        match self {
+            Frame::Deauthentication(_) => true,
+            // Only robust action frames are encrypted, which includes their category.
+            Frame::Action(action) => {
+                action.header.frame_control.protected() || action.category.is_robust()
+            }
+            _ => false,
+        }
+    }
+}
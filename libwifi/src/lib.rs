@@ -28,7 +28,19 @@ const CRC_32: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
 ///
 /// If `fcs_included` is set to `true`, it will be assumed that a CRC checksum exists
 /// and it will be used to verify the payload before parsing starts.
+///
+/// Elements are parsed with the built-in parsers, see [parse_frame_with] for custom parsers.
 pub fn parse_frame(input: &[u8], fcs_included: bool) -> Result<Frame, Error> {
+    parse_frame_with(input, fcs_included, ElementRegistry::builtin())
+}
+
+/// Parse IEE 802.11 frames like [parse_frame], using the custom element parsers and options
+/// of `registry` for the elements of management frames.
+pub fn parse_frame_with(
+    mut input: &[u8],
+    fcs_included: bool,
+    registry: &ElementRegistry,
+) -> Result<Frame, Error> {
     if fcs_included {
         if input.len() < 4 {
             return Err(Error::Incomplete("Incomplete".to_string()));
@@ -50,6 +62,9 @@ pub fn parse_frame(input: &[u8], fcs_included: bool) -> Result<Frame, Error> {
                 crc, fcs
             )));
         }
+
+        // The FCS isn't part of the frame body
+        input = frame_data;
     }
 
     let (input, frame_control) = parse_frame_control(input)?;
@@ -57,16 +72,24 @@ pub fn parse_frame(input: &[u8], fcs_included: bool) -> Result<Frame, Error> {
     // Check which kind of frame sub-type we got
     match frame_control.frame_subtype {
         // Management
-        FrameSubType::Beacon => parse_beacon(frame_control, input),
-        FrameSubType::ProbeRequest => parse_probe_request(frame_control, input),
-        FrameSubType::ProbeResponse => parse_probe_response(frame_control, input),
-        FrameSubType::AssociationRequest => parse_association_request(frame_control, input),
-        FrameSubType::AssociationResponse => parse_association_response(frame_control, input),
-        FrameSubType::ReassociationRequest => parse_reassociation_request(frame_control, input),
-        FrameSubType::ReassociationResponse => parse_reassociation_response(frame_control, input),
-        FrameSubType::Authentication => parse_authentication_frame(frame_control, input),
+        FrameSubType::Beacon => parse_beacon(frame_control, input, registry),
+        FrameSubType::ProbeRequest => parse_probe_request(frame_control, input, registry),
+        FrameSubType::ProbeResponse => parse_probe_response(frame_control, input, registry),
+        FrameSubType::AssociationRequest => {
+            parse_association_request(frame_control, input, registry)
+        }
+        FrameSubType::AssociationResponse => {
+            parse_association_response(frame_control, input, registry)
+        }
+        FrameSubType::ReassociationRequest => {
+            parse_reassociation_request(frame_control, input, registry)
+        }
+        FrameSubType::ReassociationResponse => {
+            parse_reassociation_response(frame_control, input, registry)
+        }
+        FrameSubType::Authentication => parse_authentication_frame(frame_control, input, registry),
         FrameSubType::Deauthentication => parse_deauthentication_frame(frame_control, input),
-        FrameSubType::Action => parse_action(frame_control, input),
+        FrameSubType::Action => parse_action(frame_control, input, registry),
 
         // Control
         FrameSubType::Rts => parse_rts(frame_control, input),
@@ -0,0 +1,306 @@
+use nom::{
+    IResult, Parser,
+    bytes::complete::{tag, take},
+    combinator::{all_consuming, map, map_res, rest, verify},
+    multi::{count, many0},
+    number::complete::{le_u8, le_u16, le_u32},
+};
+
+use super::clone_slice;
+use crate::frame::components::{
+    AnqpElement, AuthenticationParameter, CellularNetwork, EapMethod, Hs20AnqpElement,
+    IpAddressTypeAvailability, LocalizedName, NaiRealm, NetworkAuthenticationIndicator,
+    NetworkAuthenticationType, Plmn, ProtocolPort, VenueInfo, VenueName, WFA_OUI, WanMetrics,
+};
+
+/// Parse a list of ANQP elements, e.g. the query request or response of a GAS frame.
+///
+/// Each element consists of
+/// - Info ID (2 bytes)
+/// - Length (2 bytes)
+/// - Payload (variable)
+pub fn parse_anqp_elements(input: &[u8]) -> IResult<&[u8], Vec<AnqpElement>> {
+    many0(parse_anqp_element).parse(input)
+}
+
+fn parse_anqp_element(input: &[u8]) -> IResult<&[u8], AnqpElement> {
+    let (input, (info_id, data)) = (le_u16, le_u16.flat_map(take)).parse(input)?;
+
+    let element = match info_id {
+        256 => all_consuming(map(many0(le_u16), AnqpElement::QueryList)).parse(data),
+        257 => all_consuming(map(many0(le_u16), AnqpElement::CapabilityList)).parse(data),
+        258 => all_consuming(map(parse_venue_name, AnqpElement::VenueName)).parse(data),
+        260 => all_consuming(map(
+            many0(parse_network_authentication_type),
+            AnqpElement::NetworkAuthenticationTypes,
+        ))
+        .parse(data),
+        261 => all_consuming(map(
+            many0(map(le_u8.flat_map(take), |oi: &[u8]| oi.to_vec())),
+            AnqpElement::RoamingConsortium,
+        ))
+        .parse(data),
+        262 => all_consuming(map(le_u8, |bits| {
+            AnqpElement::IpAddressTypeAvailability(IpAddressTypeAvailability::from_bits(bits))
+        }))
+        .parse(data),
+        263 => all_consuming(map(parse_nai_realms, AnqpElement::NaiRealms)).parse(data),
+        264 => all_consuming(map(parse_cellular_network, AnqpElement::CellularNetwork)).parse(data),
+        268 => all_consuming(map(
+            many0(parse_length_prefixed_string),
+            AnqpElement::DomainNames,
+        ))
+        .parse(data),
+        56797 => all_consuming(map(parse_hs20_anqp_element, AnqpElement::Hs20)).parse(data),
+        _ => Err(nom::Err::Error(nom::error::Error::new(
+            data,
+            nom::error::ErrorKind::Switch,
+        ))),
+    };
+
+    // Keep elements that can't be decoded losslessly as raw data.
+    let element = element.map_or_else(
+        |_| AnqpElement::Unknown {
+            info_id,
+            data: data.to_vec(),
+        },
+        |(_, element)| element,
+    );
+
+    Ok((input, element))
+}
+
+fn parse_utf8(input: &[u8]) -> Result<String, std::str::Utf8Error> {
+    std::str::from_utf8(input).map(str::to_owned)
+}
+
+fn parse_length_prefixed_string(input: &[u8]) -> IResult<&[u8], String> {
+    map_res(le_u8.flat_map(take), parse_utf8).parse(input)
+}
+
+/// - Length (1 byte)
+/// - Language Code (3 bytes)
+/// - Name (variable)
+fn parse_localized_name(input: &[u8]) -> IResult<&[u8], LocalizedName> {
+    map_res(
+        verify(le_u8, |length| *length >= 3).flat_map(take),
+        |data: &[u8]| {
+            parse_utf8(&data[3..]).map(|name| LocalizedName {
+                language_code: clone_slice::<3>(data),
+                name,
+            })
+        },
+    )
+    .parse(input)
+}
+
+/// - Venue Info (2 bytes)
+/// - Venue Names (variable)
+fn parse_venue_name(input: &[u8]) -> IResult<&[u8], VenueName> {
+    let (input, (group, venue_type, names)) =
+        (le_u8, le_u8, many0(parse_localized_name)).parse(input)?;
+
+    Ok((
+        input,
+        VenueName {
+            venue_info: VenueInfo { group, venue_type },
+            names,
+        },
+    ))
+}
+
+/// - Network Authentication Type Indicator (1 byte)
+/// - Redirect URL Length (2 bytes)
+/// - Redirect URL (variable)
+fn parse_network_authentication_type(input: &[u8]) -> IResult<&[u8], NetworkAuthenticationType> {
+    let (input, (indicator, redirect_url)) =
+        (le_u8, map_res(le_u16.flat_map(take), parse_utf8)).parse(input)?;
+
+    Ok((
+        input,
+        NetworkAuthenticationType {
+            indicator: NetworkAuthenticationIndicator::from(indicator),
+            redirect_url,
+        },
+    ))
+}
+
+/// - NAI Realm Count (2 bytes)
+/// - NAI Realm Data fields, each prefixed by a 2 byte length
+fn parse_nai_realms(input: &[u8]) -> IResult<&[u8], Vec<NaiRealm>> {
+    let (input, realm_count) = le_u16(input)?;
+    count(
+        map_res(le_u16.flat_map(take), |data| {
+            all_consuming(parse_nai_realm)
+                .parse(data)
+                .map(|(_, realm)| realm)
+        }),
+        realm_count as usize,
+    )
+    .parse(input)
+}
+
+/// - NAI Realm Encoding (1 byte)
+/// - NAI Realm Length (1 byte)
+/// - NAI Realm (variable)
+/// - EAP Method Count (1 byte)
+/// - EAP Methods, each prefixed by a 1 byte length
+fn parse_nai_realm(input: &[u8]) -> IResult<&[u8], NaiRealm> {
+    let (input, (encoding, realm, method_count)) =
+        (le_u8, parse_length_prefixed_string, le_u8).parse(input)?;
+    let (input, eap_methods) = count(
+        map_res(le_u8.flat_map(take), |data| {
+            all_consuming(parse_eap_method)
+                .parse(data)
+                .map(|(_, method)| method)
+        }),
+        method_count as usize,
+    )
+    .parse(input)?;
+
+    Ok((
+        input,
+        NaiRealm {
+            utf8: encoding & 1 != 0,
+            reserved: encoding >> 1,
+            realm,
+            eap_methods,
+        },
+    ))
+}
+
+/// - EAP Method (1 byte)
+/// - Authentication Parameter Count (1 byte)
+/// - Authentication Parameters, each consisting of an ID, a length and a value
+fn parse_eap_method(input: &[u8]) -> IResult<&[u8], EapMethod> {
+    let (input, (method, parameter_count)) = (le_u8, le_u8).parse(input)?;
+    let (input, authentication_parameters) = count(
+        map((le_u8, le_u8.flat_map(take)), |(id, value): (u8, &[u8])| {
+            AuthenticationParameter {
+                id,
+                value: value.to_vec(),
+            }
+        }),
+        parameter_count as usize,
+    )
+    .parse(input)?;
+
+    Ok((
+        input,
+        EapMethod {
+            method,
+            authentication_parameters,
+        },
+    ))
+}
+
+/// - GUD, the Generic container User Data version (1 byte)
+/// - UDHL, the length of the remaining data (1 byte)
+/// - PLMN List IEI (1 byte, 0)
+/// - PLMN List Length (1 byte)
+/// - Number of PLMNs (1 byte)
+/// - PLMNs (3 bytes each)
+fn parse_cellular_network(input: &[u8]) -> IResult<&[u8], CellularNetwork> {
+    let (input, (version, _user_data_length, _, _plmn_list_length, plmn_count)) = (
+        le_u8,
+        verify(le_u8, |length| *length as usize == input.len() - 2),
+        tag(&[0][..]),
+        verify(le_u8, |length| *length as usize == input.len() - 4),
+        le_u8,
+    )
+        .parse(input)?;
+    let (input, plmns) = count(
+        map(take(3usize), |plmn: &[u8]| Plmn(clone_slice::<3>(plmn))),
+        plmn_count as usize,
+    )
+    .parse(input)?;
+
+    Ok((input, CellularNetwork { version, plmns }))
+}
+
+/// Parse the payload of a Hotspot 2.0 ANQP element.
+///
+/// - OUI (3 bytes)
+/// - OUI Type (1 byte, 0x11)
+/// - Subtype (1 byte)
+/// - Reserved (1 byte, 0)
+/// - Payload (variable)
+fn parse_hs20_anqp_element(input: &[u8]) -> IResult<&[u8], Hs20AnqpElement> {
+    let (input, (_, _, subtype, _)) =
+        (tag(&WFA_OUI[..]), tag(&[0x11][..]), le_u8, tag(&[0][..])).parse(input)?;
+
+    match subtype {
+        1 => map(rest, |subtypes: &[u8]| {
+            Hs20AnqpElement::QueryList(subtypes.to_vec())
+        })
+        .parse(input),
+        2 => map(rest, |subtypes: &[u8]| {
+            Hs20AnqpElement::CapabilityList(subtypes.to_vec())
+        })
+        .parse(input),
+        3 => map(
+            many0(parse_localized_name),
+            Hs20AnqpElement::OperatorFriendlyName,
+        )
+        .parse(input),
+        4 => map(parse_wan_metrics, Hs20AnqpElement::WanMetrics).parse(input),
+        5 => map(
+            many0(parse_protocol_port),
+            Hs20AnqpElement::ConnectionCapability,
+        )
+        .parse(input),
+        7 => map(rest, |classes: &[u8]| {
+            Hs20AnqpElement::OperatingClassIndication(classes.to_vec())
+        })
+        .parse(input),
+        _ => map(rest, |data: &[u8]| Hs20AnqpElement::Unknown {
+            subtype,
+            data: data.to_vec(),
+        })
+        .parse(input),
+    }
+}
+
+/// - WAN Info (1 byte)
+/// - Downlink Speed (4 bytes)
+/// - Uplink Speed (4 bytes)
+/// - Downlink Load (1 byte)
+/// - Uplink Load (1 byte)
+/// - Load Measurement Duration (2 bytes)
+fn parse_wan_metrics(input: &[u8]) -> IResult<&[u8], WanMetrics> {
+    let (
+        input,
+        (wan_info, downlink_speed, uplink_speed, downlink_load, uplink_load, load_duration),
+    ) = (le_u8, le_u32, le_u32, le_u8, le_u8, le_u16).parse(input)?;
+
+    Ok((
+        input,
+        WanMetrics {
+            link_status: wan_info & 0b11,
+            symmetric_link: wan_info & 0b100 != 0,
+            at_capacity: wan_info & 0b1000 != 0,
+            reserved: wan_info >> 4,
+            downlink_speed,
+            uplink_speed,
+            downlink_load,
+            uplink_load,
+            load_measurement_duration: load_duration,
+        },
+    ))
+}
+
+/// - IP Protocol (1 byte)
+/// - Port Number (2 bytes)
+/// - Status (1 byte)
+fn parse_protocol_port(input: &[u8]) -> IResult<&[u8], ProtocolPort> {
+    let (input, (ip_protocol, port, status)) = (le_u8, le_u16, le_u8).parse(input)?;
+
+    Ok((
+        input,
+        ProtocolPort {
+            ip_protocol,
+            port,
+            status,
+        },
+    ))
+}
@@ -0,0 +1,54 @@
+use nom::{
+    IResult, Parser,
+    number::complete::{le_u8, le_u16},
+};
+
+use crate::frame::components::{BssLoad, ExtendedBssLoad};
+
+/// Parse the BSS Load element.
+///
+/// - Station Count (2 bytes)
+/// - Channel Utilization (1 byte)
+/// - Available Admission Capacity (2 bytes)
+pub fn parse_bss_load(input: &[u8]) -> IResult<&[u8], BssLoad> {
+    let (input, (station_count, channel_utilization, available_admission_capacity)) =
+        (le_u16, le_u8, le_u16).parse(input)?;
+
+    Ok((
+        input,
+        BssLoad {
+            station_count,
+            channel_utilization,
+            available_admission_capacity,
+        },
+    ))
+}
+
+/// Parse the Extended BSS Load element.
+///
+/// - MU-MIMO Capable STA Count (2 bytes)
+/// - Spatial Stream Underutilization (1 byte)
+/// - Observable Secondary 20/40/80 MHz Utilization (1 byte each)
+pub fn parse_extended_bss_load(input: &[u8]) -> IResult<&[u8], ExtendedBssLoad> {
+    let (
+        input,
+        (
+            mu_mimo_capable_station_count,
+            spatial_stream_underutilization,
+            observable_secondary_20mhz_utilization,
+            observable_secondary_40mhz_utilization,
+            observable_secondary_80mhz_utilization,
+        ),
+    ) = (le_u16, le_u8, le_u8, le_u8, le_u8).parse(input)?;
+
+    Ok((
+        input,
+        ExtendedBssLoad {
+            mu_mimo_capable_station_count,
+            spatial_stream_underutilization,
+            observable_secondary_20mhz_utilization,
+            observable_secondary_40mhz_utilization,
+            observable_secondary_80mhz_utilization,
+        },
+    ))
+}
@@ -0,0 +1,81 @@
+use nom::{
+    IResult, Parser,
+    bytes::complete::take,
+    number::complete::{le_u8, le_u24},
+};
+
+use crate::frame::components::{
+    ChannelSwitchMode, ChannelSwitchWrapper, ChannelSwitchWrapperSubelement,
+    ExtendedChannelSwitchAnnouncement, WideBandwidthChannelSwitch,
+};
+
+/// Parse the Extended Channel Switch Announcement element.
+///
+/// - Channel Switch Mode (1 byte)
+/// - New Operating Class (1 byte)
+/// - New Channel Number (1 byte)
+/// - Channel Switch Count (1 byte)
+pub fn parse_extended_channel_switch(
+    input: &[u8],
+) -> IResult<&[u8], ExtendedChannelSwitchAnnouncement> {
+    let (input, (mode, new_operating_class, new_channel, count)) =
+        (le_u8, le_u8, le_u8, le_u8).parse(input)?;
+
+    Ok((
+        input,
+        ExtendedChannelSwitchAnnouncement {
+            mode: ChannelSwitchMode::from_u8(mode),
+            new_operating_class,
+            new_channel,
+            count,
+        },
+    ))
+}
+
+/// Parse the Wide Bandwidth Channel Switch element.
+///
+/// - New Channel Width (1 byte)
+/// - New Channel Center Frequency Segment 0 (1 byte)
+/// - New Channel Center Frequency Segment 1 (1 byte)
+pub fn parse_wide_bandwidth_channel_switch(
+    input: &[u8],
+) -> IResult<&[u8], WideBandwidthChannelSwitch> {
+    let (input, (new_channel_width, new_ccfs0, new_ccfs1)) = (le_u8, le_u8, le_u8).parse(input)?;
+
+    Ok((
+        input,
+        WideBandwidthChannelSwitch {
+            new_channel_width,
+            new_ccfs0,
+            new_ccfs1,
+        },
+    ))
+}
+
+/// Parse the Channel Switch Wrapper element, which contains a list of subelements.
+pub fn parse_channel_switch_wrapper(mut input: &[u8]) -> IResult<&[u8], ChannelSwitchWrapper> {
+    let mut subelements = Vec::new();
+    while !input.is_empty() {
+        let (id, length, data);
+        (input, (id, length)) = (le_u8, le_u8).parse(input)?;
+        (input, data) = take(length)(input)?;
+
+        let subelement = match parse_wide_bandwidth_channel_switch(data) {
+            Ok(([], switch)) if id == 194 => {
+                ChannelSwitchWrapperSubelement::WideBandwidthChannelSwitch(switch)
+            }
+            _ => ChannelSwitchWrapperSubelement::Unknown {
+                id,
+                data: data.to_vec(),
+            },
+        };
+        subelements.push(subelement);
+    }
+
+    Ok((input, ChannelSwitchWrapper { subelements }))
+}
+
+/// Parse the Max Channel Switch Time element, which contains the switch time in TUs.
+pub fn parse_max_channel_switch_time(input: &[u8]) -> IResult<&[u8], u32> {
+    le_u24(input)
+}
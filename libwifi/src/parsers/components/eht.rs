@@ -0,0 +1,211 @@
+use nom::{
+    IResult, Parser,
+    bytes::complete::take,
+    combinator::{cond, verify},
+    number::complete::{le_u8, le_u16, le_u64},
+};
+
+use super::station_info::parse_element_list;
+use super::{ElementRegistry, clone_slice, parse_mac};
+use crate::frame::components::{
+    BasicMultiLink, EhtCapabilities, EhtMacCapabilities, EhtOperation, EhtOperationInformation,
+    EhtPhyCapabilities, EmlCapabilities, MldCapabilities, MultiLink, PerStaProfile,
+};
+
+/// Parse the EHT Capabilities element.
+///
+/// `input` is the element payload without the extension element id.
+pub fn parse_eht_capabilities(input: &[u8]) -> IResult<&[u8], EhtCapabilities> {
+    let (input, (mac_bits, phy)) = (le_u16, take(9usize)).parse(input)?;
+
+    let phy_capabilities = EhtPhyCapabilities {
+        support_320mhz_in_6ghz: phy[0] & (1 << 1) != 0,
+        su_beamformer: phy[0] & (1 << 5) != 0,
+        su_beamformee: phy[0] & (1 << 6) != 0,
+        beamformee_ss_80mhz: (phy[0] >> 7) | ((phy[1] & 0b11) << 1),
+        partial_bandwidth_dl_mu_mimo: phy[4] & 1 != 0,
+        ppe_thresholds_present: phy[5] & (1 << 3) != 0,
+        data: phy.to_vec(),
+    };
+
+    Ok((
+        &[],
+        EhtCapabilities {
+            mac_capabilities: EhtMacCapabilities::from_bits(mac_bits),
+            phy_capabilities,
+            supported_mcs_nss_and_ppe_thresholds: input.to_vec(),
+        },
+    ))
+}
+
+/// Parse the EHT Operation element.
+///
+/// `input` is the element payload without the extension element id.
+pub fn parse_eht_operation(input: &[u8]) -> IResult<&[u8], EhtOperation> {
+    let (input, (parameters, basic_mcs_nss_set)) = (le_u8, take(4usize)).parse(input)?;
+    let information_present = parameters & 1 != 0;
+    let bitmap_present = parameters & (1 << 1) != 0;
+
+    let (input, information) = cond(
+        information_present,
+        (le_u8, le_u8, le_u8, cond(bitmap_present, le_u16)),
+    )
+    .parse(input)?;
+
+    let operation_information =
+        information.map(|(control, ccfs0, ccfs1, bitmap)| EhtOperationInformation {
+            channel_width: (control & 0b111).into(),
+            ccfs0,
+            ccfs1,
+            disabled_subchannel_bitmap: bitmap,
+        });
+
+    Ok((
+        input,
+        EhtOperation {
+            default_pe_duration: parameters & (1 << 2) != 0,
+            group_addressed_bu_indication_limit: parameters & (1 << 3) != 0,
+            group_addressed_bu_indication_exponent: (parameters >> 4) & 0b11,
+            basic_mcs_nss_set: clone_slice::<4>(basic_mcs_nss_set),
+            operation_information,
+        },
+    ))
+}
+
+/// Parse the Multi-Link element.
+///
+/// `input` is the element payload without the extension element id.
+/// Only the Basic variant is decoded, all other variants are kept as raw bytes.
+///
+/// The general structure of the Basic variant is:
+/// - Multi-Link Control (type + presence bitmap)
+/// - Common Info (length, MLD MAC address + the fields announced in the presence bitmap)
+/// - Link Info (a list of subelements, usually Per-STA Profiles)
+pub fn parse_multi_link(input: &[u8]) -> IResult<&[u8], MultiLink> {
+    parse_multi_link_with(input, ElementRegistry::builtin())
+}
+
+pub(crate) fn parse_multi_link_with<'a>(
+    input: &'a [u8],
+    registry: &ElementRegistry,
+) -> IResult<&'a [u8], MultiLink> {
+    let (input, control) = le_u16(input)?;
+    if control & 0b111 != 0 {
+        return Ok((
+            &[],
+            MultiLink::Unknown {
+                control,
+                data: input.to_vec(),
+            },
+        ));
+    }
+
+    // The common info length includes the length byte itself.
+    let (input, common_info_length) = verify(le_u8, |length| *length >= 7).parse(input)?;
+    let (mut link_info, common) = take(common_info_length - 1)(input)?;
+
+    let presence = |bit: u16| control & (1 << bit) != 0;
+    let (
+        _,
+        (
+            mld_mac_address,
+            link_id,
+            bss_parameters_change_count,
+            medium_synchronization_delay,
+            eml_capabilities,
+            mld_capabilities,
+            ap_mld_id,
+            extended_mld_capabilities,
+        ),
+    ) = (
+        parse_mac,
+        cond(presence(4), le_u8),
+        cond(presence(5), le_u8),
+        cond(presence(6), le_u16),
+        cond(presence(7), le_u16),
+        cond(presence(8), le_u16),
+        cond(presence(9), le_u8),
+        cond(presence(10), le_u16),
+    )
+        .parse(common)?;
+
+    // The link info is a list of subelements, each of which may be a Per-STA Profile.
+    let mut per_sta_profiles = Vec::new();
+    while !link_info.is_empty() {
+        let (subelement_id, length, subelement);
+        (link_info, (subelement_id, length)) = (le_u8, le_u8).parse(link_info)?;
+        (link_info, subelement) = take(length)(link_info)?;
+        // Vendor specific and fragment subelements are skipped.
+        if subelement_id == 0 {
+            let (_, profile) = parse_per_sta_profile(subelement, registry)?;
+            per_sta_profiles.push(profile);
+        }
+    }
+
+    Ok((
+        link_info,
+        MultiLink::Basic(BasicMultiLink {
+            mld_mac_address,
+            link_id: link_id.map(|id| id & 0x0f),
+            bss_parameters_change_count,
+            medium_synchronization_delay,
+            eml_capabilities: eml_capabilities.map(EmlCapabilities::from_bits),
+            mld_capabilities: mld_capabilities.map(MldCapabilities::from_bits),
+            ap_mld_id,
+            extended_mld_capabilities,
+            per_sta_profiles,
+        }),
+    ))
+}
+
+/// Parse a Per-STA Profile subelement of a Basic Multi-Link element.
+///
+/// The general structure is:
+/// - STA Control
+/// - STA Info (length + the fields announced in the STA Control)
+/// - STA Profile (capability info for complete profiles + list of elements)
+fn parse_per_sta_profile<'a>(
+    input: &'a [u8],
+    registry: &ElementRegistry,
+) -> IResult<&'a [u8], PerStaProfile> {
+    let (input, control) = le_u16(input)?;
+    let flag = |bit: u16| control & (1 << bit) != 0;
+
+    // The STA info length includes the length byte itself.
+    let (input, sta_info_length) = verify(le_u8, |length| *length >= 1).parse(input)?;
+    let (input, sta_info) = take(sta_info_length - 1)(input)?;
+
+    let nstr_bitmap_size = if flag(10) { 2usize } else { 1 };
+    let (_, (sta_mac_address, beacon_interval, tsf_offset, dtim_info, nstr_bitmap, change_count)) =
+        (
+            cond(flag(5), parse_mac),
+            cond(flag(6), le_u16),
+            cond(flag(7), le_u64),
+            cond(flag(8), (le_u8, le_u8)),
+            cond(flag(9), take(nstr_bitmap_size)),
+            cond(flag(11), le_u8),
+        )
+            .parse(sta_info)?;
+
+    let complete_profile = flag(4);
+    let (input, capability_info) =
+        cond(complete_profile && input.len() >= 2, le_u16).parse(input)?;
+
+    let (_, station_info) = parse_element_list(input, registry)?;
+
+    Ok((
+        &[],
+        PerStaProfile {
+            link_id: (control & 0x0f) as u8,
+            complete_profile,
+            sta_mac_address,
+            beacon_interval,
+            tsf_offset,
+            dtim_info,
+            nstr_indication_bitmap: nstr_bitmap.map(|bitmap: &[u8]| bitmap.to_vec()),
+            bss_parameters_change_count: change_count,
+            capability_info,
+            station_info,
+        },
+    ))
+}
@@ -0,0 +1,118 @@
+use nom::{
+    IResult, Parser,
+    bytes::complete::take,
+    combinator::map,
+    number::complete::{le_u8, le_u16, le_u32},
+};
+
+use super::{clone_slice, parse_mac};
+use crate::frame::components::{
+    FastTransition, FtCapabilityPolicy, FtMicControl, FtSubelement, MobilityDomain,
+    TimeoutInterval, TimeoutIntervalType,
+};
+
+/// Parse the Mobility Domain element.
+///
+/// - MDID (2 bytes)
+/// - FT Capability and Policy (1 byte)
+pub fn parse_mobility_domain(input: &[u8]) -> IResult<&[u8], MobilityDomain> {
+    let (input, (mdid, ft_capability_policy)) =
+        (le_u16, map(le_u8, FtCapabilityPolicy::from_bits)).parse(input)?;
+
+    Ok((
+        input,
+        MobilityDomain {
+            mdid,
+            ft_capability_policy,
+        },
+    ))
+}
+
+/// Parse the Fast BSS Transition element.
+///
+/// - MIC Control (2 bytes)
+/// - MIC (16, 24 or 32 bytes)
+/// - ANonce (32 bytes)
+/// - SNonce (32 bytes)
+/// - Optional subelements
+pub fn parse_fast_transition(input: &[u8]) -> IResult<&[u8], FastTransition> {
+    let (input, mic_control) = map(take(2usize), |bytes: &[u8]| {
+        FtMicControl::from_bytes(clone_slice::<2>(bytes))
+    })
+    .parse(input)?;
+
+    let (mut input, (mic, anonce, snonce)) =
+        (take(mic_control.mic_len()), take(32usize), take(32usize)).parse(input)?;
+
+    let mut subelements = Vec::new();
+    while !input.is_empty() {
+        let (id, length, data);
+        (input, (id, length)) = (le_u8, le_u8).parse(input)?;
+        (input, data) = take(length)(input)?;
+
+        subelements.push(parse_ft_subelement(id, data));
+    }
+
+    Ok((
+        input,
+        FastTransition {
+            mic_control,
+            mic: mic.to_vec(),
+            anonce: clone_slice::<32>(anonce),
+            snonce: clone_slice::<32>(snonce),
+            subelements,
+        },
+    ))
+}
+
+/// Parse a single FTE subelement.
+/// Malformed subelements are preserved as [FtSubelement::Unknown].
+fn parse_ft_subelement(id: u8, data: &[u8]) -> FtSubelement {
+    let parsed = match id {
+        1 if data.len() == 6 => parse_mac(data).map(|(_, mac)| FtSubelement::R1khId(mac)),
+        2 => (le_u16, le_u8, take(8usize)).parse(data).map(
+            |(wrapped_key, (key_info, key_length, rsc))| FtSubelement::Gtk {
+                key_info,
+                key_length,
+                rsc: clone_slice::<8>(rsc),
+                wrapped_key: wrapped_key.to_vec(),
+            },
+        ),
+        3 => Ok(FtSubelement::R0khId(data.to_vec())),
+        4 => (le_u16, take(6usize), le_u8).parse(data).map(
+            |(wrapped_key, (key_id, ipn, key_length))| FtSubelement::Igtk {
+                key_id,
+                ipn: clone_slice::<6>(ipn),
+                key_length,
+                wrapped_key: wrapped_key.to_vec(),
+            },
+        ),
+        _ => Ok(FtSubelement::Unknown {
+            id,
+            data: data.to_vec(),
+        }),
+    };
+
+    parsed.unwrap_or_else(
+        |_: nom::Err<nom::error::Error<&[u8]>>| FtSubelement::Unknown {
+            id,
+            data: data.to_vec(),
+        },
+    )
+}
+
+/// Parse the Timeout Interval element.
+///
+/// - Timeout Interval Type (1 byte)
+/// - Timeout Interval Value (4 bytes)
+pub fn parse_timeout_interval(input: &[u8]) -> IResult<&[u8], TimeoutInterval> {
+    let (input, (interval_type, value)) = (le_u8, le_u32).parse(input)?;
+
+    Ok((
+        input,
+        TimeoutInterval {
+            interval_type: TimeoutIntervalType::from(interval_type),
+            value,
+        },
+    ))
+}
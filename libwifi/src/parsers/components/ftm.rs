@@ -0,0 +1,45 @@
+use nom::{
+    IResult, Parser,
+    number::complete::{le_u8, le_u16, le_u32},
+};
+
+use crate::frame::components::FtmParameters;
+
+/// Parse the Fine Timing Measurement Parameters element.
+///
+/// - Status Indication, Value (1 byte)
+/// - Number of Bursts Exponent, Burst Duration (1 byte)
+/// - Min Delta FTM (1 byte)
+/// - Partial TSF Timer (2 bytes)
+/// - Partial TSF Timer No Preference, ASAP Capable, ASAP, FTMs per Burst (1 byte)
+/// - Format and Bandwidth (1 byte)
+/// - Burst Period (2 bytes)
+pub fn parse_ftm_parameters(input: &[u8]) -> IResult<&[u8], FtmParameters> {
+    let (input, (status, bursts, min_delta_ftm, partial_tsf_timer, flags, format, burst_period)) =
+        (le_u8, le_u8, le_u8, le_u16, le_u8, le_u8, le_u16).parse(input)?;
+
+    Ok((
+        input,
+        FtmParameters {
+            status_indication: status & 0b11,
+            value: (status >> 2) & 0x1f,
+            reserved: status >> 7,
+            number_of_bursts_exponent: bursts & 0x0f,
+            burst_duration: bursts >> 4,
+            min_delta_ftm,
+            partial_tsf_timer,
+            partial_tsf_timer_no_preference: flags & 0b1 != 0,
+            asap_capable: flags & 0b10 != 0,
+            asap: flags & 0b100 != 0,
+            ftms_per_burst: flags >> 3,
+            reserved_2: format & 0b11,
+            format_and_bandwidth: format >> 2,
+            burst_period,
+        },
+    ))
+}
+
+/// Parse the FTM Synchronization Information element, which contains the TSF Sync Info.
+pub fn parse_ftm_synchronization_information(input: &[u8]) -> IResult<&[u8], u32> {
+    le_u32(input)
+}
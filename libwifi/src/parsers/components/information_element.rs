@@ -0,0 +1,65 @@
+use super::ElementRegistry;
+use super::station_info::decode_element;
+use crate::frame::components::StationInfo;
+
+/// A borrowing iterator over the elements of a frame body.
+///
+/// Other than [parse_station_info](super::parse_station_info), this doesn't decode or copy
+/// anything. It yields `(element id, extension id, payload)` tuples, where the payload of
+/// extension elements doesn't include the extension ID.
+/// Individual elements can then be decoded on demand via [decode_ie].
+///
+/// The iteration stops at the first truncated element.
+/// The bytes that couldn't be consumed are available via [IeIter::remainder].
+/// Trailing bytes that aren't elements, like an FCS, have to be stripped by the caller first,
+/// as they may be mistaken for an element.
+#[derive(Clone, Debug)]
+pub struct IeIter<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> IeIter<'a> {
+    pub fn new(input: &'a [u8]) -> IeIter<'a> {
+        IeIter { input }
+    }
+
+    /// The bytes that haven't been consumed yet.
+    pub fn remainder(&self) -> &'a [u8] {
+        self.input
+    }
+}
+
+impl<'a> Iterator for IeIter<'a> {
+    type Item = (u8, Option<u8>, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let [element_id, length, rest @ ..] = self.input else {
+            return None;
+        };
+        let payload = rest.get(..*length as usize)?;
+        self.input = &rest[*length as usize..];
+
+        match (element_id, payload.split_first()) {
+            (255, Some((extension_id, data))) => Some((255, Some(*extension_id), data)),
+            _ => Some((*element_id, None, payload)),
+        }
+    }
+}
+
+/// Decode a single element, as it's yielded by [IeIter].
+///
+/// The returned [StationInfo] only has the fields set that belong to this element.
+/// Returns `None` if the element isn't decoded by any built-in parser.
+pub fn decode_ie(element_id: u8, extension_id: Option<u8>, data: &[u8]) -> Option<StationInfo> {
+    decode_ie_with(element_id, extension_id, data, ElementRegistry::builtin())
+}
+
+/// Decode a single element like [decode_ie], using the custom element parsers of `registry`.
+pub fn decode_ie_with(
+    element_id: u8,
+    extension_id: Option<u8>,
+    data: &[u8],
+    registry: &ElementRegistry,
+) -> Option<StationInfo> {
+    decode_element(element_id, extension_id, data, registry)
+}
@@ -0,0 +1,147 @@
+use nom::{
+    IResult, Parser,
+    bytes::complete::take,
+    combinator::{cond, fail, map},
+    multi::many0,
+    number::complete::{le_u8, le_u16},
+};
+
+use super::parse_mac;
+use crate::frame::components::{
+    AccessNetworkType, AdvertisementProtocol, AdvertisementProtocolId, AdvertisementProtocolTuple,
+    Hs20Indication, Interworking, RoamingConsortium, VenueInfo,
+};
+
+/// Parse the Interworking element.
+///
+/// - Access Network Options (1 byte)
+/// - Venue Info (2 bytes, optional)
+/// - HESSID (6 bytes, optional)
+///
+/// The presence of the optional fields is determined by the length of the element,
+/// which has to be 1, 3, 7 or 9 bytes.
+pub fn parse_interworking(input: &[u8]) -> IResult<&[u8], Interworking> {
+    let (venue_info, hessid) = match input.len() {
+        1 => (false, false),
+        3 => (true, false),
+        7 => (false, true),
+        9 => (true, true),
+        _ => return fail().parse(input),
+    };
+
+    let (input, (options, venue_info, hessid)) = (
+        le_u8,
+        cond(
+            venue_info,
+            map((le_u8, le_u8), |(group, venue_type)| VenueInfo {
+                group,
+                venue_type,
+            }),
+        ),
+        cond(hessid, parse_mac),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        Interworking {
+            access_network_type: AccessNetworkType::from(options & 0x0f),
+            internet: options & (1 << 4) != 0,
+            asra: options & (1 << 5) != 0,
+            esr: options & (1 << 6) != 0,
+            uesa: options & (1 << 7) != 0,
+            venue_info,
+            hessid,
+        },
+    ))
+}
+
+/// Parse the Advertisement Protocol element.
+///
+/// The element is a list of tuples of
+/// - Query Response Info (1 byte)
+/// - Advertisement Protocol ID (1 byte, or a length prefixed vendor specific ID)
+pub fn parse_advertisement_protocol(input: &[u8]) -> IResult<&[u8], AdvertisementProtocol> {
+    let (input, tuples) = many0(parse_advertisement_protocol_tuple).parse(input)?;
+
+    Ok((input, AdvertisementProtocol { tuples }))
+}
+
+fn parse_advertisement_protocol_tuple(input: &[u8]) -> IResult<&[u8], AdvertisementProtocolTuple> {
+    let (input, (query_response_info, id)) = (le_u8, le_u8).parse(input)?;
+
+    let (input, protocol) = match id {
+        0 => (input, AdvertisementProtocolId::Anqp),
+        1 => (input, AdvertisementProtocolId::MihInformationService),
+        2 => (
+            input,
+            AdvertisementProtocolId::MihCommandAndEventServicesCapabilityDiscovery,
+        ),
+        3 => (input, AdvertisementProtocolId::EmergencyAlertSystem),
+        4 => (
+            input,
+            AdvertisementProtocolId::RegisteredLocationQueryProtocol,
+        ),
+        221 => {
+            let (input, length) = le_u8(input)?;
+            let (input, data) = take(length)(input)?;
+            (
+                input,
+                AdvertisementProtocolId::VendorSpecific(data.to_vec()),
+            )
+        }
+        other => (input, AdvertisementProtocolId::Unknown(other)),
+    };
+
+    Ok((
+        input,
+        AdvertisementProtocolTuple {
+            query_response_length_limit: query_response_info & 0x7f,
+            pame_bi: query_response_info & (1 << 7) != 0,
+            protocol,
+        },
+    ))
+}
+
+/// Parse the Roaming Consortium element.
+///
+/// - Number of ANQP OIs (1 byte)
+/// - OI #1 and #2 Lengths (1 byte)
+/// - OI #1, OI #2 and optionally OI #3, which takes the remaining bytes.
+pub fn parse_roaming_consortium(input: &[u8]) -> IResult<&[u8], RoamingConsortium> {
+    let (input, (anqp_oi_count, lengths)) = (le_u8, le_u8).parse(input)?;
+    let (input, (first, second)) = (take(lengths & 0x0f), take(lengths >> 4)).parse(input)?;
+
+    let ois = [first, second, input]
+        .into_iter()
+        .filter(|oi| !oi.is_empty())
+        .map(|oi| oi.to_vec())
+        .collect();
+
+    Ok((&[], RoamingConsortium { anqp_oi_count, ois }))
+}
+
+/// Parse the payload of the Hotspot 2.0 Indication element, which starts after the OUI type.
+///
+/// - Hotspot Configuration (1 byte)
+/// - PPS MO ID (2 bytes, optional)
+/// - ANQP Domain ID (2 bytes, optional)
+pub fn parse_hs20_indication(input: &[u8]) -> IResult<&[u8], Hs20Indication> {
+    let (input, configuration) = le_u8(input)?;
+    let (input, (pps_mo_id, anqp_domain_id)) = (
+        cond(configuration & (1 << 1) != 0, le_u16),
+        cond(configuration & (1 << 2) != 0, le_u16),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        Hs20Indication {
+            dgaf_disabled: configuration & 1 != 0,
+            reserved: configuration & (1 << 3) != 0,
+            release_number: configuration >> 4,
+            pps_mo_id,
+            anqp_domain_id,
+        },
+    ))
+}
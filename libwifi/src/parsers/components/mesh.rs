@@ -0,0 +1,299 @@
+use nom::{
+    IResult, Parser,
+    bytes::complete::take,
+    combinator::{cond, map},
+    multi::count,
+    number::complete::{le_u8, le_u16, le_u32},
+};
+
+use super::{clone_slice, parse_mac};
+use crate::frame::components::{
+    CongestionControlMode, GateAnnouncement, HwmpFlags, MeshAuthenticationProtocol, MeshCapability,
+    MeshConfiguration, MeshFormationInfo, MeshPeeringManagement, PathError, PathErrorDestination,
+    PathReply, PathRequest, PathRequestTarget, PathSelectionMetric, PathSelectionProtocol,
+    RootAnnouncement, SynchronizationMethod,
+};
+
+/// Parse the Mesh Configuration element.
+///
+/// - Active Path Selection Protocol Identifier (1 byte)
+/// - Active Path Selection Metric Identifier (1 byte)
+/// - Congestion Control Mode Identifier (1 byte)
+/// - Synchronization Method Identifier (1 byte)
+/// - Authentication Protocol Identifier (1 byte)
+/// - Mesh Formation Info (1 byte)
+/// - Mesh Capability (1 byte)
+pub fn parse_mesh_configuration(input: &[u8]) -> IResult<&[u8], MeshConfiguration> {
+    let (
+        input,
+        (protocol, metric, congestion, synchronization, authentication, formation, capability),
+    ) = (le_u8, le_u8, le_u8, le_u8, le_u8, le_u8, le_u8).parse(input)?;
+
+    Ok((
+        input,
+        MeshConfiguration {
+            path_selection_protocol: PathSelectionProtocol::from(protocol),
+            path_selection_metric: PathSelectionMetric::from(metric),
+            congestion_control_mode: CongestionControlMode::from(congestion),
+            synchronization_method: SynchronizationMethod::from(synchronization),
+            authentication_protocol: MeshAuthenticationProtocol::from(authentication),
+            formation_info: MeshFormationInfo::from_bits(formation),
+            capability: MeshCapability::from_bits(capability),
+        },
+    ))
+}
+
+/// Parse the Mesh Peering Management element of Mesh Peering Open and Confirm frames.
+///
+/// - Mesh Peering Protocol Identifier (2 bytes)
+/// - Local Link ID (2 bytes)
+/// - Peer Link ID (2 bytes, optional)
+/// - Reason Code (2 bytes, optional)
+/// - Chosen PMK (16 bytes, only if the AMPE protocol is used)
+pub fn parse_mesh_peering_management(input: &[u8]) -> IResult<&[u8], MeshPeeringManagement> {
+    parse_mesh_peering_management_fields(input, false)
+}
+
+/// Parse the Mesh Peering Management element of Mesh Peering Close frames.
+///
+/// - Mesh Peering Protocol Identifier (2 bytes)
+/// - Local Link ID (2 bytes)
+/// - Peer Link ID (2 bytes, optional)
+/// - Reason Code (2 bytes)
+/// - Chosen PMK (16 bytes, only if the AMPE protocol is used)
+pub fn parse_mesh_peering_close_management(input: &[u8]) -> IResult<&[u8], MeshPeeringManagement> {
+    parse_mesh_peering_management_fields(input, true)
+}
+
+/// A single trailing field is the peer link ID, unless it's the mandatory reason code of a
+/// Mesh Peering Close frame.
+fn parse_mesh_peering_management_fields(
+    input: &[u8],
+    close: bool,
+) -> IResult<&[u8], MeshPeeringManagement> {
+    let (input, (protocol, local_link_id)) = (le_u16, le_u16).parse(input)?;
+
+    let pmk_length = if protocol == 1 && input.len() >= 16 {
+        16
+    } else {
+        0
+    };
+    let optional_fields = input.len() - pmk_length;
+
+    let (input, (peer_link_id, reason_code, chosen_pmk)) = (
+        cond(optional_fields >= if close { 4 } else { 2 }, le_u16),
+        cond(optional_fields >= if close { 2 } else { 4 }, le_u16),
+        cond(pmk_length == 16, take(16usize)),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        MeshPeeringManagement {
+            protocol,
+            local_link_id,
+            peer_link_id,
+            reason_code,
+            chosen_pmk: chosen_pmk.map(clone_slice::<16>),
+        },
+    ))
+}
+
+/// Parse the Mesh Awake Window element, which contains the awake window in TUs.
+pub fn parse_mesh_awake_window(input: &[u8]) -> IResult<&[u8], u16> {
+    le_u16(input)
+}
+
+/// Parse the Gate Announcement element.
+///
+/// - Flags (1 byte)
+/// - Hop Count (1 byte)
+/// - Element TTL (1 byte)
+/// - Mesh Gate Address (6 bytes)
+/// - GANN Sequence Number (4 bytes)
+/// - Interval (2 bytes)
+pub fn parse_gate_announcement(input: &[u8]) -> IResult<&[u8], GateAnnouncement> {
+    let (input, (flags, hop_count, element_ttl, mesh_gate, sequence_number, interval)) =
+        (le_u8, le_u8, le_u8, parse_mac, le_u32, le_u16).parse(input)?;
+
+    Ok((
+        input,
+        GateAnnouncement {
+            flags: HwmpFlags::from_bits(flags),
+            hop_count,
+            element_ttl,
+            mesh_gate,
+            sequence_number,
+            interval,
+        },
+    ))
+}
+
+/// Parse the Root Announcement element.
+///
+/// - Flags (1 byte)
+/// - Hop Count (1 byte)
+/// - Element TTL (1 byte)
+/// - Root Mesh STA Address (6 bytes)
+/// - HWMP Sequence Number (4 bytes)
+/// - Interval (4 bytes)
+/// - Metric (4 bytes)
+pub fn parse_root_announcement(input: &[u8]) -> IResult<&[u8], RootAnnouncement> {
+    let (input, (flags, hop_count, element_ttl, root, sequence_number, interval, metric)) =
+        (le_u8, le_u8, le_u8, parse_mac, le_u32, le_u32, le_u32).parse(input)?;
+
+    Ok((
+        input,
+        RootAnnouncement {
+            flags: HwmpFlags::from_bits(flags),
+            hop_count,
+            element_ttl,
+            root,
+            sequence_number,
+            interval,
+            metric,
+        },
+    ))
+}
+
+/// Parse the Path Request element.
+///
+/// - Flags (1 byte)
+/// - Hop Count (1 byte)
+/// - Element TTL (1 byte)
+/// - Path Discovery ID (4 bytes)
+/// - Originator Mesh STA Address (6 bytes)
+/// - Originator HWMP Sequence Number (4 bytes)
+/// - Originator External Address (6 bytes, only if the AE flag is set)
+/// - Lifetime (4 bytes)
+/// - Metric (4 bytes)
+/// - Target Count (1 byte)
+/// - Targets, each consisting of flags (1 byte), address (6 bytes) and HWMP sequence number
+///   (4 bytes)
+pub fn parse_path_request(input: &[u8]) -> IResult<&[u8], PathRequest> {
+    let (input, (flags, hop_count, element_ttl, path_discovery_id, originator, sequence_number)) =
+        (le_u8, le_u8, le_u8, le_u32, parse_mac, le_u32).parse(input)?;
+    let flags = HwmpFlags::from_bits(flags);
+
+    let (input, (external_address, lifetime, metric, targets)) = (
+        cond(flags.address_extension, parse_mac),
+        le_u32,
+        le_u32,
+        le_u8.flat_map(|number| {
+            count(
+                map(
+                    (le_u8, parse_mac, le_u32),
+                    |(bits, address, sequence_number)| {
+                        PathRequestTarget::from_bits(bits, address, sequence_number)
+                    },
+                ),
+                number as usize,
+            )
+        }),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        PathRequest {
+            flags,
+            hop_count,
+            element_ttl,
+            path_discovery_id,
+            originator,
+            originator_sequence_number: sequence_number,
+            originator_external_address: external_address,
+            lifetime,
+            metric,
+            targets,
+        },
+    ))
+}
+
+/// Parse the Path Reply element.
+///
+/// - Flags (1 byte)
+/// - Hop Count (1 byte)
+/// - Element TTL (1 byte)
+/// - Target Mesh STA Address (6 bytes)
+/// - Target HWMP Sequence Number (4 bytes)
+/// - Target External Address (6 bytes, only if the AE flag is set)
+/// - Lifetime (4 bytes)
+/// - Metric (4 bytes)
+/// - Originator Mesh STA Address (6 bytes)
+/// - Originator HWMP Sequence Number (4 bytes)
+pub fn parse_path_reply(input: &[u8]) -> IResult<&[u8], PathReply> {
+    let (input, (flags, hop_count, element_ttl, target, target_sequence_number)) =
+        (le_u8, le_u8, le_u8, parse_mac, le_u32).parse(input)?;
+    let flags = HwmpFlags::from_bits(flags);
+
+    let (input, (external_address, lifetime, metric, originator, originator_sequence_number)) = (
+        cond(flags.address_extension, parse_mac),
+        le_u32,
+        le_u32,
+        parse_mac,
+        le_u32,
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        PathReply {
+            flags,
+            hop_count,
+            element_ttl,
+            target,
+            target_sequence_number,
+            target_external_address: external_address,
+            lifetime,
+            metric,
+            originator,
+            originator_sequence_number,
+        },
+    ))
+}
+
+/// Parse the Path Error element.
+///
+/// - Element TTL (1 byte)
+/// - Number of Destinations (1 byte)
+/// - Destinations, each consisting of
+///   - Flags (1 byte)
+///   - Destination Address (6 bytes)
+///   - HWMP Sequence Number (4 bytes)
+///   - Destination External Address (6 bytes, only if the AE flag is set)
+///   - Reason Code (2 bytes)
+pub fn parse_path_error(input: &[u8]) -> IResult<&[u8], PathError> {
+    let (input, (element_ttl, destinations)) = (
+        le_u8,
+        le_u8.flat_map(|number| count(parse_path_error_destination, number as usize)),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        PathError {
+            element_ttl,
+            destinations,
+        },
+    ))
+}
+
+fn parse_path_error_destination(input: &[u8]) -> IResult<&[u8], PathErrorDestination> {
+    let (input, (flags, address, sequence_number)) = (le_u8, parse_mac, le_u32).parse(input)?;
+    let flags = HwmpFlags::from_bits(flags);
+
+    let (input, (external_address, reason_code)) =
+        (cond(flags.address_extension, parse_mac), le_u16).parse(input)?;
+
+    Ok((
+        input,
+        PathErrorDestination {
+            flags,
+            address,
+            sequence_number,
+            external_address,
+            reason_code,
+        },
+    ))
+}
@@ -2,15 +2,72 @@ use nom::{IResult, bytes::complete::take};
 
 use crate::frame::components::MacAddress;
 
+mod anqp;
+mod bss_load;
+mod channel_switch;
+mod eht;
+mod fast_transition;
 mod frame_control;
+mod ftm;
 mod header;
+mod information_element;
+mod interworking;
+mod mesh;
+mod multiple_bssid;
+mod p2p;
+mod qos;
+mod radio_measurement;
+mod reduced_neighbor_report;
+mod registry;
 mod sequence_control;
+mod spectrum_management;
 mod station_info;
+mod tdls;
+mod vendor;
+mod wnm;
 
+pub use anqp::parse_anqp_elements;
+pub use bss_load::{parse_bss_load, parse_extended_bss_load};
+pub use channel_switch::{
+    parse_channel_switch_wrapper, parse_extended_channel_switch, parse_max_channel_switch_time,
+    parse_wide_bandwidth_channel_switch,
+};
+pub use eht::{parse_eht_capabilities, parse_eht_operation, parse_multi_link};
+pub use fast_transition::{parse_fast_transition, parse_mobility_domain, parse_timeout_interval};
 pub use frame_control::parse_frame_control;
+pub use ftm::{parse_ftm_parameters, parse_ftm_synchronization_information};
 pub use header::*;
+pub use information_element::{IeIter, decode_ie, decode_ie_with};
+pub use interworking::{
+    parse_advertisement_protocol, parse_hs20_indication, parse_interworking,
+    parse_roaming_consortium,
+};
+pub use mesh::{
+    parse_gate_announcement, parse_mesh_awake_window, parse_mesh_configuration,
+    parse_mesh_peering_close_management, parse_mesh_peering_management, parse_path_error,
+    parse_path_reply, parse_path_request, parse_root_announcement,
+};
+pub use multiple_bssid::{parse_multiple_bssid, parse_multiple_bssid_index, parse_non_inheritance};
+pub use p2p::parse_p2p_attributes;
+pub use qos::{parse_edca_parameter_set, parse_mu_edca_parameter_set, parse_wmm};
+pub use radio_measurement::{
+    parse_measurement_report, parse_measurement_request, parse_neighbor_report, parse_subelements,
+};
+pub use reduced_neighbor_report::parse_reduced_neighbor_report;
+pub use registry::{CustomElementParser, ElementRegistry, VendorDecoder};
 pub use sequence_control::parse_sequence_control;
-pub use station_info::{parse_rsn_information, parse_station_info};
+pub use spectrum_management::{
+    parse_power_capability, parse_quiet, parse_supported_channels, parse_tpc_report,
+};
+pub(crate) use station_info::parse_element_list;
+pub use station_info::{
+    parse_rsn_extension, parse_rsn_information, parse_station_info, parse_station_info_with,
+};
+pub use tdls::parse_link_identifier;
+pub use wnm::{
+    parse_bss_termination_duration, parse_tfs_request_element, parse_tfs_response_element,
+    parse_wnm_sleep_mode,
+};
 
 /// Parse mac addresses.
 /// Just take 6 bytes, clone them and create a new MacAddress struct from those bytes.
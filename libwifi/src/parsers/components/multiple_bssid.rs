@@ -0,0 +1,76 @@
+use nom::{IResult, Parser, bytes::complete::take, multi::length_data, number::complete::le_u8};
+
+use super::ElementRegistry;
+use super::station_info::parse_element_list;
+use crate::frame::components::{MultipleBSSID, MultipleBssidIndex, NonInheritance};
+
+/// Parse the Multiple BSSID element.
+///
+/// The general structure is:
+/// - MaxBSSID Indicator
+/// - Subelements, usually Nontransmitted BSSID Profiles (subelement id 0), which contain a
+///   list of elements.
+pub fn parse_multiple_bssid(input: &[u8]) -> IResult<&[u8], MultipleBSSID> {
+    parse_multiple_bssid_with(input, ElementRegistry::builtin())
+}
+
+pub(crate) fn parse_multiple_bssid_with<'a>(
+    input: &'a [u8],
+    registry: &ElementRegistry,
+) -> IResult<&'a [u8], MultipleBSSID> {
+    let (mut input, max_bssid_indicator) = le_u8(input)?;
+
+    let mut profiles = Vec::new();
+    let mut other_data = Vec::new();
+    while !input.is_empty() {
+        let (subelement_id, length, data);
+        (input, (subelement_id, length)) = (le_u8, le_u8).parse(input)?;
+        (input, data) = take(length)(input)?;
+
+        if subelement_id == 0 {
+            let (_, profile) = parse_element_list(data, registry)?;
+            profiles.push(profile);
+        } else {
+            other_data.push(subelement_id);
+            other_data.push(length);
+            other_data.extend_from_slice(data);
+        }
+    }
+
+    Ok((
+        input,
+        MultipleBSSID {
+            max_bssid_indicator,
+            profiles,
+            other_data,
+        },
+    ))
+}
+
+/// Parse the Multiple BSSID-Index element.
+///
+/// The DTIM fields are only present in beacons.
+pub fn parse_multiple_bssid_index(data: &[u8]) -> Option<MultipleBssidIndex> {
+    Some(MultipleBssidIndex {
+        bssid_index: *data.first()?,
+        dtim_period: data.get(1).copied(),
+        dtim_count: data.get(2).copied(),
+    })
+}
+
+/// Parse the Non-Inheritance element.
+///
+/// `input` is the element payload without the extension element id.
+/// It consists of two length-prefixed lists, one for element ids and one for extension ids.
+pub fn parse_non_inheritance(input: &[u8]) -> IResult<&[u8], NonInheritance> {
+    let (input, (element_ids, extension_element_ids)) =
+        (length_data(le_u8), length_data(le_u8)).parse(input)?;
+
+    Ok((
+        input,
+        NonInheritance {
+            element_ids: element_ids.to_vec(),
+            extension_element_ids: extension_element_ids.to_vec(),
+        },
+    ))
+}
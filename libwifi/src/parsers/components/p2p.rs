@@ -0,0 +1,239 @@
+use nom::{
+    IResult, Parser,
+    bytes::complete::{tag, take},
+    combinator::{all_consuming, map, map_res},
+    multi::{count, many0},
+    number::complete::{be_u16, le_u8, le_u16, le_u32},
+};
+
+use super::{clone_slice, parse_mac};
+use crate::frame::components::{
+    GroupOwnerIntent, NoticeOfAbsence, NoticeOfAbsenceDescriptor, P2pAttribute, P2pCapability,
+    P2pChannel, P2pChannelEntry, P2pChannelList, P2pClientInfo, P2pDeviceCapability, P2pDeviceInfo,
+    P2pGroupCapability,
+};
+
+/// Parse a list of P2P attributes.
+///
+/// Each attribute consists of
+/// - Attribute ID (1 byte)
+/// - Length (2 bytes)
+/// - Attribute Body (variable)
+///
+/// Attributes may be split across multiple P2P elements, whose payloads have to be concatenated
+/// before parsing, see [StationInfo::p2p_attributes](crate::frame::components::StationInfo::p2p_attributes).
+pub fn parse_p2p_attributes(input: &[u8]) -> IResult<&[u8], Vec<P2pAttribute>> {
+    many0(parse_p2p_attribute).parse(input)
+}
+
+fn parse_p2p_attribute(input: &[u8]) -> IResult<&[u8], P2pAttribute> {
+    let (input, (id, data)) = (le_u8, le_u16.flat_map(take)).parse(input)?;
+
+    let attribute = match (id, data) {
+        (0, [status]) => Ok((&[][..], P2pAttribute::Status(*status))),
+        (2, [device, group]) => Ok((
+            &[][..],
+            P2pAttribute::Capability(P2pCapability {
+                device_capability: P2pDeviceCapability::from_bits(*device),
+                group_capability: P2pGroupCapability::from_bits(*group),
+            }),
+        )),
+        (3, _) => all_consuming(map(parse_mac, P2pAttribute::DeviceId)).parse(data),
+        (4, [bits]) => Ok((
+            &[][..],
+            P2pAttribute::GroupOwnerIntent(GroupOwnerIntent::from_bits(*bits)),
+        )),
+        (6, _) => all_consuming(map(parse_p2p_channel, P2pAttribute::ListenChannel)).parse(data),
+        (11, _) => {
+            all_consuming(map(parse_p2p_channel_list, P2pAttribute::ChannelList)).parse(data)
+        }
+        (12, _) => {
+            all_consuming(map(parse_notice_of_absence, P2pAttribute::NoticeOfAbsence)).parse(data)
+        }
+        (13, _) => all_consuming(map(parse_p2p_device_info, P2pAttribute::DeviceInfo)).parse(data),
+        (14, _) => all_consuming(map(
+            many0(map_res(le_u8.flat_map(take), |data| {
+                all_consuming(parse_p2p_client_info)
+                    .parse(data)
+                    .map(|(_, client)| client)
+            })),
+            P2pAttribute::GroupInfo,
+        ))
+        .parse(data),
+        (17, _) => {
+            all_consuming(map(parse_p2p_channel, P2pAttribute::OperatingChannel)).parse(data)
+        }
+        _ => Err(nom::Err::Error(nom::error::Error::new(
+            data,
+            nom::error::ErrorKind::Switch,
+        ))),
+    };
+
+    // Keep attributes that can't be decoded losslessly as raw data.
+    let attribute = attribute.map_or_else(
+        |_| P2pAttribute::Unknown {
+            id,
+            data: data.to_vec(),
+        },
+        |(_, attribute)| attribute,
+    );
+
+    Ok((input, attribute))
+}
+
+/// - Country String (3 bytes)
+/// - Operating Class (1 byte)
+/// - Channel Number (1 byte)
+fn parse_p2p_channel(input: &[u8]) -> IResult<&[u8], P2pChannel> {
+    let (input, (country, operating_class, channel)) = (take(3usize), le_u8, le_u8).parse(input)?;
+
+    Ok((
+        input,
+        P2pChannel {
+            country: clone_slice::<3>(country),
+            operating_class,
+            channel,
+        },
+    ))
+}
+
+/// - Country String (3 bytes)
+/// - Channel Entries, each consisting of an operating class, the number of channels and the
+///   channels
+fn parse_p2p_channel_list(input: &[u8]) -> IResult<&[u8], P2pChannelList> {
+    let (input, (country, entries)) = (
+        take(3usize),
+        many0(map(
+            (le_u8, le_u8.flat_map(take)),
+            |(operating_class, channels): (u8, &[u8])| P2pChannelEntry {
+                operating_class,
+                channels: channels.to_vec(),
+            },
+        )),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        P2pChannelList {
+            country: clone_slice::<3>(country),
+            entries,
+        },
+    ))
+}
+
+/// - Index (1 byte)
+/// - CTWindow and OppPS Parameters (1 byte)
+/// - Notice of Absence Descriptors (13 bytes each)
+fn parse_notice_of_absence(input: &[u8]) -> IResult<&[u8], NoticeOfAbsence> {
+    let (input, (index, parameters, descriptors)) = (
+        le_u8,
+        le_u8,
+        many0(map(
+            (le_u8, le_u32, le_u32, le_u32),
+            |(count, duration, interval, start_time)| NoticeOfAbsenceDescriptor {
+                count,
+                duration,
+                interval,
+                start_time,
+            },
+        )),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        NoticeOfAbsence {
+            index,
+            ct_window: parameters & 0x7f,
+            opp_ps: parameters & 0x80 != 0,
+            descriptors,
+        },
+    ))
+}
+
+/// Config methods, primary and secondary device types and device name
+type DeviceDescription = (u16, [u8; 8], Vec<[u8; 8]>, String);
+
+/// The fields that are shared by the Device Info attribute and client info descriptors.
+///
+/// - Config Methods (2 bytes, big endian)
+/// - Primary Device Type (8 bytes)
+/// - Number of Secondary Device Types (1 byte)
+/// - Secondary Device Types (8 bytes each)
+/// - Device Name (WPS attribute with a 2 byte big endian type and length)
+fn parse_device_description(input: &[u8]) -> IResult<&[u8], DeviceDescription> {
+    let device_type = |input| map(take(8usize), clone_slice::<8>).parse(input);
+    let (input, (config_methods, primary_device_type, secondary_device_types, _, device_name)) = (
+        be_u16,
+        device_type,
+        le_u8.flat_map(|number| count(device_type, number as usize)),
+        tag(&[0x10, 0x11][..]),
+        map_res(be_u16.flat_map(take), |name: &[u8]| {
+            String::from_utf8(name.to_vec())
+        }),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        (
+            config_methods,
+            primary_device_type,
+            secondary_device_types,
+            device_name,
+        ),
+    ))
+}
+
+/// - P2P Device Address (6 bytes)
+/// - Device description (variable)
+fn parse_p2p_device_info(input: &[u8]) -> IResult<&[u8], P2pDeviceInfo> {
+    let (
+        input,
+        (
+            device_address,
+            (config_methods, primary_device_type, secondary_device_types, device_name),
+        ),
+    ) = (parse_mac, parse_device_description).parse(input)?;
+
+    Ok((
+        input,
+        P2pDeviceInfo {
+            device_address,
+            config_methods,
+            primary_device_type,
+            secondary_device_types,
+            device_name,
+        },
+    ))
+}
+
+/// - P2P Device Address (6 bytes)
+/// - P2P Interface Address (6 bytes)
+/// - Device Capability Bitmap (1 byte)
+/// - Device description (variable)
+fn parse_p2p_client_info(input: &[u8]) -> IResult<&[u8], P2pClientInfo> {
+    let (
+        input,
+        (
+            device_address,
+            interface_address,
+            device_capability,
+            (config_methods, primary_device_type, secondary_device_types, device_name),
+        ),
+    ) = (parse_mac, parse_mac, le_u8, parse_device_description).parse(input)?;
+
+    Ok((
+        input,
+        P2pClientInfo {
+            device_address,
+            interface_address,
+            device_capability: P2pDeviceCapability::from_bits(device_capability),
+            config_methods,
+            primary_device_type,
+            secondary_device_types,
+            device_name,
+        },
+    ))
+}
@@ -0,0 +1,88 @@
+use nom::{
+    IResult, Parser,
+    combinator::{fail, map},
+    multi::many0,
+    number::complete::{le_u8, le_u16},
+};
+
+use crate::frame::components::{
+    AcParameters, EdcaParameterSet, MuAcParameters, MuEdcaParameterSet, QosInfo, Wmm,
+};
+
+/// Parse the EDCA Parameter Set element.
+///
+/// - QoS Info
+/// - Update EDCA Info
+/// - AC parameter records (4 bytes each)
+pub fn parse_edca_parameter_set(input: &[u8]) -> IResult<&[u8], EdcaParameterSet> {
+    let (input, (qos_info, update_edca_info, ac_parameters)) = (
+        map(le_u8, QosInfo::from_bits),
+        le_u8,
+        many0(map((le_u8, le_u8, le_u16), |(aci_aifsn, ecw, txop)| {
+            AcParameters::from_bytes(aci_aifsn, ecw, txop)
+        })),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        EdcaParameterSet {
+            qos_info,
+            update_edca_info,
+            ac_parameters,
+        },
+    ))
+}
+
+/// Parse the MU EDCA Parameter Set element.
+///
+/// - QoS Info
+/// - AC parameter records (3 bytes each, the last one being the MU EDCA Timer)
+pub fn parse_mu_edca_parameter_set(input: &[u8]) -> IResult<&[u8], MuEdcaParameterSet> {
+    let (input, (qos_info, ac_parameters)) = (
+        map(le_u8, QosInfo::from_bits),
+        many0(map((le_u8, le_u8, le_u8), |(aci_aifsn, ecw, timer)| {
+            MuAcParameters::from_bytes(aci_aifsn, ecw, timer)
+        })),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        MuEdcaParameterSet {
+            qos_info,
+            ac_parameters,
+        },
+    ))
+}
+
+/// Parse the payload of a WMM vendor specific element, which starts after the OUI type.
+///
+/// Only the WMM Information and WMM Parameter subtypes are supported.
+pub fn parse_wmm(input: &[u8]) -> IResult<&[u8], Wmm> {
+    let (input, (subtype, version)) = (le_u8, le_u8).parse(input)?;
+
+    match subtype {
+        0 => {
+            let (input, qos_info) = le_u8(input)?;
+            Ok((
+                input,
+                Wmm::Information {
+                    version,
+                    qos_info: QosInfo::from_bits(qos_info),
+                },
+            ))
+        }
+        1 => {
+            let (input, parameters) = parse_edca_parameter_set(input)?;
+            Ok((
+                input,
+                Wmm::Parameter {
+                    version,
+                    parameters,
+                },
+            ))
+        }
+        _ => fail().parse(input),
+    }
+}
@@ -0,0 +1,408 @@
+use nom::{
+    IResult, Parser,
+    bytes::complete::take,
+    combinator::{map, rest},
+    multi::many0,
+    number::complete::{le_u8, le_u16, le_u32, le_u64},
+};
+
+use super::{clone_slice, parse_mac};
+use crate::frame::components::{
+    BeaconMeasurementMode, BeaconReport, BeaconRequest, BssidInformation, ChannelLoadReport,
+    ChannelMeasurementRequest, LciReport, LciRequest, LocationCivicReport, LocationCivicRequest,
+    MeasurementReport, MeasurementReportMode, MeasurementReportType, MeasurementRequest,
+    MeasurementRequestMode, MeasurementRequestType, NeighborReport, NoiseHistogramReport,
+    StaStatisticsReport, StaStatisticsRequest, Subelement,
+};
+
+/// Parse a list of subelements with a 1 byte ID and a 1 byte length, until the input is
+/// exhausted or a subelement is truncated.
+pub fn parse_subelements(input: &[u8]) -> IResult<&[u8], Vec<Subelement>> {
+    many0(map(
+        (le_u8, le_u8.flat_map(take)),
+        |(id, data): (u8, &[u8])| Subelement {
+            id,
+            data: data.to_vec(),
+        },
+    ))
+    .parse(input)
+}
+
+/// Parse the Measurement Request element.
+///
+/// - Measurement Token (1 byte)
+/// - Measurement Request Mode (1 byte)
+/// - Measurement Type (1 byte)
+/// - Measurement Request (variable)
+pub fn parse_measurement_request(input: &[u8]) -> IResult<&[u8], MeasurementRequest> {
+    let (input, (token, mode, measurement_type)) = (le_u8, le_u8, le_u8).parse(input)?;
+
+    let (input, request) = match measurement_type {
+        3 => map(
+            parse_channel_measurement_request,
+            MeasurementRequestType::ChannelLoad,
+        )
+        .parse(input)?,
+        4 => map(
+            parse_channel_measurement_request,
+            MeasurementRequestType::NoiseHistogram,
+        )
+        .parse(input)?,
+        5 => map(parse_beacon_request, MeasurementRequestType::Beacon).parse(input)?,
+        7 => map(
+            parse_sta_statistics_request,
+            MeasurementRequestType::StaStatistics,
+        )
+        .parse(input)?,
+        8 => map(parse_lci_request, MeasurementRequestType::Lci).parse(input)?,
+        11 => map(
+            parse_location_civic_request,
+            MeasurementRequestType::LocationCivic,
+        )
+        .parse(input)?,
+        _ => map(rest, |data: &[u8]| MeasurementRequestType::Unknown {
+            measurement_type,
+            data: data.to_vec(),
+        })
+        .parse(input)?,
+    };
+
+    Ok((
+        input,
+        MeasurementRequest {
+            token,
+            mode: MeasurementRequestMode::from_bits(mode),
+            request,
+        },
+    ))
+}
+
+/// - Operating Class (1 byte)
+/// - Channel Number (1 byte)
+/// - Randomization Interval (2 bytes)
+/// - Measurement Duration (2 bytes)
+/// - Optional Subelements (variable)
+fn parse_channel_measurement_request(input: &[u8]) -> IResult<&[u8], ChannelMeasurementRequest> {
+    let (input, (operating_class, channel, randomization_interval, measurement_duration)) =
+        (le_u8, le_u8, le_u16, le_u16).parse(input)?;
+    let (input, subelements) = parse_subelements(input)?;
+
+    Ok((
+        input,
+        ChannelMeasurementRequest {
+            operating_class,
+            channel,
+            randomization_interval,
+            measurement_duration,
+            subelements,
+        },
+    ))
+}
+
+/// - Operating Class (1 byte)
+/// - Channel Number (1 byte)
+/// - Randomization Interval (2 bytes)
+/// - Measurement Duration (2 bytes)
+/// - Measurement Mode (1 byte)
+/// - BSSID (6 bytes)
+/// - Optional Subelements (variable)
+fn parse_beacon_request(input: &[u8]) -> IResult<&[u8], BeaconRequest> {
+    let (
+        input,
+        (operating_class, channel, randomization_interval, measurement_duration, mode, bssid),
+    ) = (le_u8, le_u8, le_u16, le_u16, le_u8, parse_mac).parse(input)?;
+    let (input, subelements) = parse_subelements(input)?;
+
+    Ok((
+        input,
+        BeaconRequest {
+            operating_class,
+            channel,
+            randomization_interval,
+            measurement_duration,
+            measurement_mode: BeaconMeasurementMode::from(mode),
+            bssid,
+            subelements,
+        },
+    ))
+}
+
+/// - Peer MAC Address (6 bytes)
+/// - Randomization Interval (2 bytes)
+/// - Measurement Duration (2 bytes)
+/// - Group Identity (1 byte)
+/// - Optional Subelements (variable)
+fn parse_sta_statistics_request(input: &[u8]) -> IResult<&[u8], StaStatisticsRequest> {
+    let (input, (peer_mac_address, randomization_interval, measurement_duration, group_identity)) =
+        (parse_mac, le_u16, le_u16, le_u8).parse(input)?;
+    let (input, subelements) = parse_subelements(input)?;
+
+    Ok((
+        input,
+        StaStatisticsRequest {
+            peer_mac_address,
+            randomization_interval,
+            measurement_duration,
+            group_identity,
+            subelements,
+        },
+    ))
+}
+
+/// - Location Subject (1 byte)
+/// - Optional Subelements (variable)
+fn parse_lci_request(input: &[u8]) -> IResult<&[u8], LciRequest> {
+    let (input, (location_subject, subelements)) = (le_u8, parse_subelements).parse(input)?;
+
+    Ok((
+        input,
+        LciRequest {
+            location_subject,
+            subelements,
+        },
+    ))
+}
+
+/// - Location Subject (1 byte)
+/// - Civic Location Type (1 byte)
+/// - Location Service Interval Units (1 byte)
+/// - Location Service Interval (2 bytes)
+/// - Optional Subelements (variable)
+fn parse_location_civic_request(input: &[u8]) -> IResult<&[u8], LocationCivicRequest> {
+    let (
+        input,
+        (
+            location_subject,
+            civic_location_type,
+            location_service_interval_units,
+            location_service_interval,
+            subelements,
+        ),
+    ) = (le_u8, le_u8, le_u8, le_u16, parse_subelements).parse(input)?;
+
+    Ok((
+        input,
+        LocationCivicRequest {
+            location_subject,
+            civic_location_type,
+            location_service_interval_units,
+            location_service_interval,
+            subelements,
+        },
+    ))
+}
+
+/// Parse the Measurement Report element.
+///
+/// - Measurement Token (1 byte)
+/// - Measurement Report Mode (1 byte)
+/// - Measurement Type (1 byte)
+/// - Measurement Report (variable, empty if the measurement is late, incapable or refused)
+pub fn parse_measurement_report(input: &[u8]) -> IResult<&[u8], MeasurementReport> {
+    let (input, (token, mode, measurement_type)) = (le_u8, le_u8, le_u8).parse(input)?;
+
+    let (input, report) = match measurement_type {
+        3 if !input.is_empty() => map(
+            parse_channel_load_report,
+            MeasurementReportType::ChannelLoad,
+        )
+        .parse(input)?,
+        4 if !input.is_empty() => map(
+            parse_noise_histogram_report,
+            MeasurementReportType::NoiseHistogram,
+        )
+        .parse(input)?,
+        5 if !input.is_empty() => {
+            map(parse_beacon_report, MeasurementReportType::Beacon).parse(input)?
+        }
+        7 if !input.is_empty() => map(
+            parse_sta_statistics_report,
+            MeasurementReportType::StaStatistics,
+        )
+        .parse(input)?,
+        8 if !input.is_empty() => map(parse_subelements, |subelements| {
+            MeasurementReportType::Lci(LciReport { subelements })
+        })
+        .parse(input)?,
+        11 if !input.is_empty() => map(
+            (le_u8, parse_subelements),
+            |(civic_location_type, subelements)| {
+                MeasurementReportType::LocationCivic(LocationCivicReport {
+                    civic_location_type,
+                    subelements,
+                })
+            },
+        )
+        .parse(input)?,
+        _ => map(rest, |data: &[u8]| MeasurementReportType::Unknown {
+            measurement_type,
+            data: data.to_vec(),
+        })
+        .parse(input)?,
+    };
+
+    Ok((
+        input,
+        MeasurementReport {
+            token,
+            mode: MeasurementReportMode::from_bits(mode),
+            report,
+        },
+    ))
+}
+
+/// - Operating Class (1 byte)
+/// - Channel Number (1 byte)
+/// - Actual Measurement Start Time (8 bytes)
+/// - Measurement Duration (2 bytes)
+/// - Channel Load (1 byte)
+/// - Optional Subelements (variable)
+fn parse_channel_load_report(input: &[u8]) -> IResult<&[u8], ChannelLoadReport> {
+    let (
+        input,
+        (
+            operating_class,
+            channel,
+            actual_measurement_start_time,
+            measurement_duration,
+            channel_load,
+        ),
+    ) = (le_u8, le_u8, le_u64, le_u16, le_u8).parse(input)?;
+    let (input, subelements) = parse_subelements(input)?;
+
+    Ok((
+        input,
+        ChannelLoadReport {
+            operating_class,
+            channel,
+            actual_measurement_start_time,
+            measurement_duration,
+            channel_load,
+            subelements,
+        },
+    ))
+}
+
+/// - Operating Class (1 byte)
+/// - Channel Number (1 byte)
+/// - Actual Measurement Start Time (8 bytes)
+/// - Measurement Duration (2 bytes)
+/// - Antenna ID (1 byte)
+/// - ANPI (1 byte)
+/// - IPI 0-10 Density (11 bytes)
+/// - Optional Subelements (variable)
+fn parse_noise_histogram_report(input: &[u8]) -> IResult<&[u8], NoiseHistogramReport> {
+    let (
+        input,
+        (
+            operating_class,
+            channel,
+            actual_measurement_start_time,
+            measurement_duration,
+            antenna_id,
+            anpi,
+            ipi_densities,
+        ),
+    ) = (le_u8, le_u8, le_u64, le_u16, le_u8, le_u8, take(11usize)).parse(input)?;
+    let (input, subelements) = parse_subelements(input)?;
+
+    Ok((
+        input,
+        NoiseHistogramReport {
+            operating_class,
+            channel,
+            actual_measurement_start_time,
+            measurement_duration,
+            antenna_id,
+            anpi,
+            ipi_densities: clone_slice::<11>(ipi_densities),
+            subelements,
+        },
+    ))
+}
+
+/// - Operating Class (1 byte)
+/// - Channel Number (1 byte)
+/// - Actual Measurement Start Time (8 bytes)
+/// - Measurement Duration (2 bytes)
+/// - Reported Frame Information (1 byte)
+/// - RCPI (1 byte)
+/// - RSNI (1 byte)
+/// - BSSID (6 bytes)
+/// - Antenna ID (1 byte)
+/// - Parent TSF (4 bytes)
+/// - Optional Subelements (variable)
+fn parse_beacon_report(input: &[u8]) -> IResult<&[u8], BeaconReport> {
+    let (
+        input,
+        (
+            (operating_class, channel, actual_measurement_start_time, measurement_duration),
+            (reported_frame_information, rcpi, rsni, bssid, antenna_id, parent_tsf),
+        ),
+    ) = (
+        (le_u8, le_u8, le_u64, le_u16),
+        (le_u8, le_u8, le_u8, parse_mac, le_u8, le_u32),
+    )
+        .parse(input)?;
+    let (input, subelements) = parse_subelements(input)?;
+
+    Ok((
+        input,
+        BeaconReport {
+            operating_class,
+            channel,
+            actual_measurement_start_time,
+            measurement_duration,
+            reported_frame_information,
+            rcpi,
+            rsni,
+            bssid,
+            antenna_id,
+            parent_tsf,
+            subelements,
+        },
+    ))
+}
+
+/// - Measurement Duration (2 bytes)
+/// - Group Identity (1 byte)
+/// - Statistics Group Data and optional Subelements (variable)
+fn parse_sta_statistics_report(input: &[u8]) -> IResult<&[u8], StaStatisticsReport> {
+    let (input, (measurement_duration, group_identity, statistics)) =
+        (le_u16, le_u8, rest).parse(input)?;
+
+    Ok((
+        input,
+        StaStatisticsReport {
+            measurement_duration,
+            group_identity,
+            statistics: statistics.to_vec(),
+        },
+    ))
+}
+
+/// Parse the Neighbor Report element.
+///
+/// - BSSID (6 bytes)
+/// - BSSID Information (4 bytes)
+/// - Operating Class (1 byte)
+/// - Channel Number (1 byte)
+/// - PHY Type (1 byte)
+/// - Optional Subelements (variable)
+pub fn parse_neighbor_report(input: &[u8]) -> IResult<&[u8], NeighborReport> {
+    let (input, (bssid, bssid_information, operating_class, channel, phy_type)) =
+        (parse_mac, le_u32, le_u8, le_u8, le_u8).parse(input)?;
+    let (input, subelements) = parse_subelements(input)?;
+
+    Ok((
+        input,
+        NeighborReport {
+            bssid,
+            bssid_information: BssidInformation::from_bits(bssid_information),
+            operating_class,
+            channel,
+            phy_type,
+            subelements,
+        },
+    ))
+}
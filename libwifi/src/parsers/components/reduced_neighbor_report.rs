@@ -0,0 +1,114 @@
+use nom::{
+    IResult, Parser,
+    bytes::complete::take,
+    combinator::cond,
+    number::complete::{le_u8, le_u16, le_u32},
+};
+
+use super::{clone_slice, parse_mac};
+use crate::frame::components::{
+    NeighborApInformation, ReducedNeighborReport, RnrBssParameters, RnrMldParameters,
+    TbttInformation,
+};
+
+/// Parse the Reduced Neighbor Report element.
+///
+/// The element consists of one or more Neighbor AP Information fields:
+/// - TBTT Information Header (field type, filtered flag, count, length)
+/// - Operating class
+/// - Channel number
+/// - TBTT Information Set (count * TBTT Information fields)
+///
+/// Parsing stops at the first field that can't be parsed, e.g. because of a TBTT Information
+/// Length of `0`. The remaining bytes are kept as raw data.
+pub fn parse_reduced_neighbor_report(mut input: &[u8]) -> IResult<&[u8], ReducedNeighborReport> {
+    let mut neighbor_ap_information = Vec::new();
+
+    while !input.is_empty() {
+        let Ok((remaining, info)) = parse_neighbor_ap_information(input) else {
+            break;
+        };
+        neighbor_ap_information.push(info);
+        input = remaining;
+    }
+
+    Ok((
+        &[],
+        ReducedNeighborReport {
+            neighbor_ap_information,
+            other_data: input.to_vec(),
+        },
+    ))
+}
+
+fn parse_neighbor_ap_information(input: &[u8]) -> IResult<&[u8], NeighborApInformation> {
+    let (mut input, (header, operating_class, channel)) = (le_u16, le_u8, le_u8).parse(input)?;
+
+    let count = ((header >> 4) & 0x0f) as usize + 1;
+    let tbtt_information_length = (header >> 8) as usize;
+
+    let mut tbtt_information = Vec::with_capacity(count);
+    for _ in 0..count {
+        let data;
+        (input, data) = take(tbtt_information_length)(input)?;
+        let (_, info) = parse_tbtt_information(data)?;
+        tbtt_information.push(info);
+    }
+
+    Ok((
+        input,
+        NeighborApInformation {
+            tbtt_information_field_type: (header & 0b11) as u8,
+            filtered_neighbor_ap: header & (1 << 2) != 0,
+            operating_class,
+            channel,
+            tbtt_information,
+        },
+    ))
+}
+
+/// Parse a single TBTT Information field.
+///
+/// The contained subfields are determined by the length of the field.
+fn parse_tbtt_information(input: &[u8]) -> IResult<&[u8], TbttInformation> {
+    // (BSSID, Short SSID, BSS Parameters, 20 MHz PSD, MLD Parameters)
+    let (bssid, short_ssid, bss_parameters, psd, mld) = match input.len() {
+        1 => (false, false, false, false, false),
+        2 => (false, false, true, false, false),
+        5 => (false, true, false, false, false),
+        6 => (false, true, true, false, false),
+        7 => (true, false, false, false, false),
+        8 => (true, false, true, false, false),
+        9 => (true, false, true, true, false),
+        11 => (true, true, false, false, false),
+        12 => (true, true, true, false, false),
+        13 => (true, true, true, true, false),
+        length if length >= 16 => (true, true, true, true, true),
+        // Reserved lengths, only the TBTT offset can be parsed.
+        _ => (false, false, false, false, false),
+    };
+
+    let (input, (tbtt_offset, bssid, short_ssid, bss_parameters, psd_20mhz, mld_parameters)) = (
+        le_u8,
+        cond(bssid, parse_mac),
+        cond(short_ssid, le_u32),
+        cond(bss_parameters, le_u8),
+        cond(psd, le_u8),
+        cond(mld, take(3usize)),
+    )
+        .parse(input)?;
+
+    Ok((
+        &[],
+        TbttInformation {
+            tbtt_offset,
+            bssid,
+            short_ssid,
+            bss_parameters: bss_parameters.map(RnrBssParameters::from_bits),
+            psd_20mhz: psd_20mhz.map(|psd| psd as i8),
+            mld_parameters: mld_parameters
+                .map(|bytes| RnrMldParameters::from_bytes(clone_slice::<3>(bytes))),
+            other_data: input.to_vec(),
+        },
+    ))
+}
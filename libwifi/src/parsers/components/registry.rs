@@ -0,0 +1,150 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock},
+};
+
+use super::vendor::{
+    decode_apple_device_info, decode_aruba_ap_name, decode_cisco_ap_name,
+    decode_dpp_configurator_connectivity, decode_multi_ap, decode_owe_transition_mode, decode_p2p,
+    decode_wifi_display,
+};
+use crate::frame::components::{
+    APPLE_OUI, ARUBA_OUI, CISCO_OUI, CustomElement, DecodedVendorElement, ElementKey,
+    VendorSpecificInfo, WFA_OUI,
+};
+
+/// A decoder for the payload of a vendor specific element, i.e. everything after the OUI type.
+pub type VendorDecoder = fn(&[u8]) -> Option<DecodedVendorElement>;
+
+/// A parser for the payload of a custom element.
+///
+/// The payload starts after the extension ID for extension elements and after the OUI type
+/// for vendor specific elements.
+/// Returning `None` falls back to the built-in handling of the element.
+pub type CustomElementParser = fn(&[u8]) -> Option<Arc<dyn CustomElement>>;
+
+static BUILTIN: LazyLock<ElementRegistry> = LazyLock::new(ElementRegistry::default);
+
+/// The vendor specific element decoders and custom element parsers that are used while parsing
+/// elements.
+///
+/// The registry is owned by the caller and passed to
+/// [parse_station_info_with](super::parse_station_info_with), while
+/// [parse_station_info](super::parse_station_info) uses [ElementRegistry::builtin].
+///
+/// [ElementRegistry::default] contains vendor decoders for:
+/// - WFA P2P, Wi-Fi Display, Multi-AP, OWE Transition Mode and DPP Configurator Connectivity
+/// - Cisco CCX AP name
+/// - Aruba AP name
+/// - Apple device info
+///
+/// Additional decoders and parsers can be added via [ElementRegistry::register_vendor_decoder]
+/// and [ElementRegistry::register_element_parser].
+///
+/// By default, all received elements are also copied into
+/// [StationInfo::elements](crate::frame::components::StationInfo::elements), which allows to
+/// re-encode parsed frames byte by byte.
+/// This can be disabled via [ElementRegistry::set_keep_elements].
+#[derive(Clone, Debug)]
+pub struct ElementRegistry {
+    vendor_decoders: HashMap<([u8; 3], u8), VendorDecoder>,
+    element_parsers: HashMap<ElementKey, CustomElementParser>,
+    keep_elements: bool,
+}
+
+impl Default for ElementRegistry {
+    fn default() -> Self {
+        let mut registry = ElementRegistry::empty();
+        registry.register_vendor_decoder(WFA_OUI, 0x09, decode_p2p);
+        registry.register_vendor_decoder(WFA_OUI, 0x0a, decode_wifi_display);
+        registry.register_vendor_decoder(WFA_OUI, 0x1b, decode_multi_ap);
+        registry.register_vendor_decoder(WFA_OUI, 0x1c, decode_owe_transition_mode);
+        registry.register_vendor_decoder(WFA_OUI, 0x1e, decode_dpp_configurator_connectivity);
+        registry.register_vendor_decoder(CISCO_OUI, 0x2f, decode_cisco_ap_name);
+        registry.register_vendor_decoder(ARUBA_OUI, 0x01, decode_aruba_ap_name);
+        registry.register_vendor_decoder(APPLE_OUI, 0x0a, decode_apple_device_info);
+        registry
+    }
+}
+
+impl ElementRegistry {
+    /// Create a registry without any decoders or parsers.
+    pub fn empty() -> Self {
+        ElementRegistry {
+            vendor_decoders: HashMap::new(),
+            element_parsers: HashMap::new(),
+            keep_elements: true,
+        }
+    }
+
+    /// The shared, immutable registry with all built-in decoders and no custom parsers.
+    pub fn builtin() -> &'static ElementRegistry {
+        &BUILTIN
+    }
+
+    /// Register a vendor decoder, replacing any existing decoder for the same OUI and type.
+    pub fn register_vendor_decoder(&mut self, oui: [u8; 3], oui_type: u8, decoder: VendorDecoder) {
+        self.vendor_decoders.insert((oui, oui_type), decoder);
+    }
+
+    /// Register a parser for an element ID, an extension element ID or a vendor OUI/type.
+    ///
+    /// The parser takes precedence over the built-in parsers.
+    /// The decoded values are stored in
+    /// [StationInfo::custom_elements](crate::frame::components::StationInfo::custom_elements).
+    pub fn register_element_parser(&mut self, key: ElementKey, parser: CustomElementParser) {
+        self.element_parsers.insert(key, parser);
+    }
+
+    /// Remove a previously registered parser.
+    pub fn unregister_element_parser(&mut self, key: ElementKey) {
+        self.element_parsers.remove(&key);
+    }
+
+    /// Keep a copy of all received elements in their original order and raw form.
+    ///
+    /// This is enabled by default.
+    /// Disabling it avoids copying every element, but frames are then encoded from the typed
+    /// fields, see [StationInfo::encode](crate::frame::components::StationInfo::encode).
+    pub fn set_keep_elements(&mut self, keep: bool) {
+        self.keep_elements = keep;
+    }
+
+    /// Whether a copy of all received elements is kept.
+    pub fn keeps_elements(&self) -> bool {
+        self.keep_elements
+    }
+
+    /// Decode a vendor specific element.
+    /// Returns `None` if there's no decoder for it or the payload is malformed.
+    pub fn decode_vendor(&self, info: &VendorSpecificInfo) -> Option<DecodedVendorElement> {
+        let decoder = self.vendor_decoders.get(&(info.oui, info.oui_type))?;
+        decoder(&info.data)
+    }
+
+    /// Try to decode an element with a registered custom parser.
+    ///
+    /// `data` is the element payload without the extension ID, but including the OUI.
+    pub(crate) fn parse_custom_element(
+        &self,
+        element_id: u8,
+        extension_id: Option<u8>,
+        data: &[u8],
+    ) -> Option<(ElementKey, Arc<dyn CustomElement>)> {
+        if self.element_parsers.is_empty() {
+            return None;
+        }
+
+        let (key, payload) = match (element_id, extension_id) {
+            (255, Some(extension_id)) => (ElementKey::Extension(extension_id), data),
+            (221, _) if data.len() >= 4 => (
+                ElementKey::Vendor([data[0], data[1], data[2]], data[3]),
+                &data[4..],
+            ),
+            _ => (ElementKey::Element(element_id), data),
+        };
+
+        let parser = self.element_parsers.get(&key)?;
+        parser(payload).map(|element| (key, element))
+    }
+}
@@ -0,0 +1,78 @@
+use nom::{
+    IResult, Parser,
+    combinator::map,
+    multi::many0,
+    number::complete::{le_i8, le_u8, le_u16},
+};
+
+use crate::frame::components::{
+    ChannelSubband, PowerCapability, Quiet, SupportedChannels, TpcReport,
+};
+
+/// Parse the Power Capability element.
+///
+/// - Minimum Transmit Power (1 byte)
+/// - Maximum Transmit Power (1 byte)
+pub fn parse_power_capability(input: &[u8]) -> IResult<&[u8], PowerCapability> {
+    let (input, (min_tx_power, max_tx_power)) = (le_i8, le_i8).parse(input)?;
+
+    Ok((
+        input,
+        PowerCapability {
+            min_tx_power,
+            max_tx_power,
+        },
+    ))
+}
+
+/// Parse the TPC Report element.
+///
+/// - Transmit Power (1 byte)
+/// - Link Margin (1 byte)
+pub fn parse_tpc_report(input: &[u8]) -> IResult<&[u8], TpcReport> {
+    let (input, (tx_power, link_margin)) = (le_i8, le_i8).parse(input)?;
+
+    Ok((
+        input,
+        TpcReport {
+            tx_power,
+            link_margin,
+        },
+    ))
+}
+
+/// Parse the Supported Channels element.
+///
+/// The element is a list of (First Channel Number, Number of Channels) tuples.
+pub fn parse_supported_channels(input: &[u8]) -> IResult<&[u8], SupportedChannels> {
+    let (input, subbands) = many0(map(
+        (le_u8, le_u8),
+        |(first_channel, number_of_channels)| ChannelSubband {
+            first_channel,
+            number_of_channels,
+        },
+    ))
+    .parse(input)?;
+
+    Ok((input, SupportedChannels { subbands }))
+}
+
+/// Parse the Quiet element.
+///
+/// - Quiet Count (1 byte)
+/// - Quiet Period (1 byte)
+/// - Quiet Duration (2 bytes)
+/// - Quiet Offset (2 bytes)
+pub fn parse_quiet(input: &[u8]) -> IResult<&[u8], Quiet> {
+    let (input, (count, period, duration, offset)) = (le_u8, le_u8, le_u16, le_u16).parse(input)?;
+
+    Ok((
+        input,
+        Quiet {
+            count,
+            period,
+            duration,
+            offset,
+        },
+    ))
+}
@@ -7,13 +7,31 @@ use nom::{
     number::complete::u8 as get_u8,
 };
 
+use super::eht::parse_multi_link_with;
+use super::multiple_bssid::parse_multiple_bssid_with;
+use super::{ElementRegistry, IeIter};
+use super::{
+    parse_advertisement_protocol, parse_bss_load, parse_channel_switch_wrapper,
+    parse_edca_parameter_set, parse_eht_capabilities, parse_eht_operation, parse_extended_bss_load,
+    parse_extended_channel_switch, parse_fast_transition, parse_ftm_parameters,
+    parse_ftm_synchronization_information, parse_gate_announcement, parse_hs20_indication,
+    parse_interworking, parse_link_identifier, parse_max_channel_switch_time,
+    parse_measurement_report, parse_measurement_request, parse_mesh_awake_window,
+    parse_mesh_configuration, parse_mesh_peering_management, parse_mobility_domain,
+    parse_mu_edca_parameter_set, parse_multiple_bssid_index, parse_neighbor_report,
+    parse_non_inheritance, parse_path_error, parse_path_reply, parse_path_request,
+    parse_power_capability, parse_quiet, parse_reduced_neighbor_report, parse_roaming_consortium,
+    parse_root_announcement, parse_supported_channels, parse_tfs_request_element,
+    parse_tfs_response_element, parse_timeout_interval, parse_tpc_report,
+    parse_wide_bandwidth_channel_switch, parse_wmm, parse_wnm_sleep_mode,
+};
 use crate::frame::components::{
     AudioDevices, Cameras, Category, ChannelSwitchAnnouncment, ChannelSwitchMode, Computers,
-    Displays, DockingDevices, ExtendedCapabilities, GamingDevices, HTCapabilities, HTInformation,
-    InputDevices, MultimediaDevices, MultipleBSSID, NetworkInfrastructure, PrintersEtAl,
-    RsnAkmSuite, RsnCipherSuite, RsnInformation, StationInfo, Storage, SupportedRate, Telephone,
-    VHTCapabilities, VendorSpecificInfo, WpaAkmSuite, WpaCipherSuite, WpaInformation,
-    WpsInformation, WpsSetupState,
+    Displays, DockingDevices, ErpInformation, ExtendedCapabilities, GamingDevices, HTCapabilities,
+    HTInformation, InformationElement, InputDevices, MultimediaDevices, NetworkInfrastructure,
+    PrintersEtAl, RsnAkmSuite, RsnCipherSuite, RsnExtension, RsnInformation,
+    SecondaryChannelOffset, StationInfo, Storage, SupportedRate, Telephone, VHTCapabilities,
+    VendorSpecificInfo, WpaAkmSuite, WpaCipherSuite, WpaInformation, WpsInformation, WpsSetupState,
 };
 
 /// Parse variable length and variable field information.
@@ -27,111 +45,483 @@ use crate::frame::components::{
 ///
 /// There might be multiple elements with the same element id,
 /// which is why StationInfo uses a Vec instead of BTreeMap as a data structure.
-pub fn parse_station_info(mut input: &[u8]) -> IResult<&[u8], StationInfo> {
+///
+/// Only the built-in parsers are used, see [parse_station_info_with] for custom parsers.
+pub fn parse_station_info(input: &[u8]) -> IResult<&[u8], StationInfo> {
+    parse_station_info_with(input, ElementRegistry::builtin())
+}
+
+/// Parse elements like [parse_station_info], using the custom element parsers of `registry`.
+///
+/// Unless disabled via [ElementRegistry::set_keep_elements], all elements are additionally
+/// copied into [StationInfo::elements].
+pub fn parse_station_info_with<'a>(
+    mut input: &'a [u8],
+    registry: &ElementRegistry,
+) -> IResult<&'a [u8], StationInfo> {
+    let mut station_info = StationInfo::default();
+
+    // The input has to start with a complete element.
+    let mut complete = false;
+    // Parse elements as long as there's a complete element left.
+    // Trailing bytes that don't form a complete element are returned as remainder.
+    let mut elements = IeIter::new(input);
+    for (element_id, extension_id, data) in elements.by_ref() {
+        complete = true;
+
+        let Ok(decoded) =
+            parse_element(&mut station_info, element_id, extension_id, data, registry)
+        else {
+            let nom_error = Error::new(elements.remainder(), ErrorKind::Fail);
+            return Err(nom::Err::Error(nom_error));
+        };
+
+        if registry.keeps_elements() {
+            station_info.elements.push(InformationElement {
+                id: element_id,
+                extension_id,
+                data: data.to_vec(),
+                decoded,
+            });
+        }
+    }
+    input = elements.remainder();
+
+    if !complete {
+        let nom_error = Error::new(input, ErrorKind::Eof);
+        return Err(nom::Err::Error(nom_error));
+    }
+
+    Ok((input, station_info))
+}
+
+/// Parse a list of elements that is nested inside of another element or subelement,
+/// for instance the elements of a per-STA profile.
+///
+/// Other than [parse_station_info], this expects the element list to span the whole input.
+pub(crate) fn parse_element_list<'a>(
+    mut input: &'a [u8],
+    registry: &ElementRegistry,
+) -> IResult<&'a [u8], StationInfo> {
     let mut station_info = StationInfo::default();
 
     let mut element_id;
     let mut length;
     let mut data;
-    loop {
+    while !input.is_empty() {
         (input, (element_id, length)) = (get_u8, get_u8).parse(input)?;
         (input, data) = take(length)(input)?;
-        if !data.is_empty() {
-            match element_id {
-                0 => {
-                    let ssid = String::from_utf8_lossy(data).to_string();
-                    station_info.ssid = Some(ssid);
-                    station_info.ssid_length = Some(length as usize);
-                    // if ssid is not utf8, can use the raw data.
-                    station_info.ssid_raw = Some(data[..length as usize].to_vec());
-                }
-                1 => station_info.supported_rates = parse_supported_rates(data),
-                3 => station_info.ds_parameter_set = Some(data[0]),
-                5 => station_info.tim = Some(data.to_vec()),
-                6 => {
-                    station_info.ibss_parameter_set = if data.len() >= 2 {
-                        Some(u16::from_le_bytes([data[0], data[1]]))
-                    } else {
-                        None
-                    }
-                }
-                7 => station_info.country_info = Some(data.to_vec()),
-                32 => station_info.power_constraint = Some(data[0]),
-                37 => station_info.channel_switch = parse_channel_switch(data),
-                45 => station_info.ht_capabilities = parse_ht_capabilities(data),
-                48 => {
-                    if let Ok(rsn_info) = parse_rsn_information(data) {
-                        station_info.rsn_information = Some(rsn_info)
-                    }
-                }
-                50 => station_info.extended_supported_rates = Some(parse_supported_rates(data)),
-                61 => {
-                    if let Ok(ht_info) = parse_ht_information(data) {
-                        station_info.ht_information = Some(ht_info)
-                    }
-                }
-                71 => {
-                    if let Ok(multiple_bssid) = parse_multiple_bssid(data) {
-                        station_info.multiple_bssid = Some(multiple_bssid)
-                    }
-                }
-                127 => station_info.extended_capabilities = parse_extended_capabilities(data).ok(),
-                191 => station_info.vht_capabilities = parse_vht_capabilities(data),
-                221 => {
-                    // Vendor-specific tag
-                    if data.len() >= 4 {
-                        // Minimum length for OUI and OUI Type
-                        let oui = [data[0], data[1], data[2]];
-                        let oui_type = data[3];
-                        let vendor_data = data[4..].to_vec();
-
-                        if oui == [0x00, 0x50, 0xf2] && oui_type == 1 {
-                            let wpa_info = match parse_wpa_information(&vendor_data) {
-                                Ok(wpa_info) => wpa_info,
-                                Err(_) => {
-                                    let nom_error = Error::new(input, ErrorKind::Fail);
-                                    return Err(nom::Err::Error(nom_error));
-                                }
-                            };
-                            // Specific parsing for WPA Information Element
-                            station_info.wpa_info = Some(wpa_info);
-                        } else if oui == [0x00, 0x50, 0xf2] && oui_type == 4 {
-                            // Specific parsing for WPS Information Element
-                            station_info.wps_info = parse_wps_information(&vendor_data).ok();
-                        } else {
-                            let vendor_specific_info = VendorSpecificInfo {
-                                element_id,
-                                length,
-                                oui,
-                                oui_type,
-                                data: vendor_data,
-                            };
-                            station_info.vendor_specific.push(vendor_specific_info);
-                        }
-                    }
+        let (extension_id, data) = split_extension_id(element_id, data);
+        if parse_element(&mut station_info, element_id, extension_id, data, registry).is_err() {
+            let nom_error = Error::new(input, ErrorKind::Fail);
+            return Err(nom::Err::Error(nom_error));
+        }
+    }
+
+    Ok((input, station_info))
+}
+
+/// Split the extension ID off the payload of extension elements.
+fn split_extension_id(element_id: u8, data: &[u8]) -> (Option<u8>, &[u8]) {
+    match (element_id, data.split_first()) {
+        (255, Some((extension_id, data))) => (Some(*extension_id), data),
+        _ => (None, data),
+    }
+}
+
+/// Decode a single element into an otherwise empty [StationInfo].
+///
+/// For extension elements, `data` doesn't include the extension ID.
+/// Returns `None` if the element is malformed or there's no parser for it.
+pub(crate) fn decode_element(
+    element_id: u8,
+    extension_id: Option<u8>,
+    data: &[u8],
+    registry: &ElementRegistry,
+) -> Option<StationInfo> {
+    let mut station_info = StationInfo::default();
+    match parse_element(&mut station_info, element_id, extension_id, data, registry) {
+        Ok(true) => Some(station_info),
+        _ => None,
+    }
+}
+
+/// Parse the payload of a single element and store it in the respective field of the
+/// [StationInfo].
+///
+/// Returns whether the element has been decoded.
+/// Elements that aren't decoded, either because they're unknown or malformed, are stored in
+/// their raw form in [StationInfo::data] or [StationInfo::vendor_specific].
+/// For extension elements, `data` doesn't include the extension ID.
+/// Empty elements are only decoded for the SSID of hidden networks.
+fn parse_element(
+    station_info: &mut StationInfo,
+    element_id: u8,
+    extension_id: Option<u8>,
+    data: &[u8],
+    registry: &ElementRegistry,
+) -> Result<bool, &'static str> {
+    if data.is_empty() && extension_id.is_none() && element_id != 0 {
+        station_info.data.push((element_id, Vec::new()));
+        return Ok(false);
+    }
+
+    if let Some((key, element)) = registry.parse_custom_element(element_id, extension_id, data) {
+        station_info.custom_elements.push(key, element);
+        return Ok(true);
+    }
+
+    let length = data.len() as u8;
+    let decoded = match element_id {
+        0 => {
+            let ssid = String::from_utf8_lossy(data).to_string();
+            station_info.ssid = Some(ssid);
+            station_info.ssid_length = Some(length as usize);
+            // if ssid is not utf8, can use the raw data.
+            station_info.ssid_raw = Some(data[..length as usize].to_vec());
+            true
+        }
+        1 => {
+            station_info.supported_rates = parse_supported_rates(data);
+            true
+        }
+        3 => store(&mut station_info.ds_parameter_set, Some(data[0])),
+        5 => store(&mut station_info.tim, Some(data.to_vec())),
+        6 => store(
+            &mut station_info.ibss_parameter_set,
+            (data.len() >= 2).then(|| u16::from_le_bytes([data[0], data[1]])),
+        ),
+        7 => store(&mut station_info.country_info, Some(data.to_vec())),
+        11 => store(
+            &mut station_info.bss_load,
+            parse_bss_load(data).ok().map(|(_, load)| load),
+        ),
+        12 => store(
+            &mut station_info.edca_parameter_set,
+            parse_edca_parameter_set(data).ok().map(|(_, set)| set),
+        ),
+        32 => store(&mut station_info.power_constraint, Some(data[0])),
+        33 => store(
+            &mut station_info.power_capability,
+            parse_power_capability(data)
+                .ok()
+                .map(|(_, capability)| capability),
+        ),
+        35 => store(
+            &mut station_info.tpc_report,
+            parse_tpc_report(data).ok().map(|(_, report)| report),
+        ),
+        36 => store(
+            &mut station_info.supported_channels,
+            parse_supported_channels(data)
+                .ok()
+                .map(|(_, channels)| channels),
+        ),
+        37 => store(&mut station_info.channel_switch, parse_channel_switch(data)),
+        38 => push(
+            &mut station_info.measurement_requests,
+            parse_measurement_request(data)
+                .ok()
+                .map(|(_, request)| request),
+        ),
+        39 => push(
+            &mut station_info.measurement_reports,
+            parse_measurement_report(data)
+                .ok()
+                .map(|(_, report)| report),
+        ),
+        40 => push(
+            &mut station_info.quiet,
+            parse_quiet(data).ok().map(|(_, quiet)| quiet),
+        ),
+        42 => store(
+            &mut station_info.erp_information,
+            Some(ErpInformation::from_bits(data[0])),
+        ),
+        45 => store(
+            &mut station_info.ht_capabilities,
+            parse_ht_capabilities(data),
+        ),
+        48 => store(
+            &mut station_info.rsn_information,
+            parse_rsn_information(data).ok(),
+        ),
+        50 => store(
+            &mut station_info.extended_supported_rates,
+            Some(parse_supported_rates(data)),
+        ),
+        52 => push(
+            &mut station_info.neighbor_reports,
+            parse_neighbor_report(data).ok().map(|(_, report)| report),
+        ),
+        54 => store(
+            &mut station_info.mobility_domain,
+            parse_mobility_domain(data).ok().map(|(_, md)| md),
+        ),
+        55 => store(
+            &mut station_info.fast_transition,
+            parse_fast_transition(data).ok().map(|(_, ft)| ft),
+        ),
+        56 => store(
+            &mut station_info.timeout_interval,
+            parse_timeout_interval(data)
+                .ok()
+                .map(|(_, interval)| interval),
+        ),
+        60 => store(
+            &mut station_info.extended_channel_switch,
+            parse_extended_channel_switch(data)
+                .ok()
+                .map(|(_, switch)| switch),
+        ),
+        61 => store(
+            &mut station_info.ht_information,
+            parse_ht_information(data).ok(),
+        ),
+        62 => store(
+            &mut station_info.secondary_channel_offset,
+            Some(SecondaryChannelOffset::from(data[0])),
+        ),
+        71 => push(
+            &mut station_info.multiple_bssids,
+            parse_multiple_bssid_with(data, registry)
+                .ok()
+                .map(|(_, multiple_bssid)| multiple_bssid),
+        ),
+        83 => store(
+            &mut station_info.nontransmitted_bssid_capability,
+            (data.len() >= 2).then(|| u16::from_le_bytes([data[0], data[1]])),
+        ),
+        85 => store(
+            &mut station_info.multiple_bssid_index,
+            parse_multiple_bssid_index(data),
+        ),
+        91 => push(
+            &mut station_info.tfs_requests,
+            parse_tfs_request_element(data)
+                .ok()
+                .map(|(_, request)| request),
+        ),
+        92 => push(
+            &mut station_info.tfs_responses,
+            parse_tfs_response_element(data)
+                .ok()
+                .map(|(_, response)| response),
+        ),
+        93 => store(
+            &mut station_info.wnm_sleep_mode,
+            parse_wnm_sleep_mode(data).ok().map(|(_, mode)| mode),
+        ),
+        101 => store(
+            &mut station_info.link_identifier,
+            parse_link_identifier(data)
+                .ok()
+                .map(|(_, identifier)| identifier),
+        ),
+        107 => store(
+            &mut station_info.interworking,
+            parse_interworking(data).ok().map(|(_, iw)| iw),
+        ),
+        108 => store(
+            &mut station_info.advertisement_protocol,
+            parse_advertisement_protocol(data).ok().map(|(_, ap)| ap),
+        ),
+        111 => store(
+            &mut station_info.roaming_consortium,
+            parse_roaming_consortium(data).ok().map(|(_, rc)| rc),
+        ),
+        113 => store(
+            &mut station_info.mesh_configuration,
+            parse_mesh_configuration(data)
+                .ok()
+                .map(|(_, config)| config),
+        ),
+        114 => store(&mut station_info.mesh_id, Some(data.to_vec())),
+        117 => store(
+            &mut station_info.mesh_peering_management,
+            parse_mesh_peering_management(data).ok().map(|(_, mpm)| mpm),
+        ),
+        119 => store(
+            &mut station_info.mesh_awake_window,
+            parse_mesh_awake_window(data).ok().map(|(_, window)| window),
+        ),
+        125 => store(
+            &mut station_info.gate_announcement,
+            parse_gate_announcement(data)
+                .ok()
+                .map(|(_, announcement)| announcement),
+        ),
+        126 => store(
+            &mut station_info.root_announcement,
+            parse_root_announcement(data)
+                .ok()
+                .map(|(_, announcement)| announcement),
+        ),
+        127 => store(
+            &mut station_info.extended_capabilities,
+            parse_extended_capabilities(data).ok(),
+        ),
+        130 => push(
+            &mut station_info.path_requests,
+            parse_path_request(data).ok().map(|(_, request)| request),
+        ),
+        131 => push(
+            &mut station_info.path_replies,
+            parse_path_reply(data).ok().map(|(_, reply)| reply),
+        ),
+        132 => store(
+            &mut station_info.path_error,
+            parse_path_error(data).ok().map(|(_, error)| error),
+        ),
+        191 => store(
+            &mut station_info.vht_capabilities,
+            parse_vht_capabilities(data),
+        ),
+        193 => store(
+            &mut station_info.extended_bss_load,
+            parse_extended_bss_load(data).ok().map(|(_, load)| load),
+        ),
+        194 => store(
+            &mut station_info.wide_bandwidth_channel_switch,
+            parse_wide_bandwidth_channel_switch(data)
+                .ok()
+                .map(|(_, switch)| switch),
+        ),
+        196 => store(
+            &mut station_info.channel_switch_wrapper,
+            parse_channel_switch_wrapper(data)
+                .ok()
+                .map(|(_, wrapper)| wrapper),
+        ),
+        201 => push(
+            &mut station_info.reduced_neighbor_reports,
+            parse_reduced_neighbor_report(data)
+                .ok()
+                .map(|(_, report)| report),
+        ),
+        206 => store(
+            &mut station_info.ftm_parameters,
+            parse_ftm_parameters(data)
+                .ok()
+                .map(|(_, parameters)| parameters),
+        ),
+        221 if data.len() >= 4 => {
+            // Vendor-specific tag
+            // Minimum length for OUI and OUI Type
+            let oui = [data[0], data[1], data[2]];
+            let oui_type = data[3];
+            let vendor_data = data[4..].to_vec();
+
+            if oui == [0x00, 0x50, 0xf2] && oui_type == 1 {
+                // Specific parsing for WPA Information Element
+                station_info.wpa_info = Some(parse_wpa_information(&vendor_data)?);
+                return Ok(true);
+            } else if oui == [0x00, 0x50, 0xf2] && oui_type == 2 {
+                // WMM Information/Parameter element.
+                // Other subtypes (e.g. TSPEC) are kept as generic vendor specific info.
+                if let Ok(([], wmm)) = parse_wmm(&vendor_data) {
+                    station_info.wmm = Some(wmm);
+                    return Ok(true);
                 }
-                255 => {
-                    let ext_element_id = data[0];
-                    match ext_element_id {
-                        35 => {
-                            station_info.he_capabilities = Some(data.to_vec());
-                        }
-                        _ => { // TODO: implement parsing for other extended element ids
-                        }
-                    }
+            } else if oui == [0x00, 0x50, 0xf2] && oui_type == 4 {
+                // Specific parsing for WPS Information Element
+                if let Ok(wps_info) = parse_wps_information(&vendor_data) {
+                    station_info.wps_info = Some(wps_info);
+                    return Ok(true);
                 }
-                _ => {
-                    station_info.data.push((element_id, data.to_vec()));
+            } else if oui == [0x50, 0x6f, 0x9a] && oui_type == 0x10 {
+                // WFA Hotspot 2.0 Indication element
+                if let Ok(([], hs20_indication)) = parse_hs20_indication(&vendor_data) {
+                    station_info.hs20_indication = Some(hs20_indication);
+                    return Ok(true);
                 }
-            };
+            }
 
-            if input.len() <= 4 {
-                break;
+            let vendor_specific_info = VendorSpecificInfo {
+                element_id,
+                length,
+                oui,
+                oui_type,
+                data: vendor_data,
+            };
+            station_info.vendor_specific.push(vendor_specific_info);
+            return Ok(false);
+        }
+        244 => store(
+            &mut station_info.rsn_extension,
+            Some(parse_rsn_extension(data)),
+        ),
+        255 => {
+            match extension_id {
+                Some(9) => store(
+                    &mut station_info.ftm_synchronization_information,
+                    parse_ftm_synchronization_information(data)
+                        .ok()
+                        .map(|(_, info)| info),
+                ),
+                Some(35) => store(
+                    &mut station_info.he_capabilities,
+                    Some([&[35], data].concat()),
+                ),
+                Some(38) => store(
+                    &mut station_info.mu_edca_parameter_set,
+                    parse_mu_edca_parameter_set(data).ok().map(|(_, set)| set),
+                ),
+                Some(52) => store(
+                    &mut station_info.max_channel_switch_time,
+                    parse_max_channel_switch_time(data)
+                        .ok()
+                        .map(|(_, time)| time),
+                ),
+                Some(56) => store(
+                    &mut station_info.non_inheritance,
+                    parse_non_inheritance(data).ok().map(|(_, list)| list),
+                ),
+                Some(106) => store(
+                    &mut station_info.eht_operation,
+                    parse_eht_operation(data).ok().map(|(_, op)| op),
+                ),
+                Some(107) => store(
+                    &mut station_info.multi_link,
+                    parse_multi_link_with(data, registry)
+                        .ok()
+                        .map(|(_, multi_link)| multi_link),
+                ),
+                Some(108) => store(
+                    &mut station_info.eht_capabilities,
+                    parse_eht_capabilities(data).ok().map(|(_, caps)| caps),
+                ),
+                // TODO: implement parsing for other extended element ids
+                _ => false,
             }
         }
+        _ => false,
+    };
+
+    if !decoded {
+        let raw = extension_id.iter().chain(data).copied().collect();
+        station_info.data.push((element_id, raw));
     }
 
-    Ok((input, station_info))
+    Ok(decoded)
+}
+
+/// Store a decoded value in its field, unless it couldn't be decoded.
+/// Returns whether the value has been decoded.
+fn store<T>(field: &mut Option<T>, value: Option<T>) -> bool {
+    let decoded = value.is_some();
+    if decoded {
+        *field = value;
+    }
+    decoded
+}
+
+/// Add a decoded value to its list, unless it couldn't be decoded.
+/// Returns whether the value has been decoded.
+fn push<T>(list: &mut Vec<T>, value: Option<T>) -> bool {
+    let decoded = value.is_some();
+    list.extend(value);
+    decoded
 }
 
 fn parse_wpa_information(data: &[u8]) -> Result<WpaInformation, &'static str> {
@@ -350,17 +740,6 @@ fn parse_vht_capabilities(data: &[u8]) -> Option<VHTCapabilities> {
     })
 }
 
-fn parse_multiple_bssid(data: &[u8]) -> Result<MultipleBSSID, &'static str> {
-    // shortest possible length is maxBSSIDIndicator u8
-    if data.is_empty() {
-        return Err("Multiple BSSID data too short");
-    }
-    Ok(MultipleBSSID {
-        max_bssid_indicator: data[0],
-        other_data: data[1..].to_vec(),
-    })
-}
-
 fn parse_wps_information(data: &[u8]) -> Result<WpsInformation, &'static str> {
     let mut wps_info = WpsInformation::default();
     let mut offset = 0;
@@ -660,6 +1039,35 @@ pub fn parse_rsn_information(data: &[u8]) -> Result<RsnInformation, &'static str
     }
 }
 
+/// Parse the RSN Extension element.
+///
+/// The lower 4 bits of the first byte contain the length of the capability field minus one.
+/// Capability bits beyond that length aren't set, even if the element contains further bytes.
+pub fn parse_rsn_extension(data: &[u8]) -> RsnExtension {
+    let length = ((data[0] & 0x0f) as usize + 1).min(data.len());
+    let (capabilities, trailing_data) = data.split_at(length);
+    let bits = |bit: usize| {
+        capabilities
+            .get(bit / 8)
+            .is_some_and(|byte| byte & (1 << (bit % 8)) != 0)
+    };
+
+    RsnExtension {
+        protected_twt_operations: bits(4),
+        sae_hash_to_element: bits(5),
+        sae_pk: bits(6),
+        protected_wur_frames: bits(7),
+        secure_ltf: bits(8),
+        secure_rtt: bits(9),
+        urnm_mfpr_x20: bits(10),
+        protected_announce: bits(11),
+        pbac: bits(12),
+        length: length as u8,
+        other_data: capabilities.get(2..).unwrap_or_default().to_vec(),
+        trailing_data: trailing_data.to_vec(),
+    }
+}
+
 pub fn parse_channel_switch(data: &[u8]) -> Option<ChannelSwitchAnnouncment> {
     if data.len() < 3 {
         return None;
@@ -727,7 +1135,13 @@ fn parse_akm_suite(data: &[u8]) -> RsnAkmSuite {
         [0x00, 0x0F, 0xAC, 0x05] => RsnAkmSuite::EAP256,
         [0x00, 0x0F, 0xAC, 0x06] => RsnAkmSuite::PSK256,
         [0x00, 0x0F, 0xAC, 0x08] => RsnAkmSuite::SAE,
+        [0x00, 0x0F, 0xAC, 0x09] => RsnAkmSuite::SAEFT,
         [0x00, 0x0F, 0xAC, 0x0b] => RsnAkmSuite::SUITEBEAP256,
+        [0x00, 0x0F, 0xAC, 0x0c] => RsnAkmSuite::SUITEBEAP384,
+        [0x00, 0x0F, 0xAC, 0x0d] => RsnAkmSuite::EAPFT384,
+        [0x00, 0x0F, 0xAC, 0x12] => RsnAkmSuite::OWE,
+        [0x00, 0x0F, 0xAC, 0x18] => RsnAkmSuite::SAEEXTKEY,
+        [0x00, 0x0F, 0xAC, 0x19] => RsnAkmSuite::SAEEXTKEYFT,
         _ => RsnAkmSuite::Unknown(data.to_vec()),
     }
 }
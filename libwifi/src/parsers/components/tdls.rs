@@ -0,0 +1,22 @@
+use nom::{IResult, Parser};
+
+use super::parse_mac;
+use crate::frame::components::LinkIdentifier;
+
+/// Parse the Link Identifier element.
+///
+/// - BSSID (6 bytes)
+/// - TDLS Initiator STA Address (6 bytes)
+/// - TDLS Responder STA Address (6 bytes)
+pub fn parse_link_identifier(input: &[u8]) -> IResult<&[u8], LinkIdentifier> {
+    let (input, (bssid, initiator, responder)) = (parse_mac, parse_mac, parse_mac).parse(input)?;
+
+    Ok((
+        input,
+        LinkIdentifier {
+            bssid,
+            initiator,
+            responder,
+        },
+    ))
+}
@@ -0,0 +1,111 @@
+use nom::{
+    IResult, Parser,
+    bytes::complete::take,
+    combinator::all_consuming,
+    number::complete::{be_u16, le_u8},
+};
+
+use super::{parse_mac, parse_p2p_attributes};
+use crate::frame::components::{
+    AppleDeviceInfo, DecodedVendorElement, MultiAp, OweTransitionMode, WfdSubelement,
+};
+
+/// P2P attributes, see [parse_p2p_attributes]
+pub(super) fn decode_p2p(input: &[u8]) -> Option<DecodedVendorElement> {
+    let (_, attributes) = all_consuming(parse_p2p_attributes).parse(input).ok()?;
+
+    Some(DecodedVendorElement::P2p(attributes))
+}
+
+/// Wi-Fi Display subelements: ID (1 byte), length (2 bytes, big endian), data
+pub(super) fn decode_wifi_display(mut input: &[u8]) -> Option<DecodedVendorElement> {
+    let mut subelements = Vec::new();
+    while !input.is_empty() {
+        let result: IResult<&[u8], (u8, &[u8])> = (le_u8, be_u16)
+            .flat_map(|(id, length)| take(length).map(move |data| (id, data)))
+            .parse(input);
+        let (id, data);
+        (input, (id, data)) = result.ok()?;
+        subelements.push(WfdSubelement {
+            id,
+            data: data.to_vec(),
+        });
+    }
+
+    Some(DecodedVendorElement::WifiDisplay(subelements))
+}
+
+/// Multi-AP subelements: ID (1 byte), length (1 byte), data
+pub(super) fn decode_multi_ap(mut input: &[u8]) -> Option<DecodedVendorElement> {
+    let mut multi_ap = MultiAp::default();
+    while input.len() >= 2 {
+        let (id, length) = (input[0], input[1] as usize);
+        let data = input.get(2..2 + length)?;
+        match id {
+            // Multi-AP Extension
+            0x06 => {
+                let bits = *data.first()?;
+                multi_ap.tear_down = bits & (1 << 4) != 0;
+                multi_ap.fronthaul_bss = bits & (1 << 5) != 0;
+                multi_ap.backhaul_bss = bits & (1 << 6) != 0;
+                multi_ap.backhaul_sta = bits & (1 << 7) != 0;
+            }
+            // Multi-AP Profile
+            0x07 => multi_ap.profile = data.first().copied(),
+            // Multi-AP Default 802.1Q Setting
+            0x08 if data.len() >= 2 => {
+                multi_ap.default_vlan_id = Some(u16::from_le_bytes([data[0], data[1]]))
+            }
+            _ => {}
+        }
+        input = &input[2 + length..];
+    }
+
+    Some(DecodedVendorElement::MultiAp(multi_ap))
+}
+
+/// OWE Transition Mode: BSSID, SSID length, SSID and optional band info
+pub(super) fn decode_owe_transition_mode(input: &[u8]) -> Option<DecodedVendorElement> {
+    let result: IResult<&[u8], _> = (parse_mac, le_u8.flat_map(take)).parse(input);
+    let (band_info, (bssid, ssid)) = result.ok()?;
+
+    Some(DecodedVendorElement::OweTransitionMode(OweTransitionMode {
+        bssid,
+        ssid: ssid.to_vec(),
+        operating_class: band_info.first().copied(),
+        channel: band_info.get(1).copied(),
+    }))
+}
+
+pub(super) fn decode_dpp_configurator_connectivity(_input: &[u8]) -> Option<DecodedVendorElement> {
+    Some(DecodedVendorElement::DppConfiguratorConnectivity)
+}
+
+/// The zero padded AP name
+pub(super) fn decode_cisco_ap_name(input: &[u8]) -> Option<DecodedVendorElement> {
+    Some(DecodedVendorElement::CiscoApName(parse_padded_string(
+        input,
+    )))
+}
+
+/// Subtype (1 byte), followed by the AP name for subtype 3
+pub(super) fn decode_aruba_ap_name(input: &[u8]) -> Option<DecodedVendorElement> {
+    match input.split_first()? {
+        (3, name) => Some(DecodedVendorElement::ArubaApName(parse_padded_string(name))),
+        _ => None,
+    }
+}
+
+pub(super) fn decode_apple_device_info(input: &[u8]) -> Option<DecodedVendorElement> {
+    Some(DecodedVendorElement::AppleDeviceInfo(AppleDeviceInfo {
+        flags: input.to_vec(),
+    }))
+}
+
+fn parse_padded_string(input: &[u8]) -> String {
+    let end = input
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(input.len());
+    String::from_utf8_lossy(&input[..end]).to_string()
+}
@@ -0,0 +1,69 @@
+use nom::{
+    IResult, Parser,
+    number::complete::{le_u8, le_u16, le_u64},
+};
+
+use super::parse_subelements;
+use crate::frame::components::{
+    BssTerminationDuration, TfsRequestElement, TfsResponseElement, WnmSleepMode,
+};
+
+/// Parse the payload of a BSS Termination Duration subelement.
+///
+/// - BSS Termination TSF (8 bytes)
+/// - Duration (2 bytes)
+pub fn parse_bss_termination_duration(input: &[u8]) -> IResult<&[u8], BssTerminationDuration> {
+    let (input, (bss_termination_tsf, duration)) = (le_u64, le_u16).parse(input)?;
+
+    Ok((
+        input,
+        BssTerminationDuration {
+            bss_termination_tsf,
+            duration,
+        },
+    ))
+}
+
+/// Parse the WNM Sleep Mode element.
+///
+/// - Action Type (1 byte)
+/// - WNM Sleep Mode Response Status (1 byte)
+/// - WNM Sleep Interval (2 bytes)
+pub fn parse_wnm_sleep_mode(input: &[u8]) -> IResult<&[u8], WnmSleepMode> {
+    let (input, (action_type, status, interval)) = (le_u8, le_u8, le_u16).parse(input)?;
+
+    Ok((
+        input,
+        WnmSleepMode {
+            action_type,
+            status,
+            interval,
+        },
+    ))
+}
+
+/// Parse the TFS Request element.
+///
+/// - TFS ID (1 byte)
+/// - TFS Action Code (1 byte)
+/// - TFS Subelements (variable)
+pub fn parse_tfs_request_element(input: &[u8]) -> IResult<&[u8], TfsRequestElement> {
+    let (input, (tfs_id, action_code, subelements)) =
+        (le_u8, le_u8, parse_subelements).parse(input)?;
+
+    Ok((
+        input,
+        TfsRequestElement {
+            tfs_id,
+            action_code,
+            subelements,
+        },
+    ))
+}
+
+/// Parse the TFS Response element, which only consists of TFS Status subelements.
+pub fn parse_tfs_response_element(input: &[u8]) -> IResult<&[u8], TfsResponseElement> {
+    let (input, subelements) = parse_subelements(input)?;
+
+    Ok((input, TfsResponseElement { subelements }))
+}
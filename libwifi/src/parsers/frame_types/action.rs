@@ -0,0 +1,208 @@
+use nom::{IResult, Parser, branch::alt, combinator::map};
+
+use super::{
+    parse_addba_request, parse_addba_response, parse_bss_transition_query,
+    parse_bss_transition_request, parse_bss_transition_response, parse_delba, parse_dpp_frame,
+    parse_ftm, parse_ftm_request, parse_gas_comeback_request, parse_gas_comeback_response,
+    parse_gas_initial_request, parse_gas_initial_response, parse_link_measurement_report,
+    parse_link_measurement_request, parse_mesh_peering_confirm, parse_mesh_peering_open,
+    parse_neighbor_report_request, parse_neighbor_report_response, parse_p2p_action,
+    parse_p2p_public_action, parse_radio_measurement_report, parse_radio_measurement_request,
+    parse_sa_query_request, parse_sa_query_response, parse_tdls_channel_switch_request,
+    parse_tdls_channel_switch_response, parse_tdls_discovery_request,
+    parse_tdls_discovery_response, parse_tdls_peer_traffic_indication, parse_tdls_setup_confirm,
+    parse_tdls_setup_request, parse_tdls_setup_response, parse_tdls_teardown, parse_tfs_request,
+    parse_tfs_response, parse_wnm_sleep_mode_request, parse_wnm_sleep_mode_response,
+};
+use crate::frame::*;
+
+/// Parse the fixed fields of an action frame body.
+///
+/// Returns `None` for actions that aren't known to this library.
+pub fn parse_action_body(
+    category: ActionCategory,
+    action: u8,
+    input: &[u8],
+) -> IResult<&[u8], Option<ActionBody>> {
+    match (category, action) {
+        (ActionCategory::BlockAck, 0) => map(parse_addba_request, ActionBody::AddbaRequest)
+            .map(Some)
+            .parse(input),
+        (ActionCategory::BlockAck, 1) => map(parse_addba_response, ActionBody::AddbaResponse)
+            .map(Some)
+            .parse(input),
+        (ActionCategory::BlockAck, 2) => map(parse_delba, ActionBody::Delba).map(Some).parse(input),
+        (ActionCategory::RadioMeasurement, 0) => map(
+            parse_radio_measurement_request,
+            ActionBody::RadioMeasurementRequest,
+        )
+        .map(Some)
+        .parse(input),
+        (ActionCategory::RadioMeasurement, 1) => map(
+            parse_radio_measurement_report,
+            ActionBody::RadioMeasurementReport,
+        )
+        .map(Some)
+        .parse(input),
+        (ActionCategory::RadioMeasurement, 2) => map(
+            parse_link_measurement_request,
+            ActionBody::LinkMeasurementRequest,
+        )
+        .map(Some)
+        .parse(input),
+        (ActionCategory::RadioMeasurement, 3) => map(
+            parse_link_measurement_report,
+            ActionBody::LinkMeasurementReport,
+        )
+        .map(Some)
+        .parse(input),
+        (ActionCategory::RadioMeasurement, 4) => map(
+            parse_neighbor_report_request,
+            ActionBody::NeighborReportRequest,
+        )
+        .map(Some)
+        .parse(input),
+        (ActionCategory::RadioMeasurement, 5) => map(
+            parse_neighbor_report_response,
+            ActionBody::NeighborReportResponse,
+        )
+        .map(Some)
+        .parse(input),
+        (ActionCategory::Wnm, 6) => map(parse_bss_transition_query, ActionBody::BssTransitionQuery)
+            .map(Some)
+            .parse(input),
+        (ActionCategory::Wnm, 7) => map(
+            parse_bss_transition_request,
+            ActionBody::BssTransitionRequest,
+        )
+        .map(Some)
+        .parse(input),
+        (ActionCategory::Wnm, 8) => map(
+            parse_bss_transition_response,
+            ActionBody::BssTransitionResponse,
+        )
+        .map(Some)
+        .parse(input),
+        (ActionCategory::Wnm, 13) => map(parse_tfs_request, ActionBody::TfsRequest)
+            .map(Some)
+            .parse(input),
+        (ActionCategory::Wnm, 14) => map(parse_tfs_response, ActionBody::TfsResponse)
+            .map(Some)
+            .parse(input),
+        (ActionCategory::Wnm, 16) => map(
+            parse_wnm_sleep_mode_request,
+            ActionBody::WnmSleepModeRequest,
+        )
+        .map(Some)
+        .parse(input),
+        (ActionCategory::Wnm, 17) => map(
+            parse_wnm_sleep_mode_response,
+            ActionBody::WnmSleepModeResponse,
+        )
+        .map(Some)
+        .parse(input),
+        // Vendor specific public actions, which are distinguished by their OUI type
+        (ActionCategory::Public, 9) => alt((
+            map(parse_dpp_frame, ActionBody::Dpp),
+            map(parse_p2p_public_action, ActionBody::P2pPublicAction),
+        ))
+        .map(Some)
+        .parse(input),
+        (ActionCategory::Public, 10) => {
+            map(parse_gas_initial_request, ActionBody::GasInitialRequest)
+                .map(Some)
+                .parse(input)
+        }
+        (ActionCategory::Public, 11) => {
+            map(parse_gas_initial_response, ActionBody::GasInitialResponse)
+                .map(Some)
+                .parse(input)
+        }
+        (ActionCategory::Public, 12) => {
+            map(parse_gas_comeback_request, ActionBody::GasComebackRequest)
+                .map(Some)
+                .parse(input)
+        }
+        (ActionCategory::Public, 13) => {
+            map(parse_gas_comeback_response, ActionBody::GasComebackResponse)
+                .map(Some)
+                .parse(input)
+        }
+        (ActionCategory::Public, 14) => map(
+            parse_tdls_discovery_response,
+            ActionBody::TdlsDiscoveryResponse,
+        )
+        .map(Some)
+        .parse(input),
+        (ActionCategory::Public, 32) => map(parse_ftm_request, ActionBody::FtmRequest)
+            .map(Some)
+            .parse(input),
+        (ActionCategory::Public, 33) => map(parse_ftm, ActionBody::Ftm).map(Some).parse(input),
+        (ActionCategory::SaQuery, 0) => map(parse_sa_query_request, ActionBody::SaQueryRequest)
+            .map(Some)
+            .parse(input),
+        (ActionCategory::SaQuery, 1) => map(parse_sa_query_response, ActionBody::SaQueryResponse)
+            .map(Some)
+            .parse(input),
+        (ActionCategory::Tdls, 0) => map(parse_tdls_setup_request, ActionBody::TdlsSetupRequest)
+            .map(Some)
+            .parse(input),
+        (ActionCategory::Tdls, 1) => map(parse_tdls_setup_response, ActionBody::TdlsSetupResponse)
+            .map(Some)
+            .parse(input),
+        (ActionCategory::Tdls, 2) => map(parse_tdls_setup_confirm, ActionBody::TdlsSetupConfirm)
+            .map(Some)
+            .parse(input),
+        (ActionCategory::Tdls, 3) => map(parse_tdls_teardown, ActionBody::TdlsTeardown)
+            .map(Some)
+            .parse(input),
+        (ActionCategory::Tdls, 4) => map(
+            parse_tdls_peer_traffic_indication,
+            ActionBody::TdlsPeerTrafficIndication,
+        )
+        .map(Some)
+        .parse(input),
+        (ActionCategory::Tdls, 5) => map(
+            parse_tdls_channel_switch_request,
+            ActionBody::TdlsChannelSwitchRequest,
+        )
+        .map(Some)
+        .parse(input),
+        (ActionCategory::Tdls, 6) => map(
+            parse_tdls_channel_switch_response,
+            ActionBody::TdlsChannelSwitchResponse,
+        )
+        .map(Some)
+        .parse(input),
+        (ActionCategory::Tdls, 10) => map(
+            parse_tdls_discovery_request,
+            ActionBody::TdlsDiscoveryRequest,
+        )
+        .map(Some)
+        .parse(input),
+        (ActionCategory::Mesh, 0) => Ok((input, Some(ActionBody::LinkMetricReport))),
+        (ActionCategory::Mesh, 1) => Ok((input, Some(ActionBody::HwmpMeshPathSelection))),
+        (ActionCategory::Mesh, 2) => Ok((input, Some(ActionBody::GateAnnouncement))),
+        (ActionCategory::SelfProtected, 1) => {
+            map(parse_mesh_peering_open, ActionBody::MeshPeeringOpen)
+                .map(Some)
+                .parse(input)
+        }
+        (ActionCategory::SelfProtected, 2) => {
+            map(parse_mesh_peering_confirm, ActionBody::MeshPeeringConfirm)
+                .map(Some)
+                .parse(input)
+        }
+        (ActionCategory::SelfProtected, 3) => Ok((input, Some(ActionBody::MeshPeeringClose))),
+        (ActionCategory::SelfProtected, 4) => Ok((input, Some(ActionBody::MeshGroupKeyInform))),
+        (ActionCategory::SelfProtected, 5) => {
+            Ok((input, Some(ActionBody::MeshGroupKeyAcknowledge)))
+        }
+        // Vendor specific actions, which are distinguished by their OUI and OUI type.
+        // They have no action field, so the input starts with the OUI.
+        (ActionCategory::VendorSpecific, _) => map(parse_p2p_action, ActionBody::P2pAction)
+            .map(Some)
+            .parse(input),
+        _ => Ok((input, None)),
+    }
+}
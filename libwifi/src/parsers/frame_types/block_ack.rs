@@ -0,0 +1,66 @@
+use nom::{
+    IResult, Parser,
+    number::complete::{le_u8, le_u16},
+};
+
+use crate::frame::*;
+use crate::parsers::parse_sequence_control;
+
+/// Parse an [AddbaRequest].
+///
+/// - Dialog Token (1 byte)
+/// - Block Ack Parameter Set (2 bytes)
+/// - Block Ack Timeout Value (2 bytes)
+/// - Block Ack Starting Sequence Control (2 bytes)
+pub fn parse_addba_request(input: &[u8]) -> IResult<&[u8], AddbaRequest> {
+    let (input, (dialog_token, parameters, timeout, starting_sequence_control)) =
+        (le_u8, le_u16, le_u16, parse_sequence_control).parse(input)?;
+
+    Ok((
+        input,
+        AddbaRequest {
+            dialog_token,
+            parameters: BlockAckParameterSet::from_bits(parameters),
+            timeout,
+            starting_sequence_control,
+        },
+    ))
+}
+
+/// Parse an [AddbaResponse].
+///
+/// - Dialog Token (1 byte)
+/// - Status Code (2 bytes)
+/// - Block Ack Parameter Set (2 bytes)
+/// - Block Ack Timeout Value (2 bytes)
+pub fn parse_addba_response(input: &[u8]) -> IResult<&[u8], AddbaResponse> {
+    let (input, (dialog_token, status_code, parameters, timeout)) =
+        (le_u8, le_u16, le_u16, le_u16).parse(input)?;
+
+    Ok((
+        input,
+        AddbaResponse {
+            dialog_token,
+            status_code,
+            parameters: BlockAckParameterSet::from_bits(parameters),
+            timeout,
+        },
+    ))
+}
+
+/// Parse a [Delba].
+///
+/// - DELBA Parameter Set (2 bytes)
+/// - Reason Code (2 bytes)
+pub fn parse_delba(input: &[u8]) -> IResult<&[u8], Delba> {
+    let (input, (parameters, reason_code)) = (le_u16, le_u16).parse(input)?;
+
+    Ok((
+        input,
+        Delba {
+            initiator: parameters & (1 << 11) != 0,
+            tid: (parameters >> 12) as u8,
+            reason_code,
+        },
+    ))
+}
@@ -0,0 +1,77 @@
+use nom::{
+    IResult, Parser,
+    bytes::complete::{tag, take},
+    multi::many0,
+    number::complete::{le_u8, le_u16},
+};
+
+use crate::frame::components::WFA_OUI;
+use crate::frame::*;
+
+/// Parse a [DppFrame], which is the payload of a vendor specific public action.
+///
+/// - OUI (3 bytes, 50:6f:9a)
+/// - OUI Type (1 byte, 0x1a)
+/// - Crypto Suite (1 byte)
+/// - DPP Frame Type (1 byte)
+/// - Attributes (variable)
+pub fn parse_dpp_frame(input: &[u8]) -> IResult<&[u8], DppFrame> {
+    let (input, (_, _, crypto_suite, frame_type, attributes)) = (
+        tag(&WFA_OUI[..]),
+        tag(&[0x1a][..]),
+        le_u8,
+        le_u8,
+        many0(parse_dpp_attribute),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        DppFrame {
+            crypto_suite,
+            frame_type: DppFrameType::from(frame_type),
+            attributes,
+        },
+    ))
+}
+
+/// - Attribute ID (2 bytes)
+/// - Length (2 bytes)
+/// - Value (variable)
+fn parse_dpp_attribute(input: &[u8]) -> IResult<&[u8], DppAttribute> {
+    let (input, (id, data)) = (le_u16, le_u16.flat_map(take)).parse(input)?;
+
+    let attribute = match (id, data) {
+        (0x1000, [status]) => Some(DppAttribute::Status(DppStatus::from(*status))),
+        (0x1001, _) => Some(DppAttribute::InitiatorBootstrappingKeyHash(data.to_vec())),
+        (0x1002, _) => Some(DppAttribute::ResponderBootstrappingKeyHash(data.to_vec())),
+        (0x1003, _) => Some(DppAttribute::InitiatorProtocolKey(data.to_vec())),
+        (0x1004, _) => Some(DppAttribute::WrappedData(data.to_vec())),
+        (0x1005, _) => Some(DppAttribute::InitiatorNonce(data.to_vec())),
+        (0x1006, [bits]) => Some(DppAttribute::InitiatorCapabilities(
+            DppCapabilities::from_bits(*bits),
+        )),
+        (0x1007, _) => Some(DppAttribute::ResponderNonce(data.to_vec())),
+        (0x1008, [bits]) => Some(DppAttribute::ResponderCapabilities(
+            DppCapabilities::from_bits(*bits),
+        )),
+        (0x1009, _) => Some(DppAttribute::ResponderProtocolKey(data.to_vec())),
+        (0x100d, _) => String::from_utf8(data.to_vec())
+            .ok()
+            .map(DppAttribute::Connector),
+        (0x1018, [operating_class, channel]) => Some(DppAttribute::Channel {
+            operating_class: *operating_class,
+            channel: *channel,
+        }),
+        (0x1019, [version]) => Some(DppAttribute::ProtocolVersion(*version)),
+        _ => None,
+    };
+
+    // Keep attributes that can't be decoded losslessly as raw data.
+    let attribute = attribute.unwrap_or_else(|| DppAttribute::Unknown {
+        id,
+        data: data.to_vec(),
+    });
+
+    Ok((input, attribute))
+}
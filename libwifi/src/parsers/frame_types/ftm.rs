@@ -0,0 +1,59 @@
+use nom::{
+    IResult, Parser,
+    bytes::complete::take,
+    combinator::map,
+    number::complete::{le_u8, le_u16},
+};
+
+use crate::frame::*;
+
+/// Parse a [FtmRequest].
+///
+/// - Trigger (1 byte)
+pub fn parse_ftm_request(input: &[u8]) -> IResult<&[u8], FtmRequest> {
+    let (input, trigger) = le_u8(input)?;
+
+    Ok((input, FtmRequest { trigger }))
+}
+
+/// Parse a [Ftm] frame.
+///
+/// - Dialog Token (1 byte)
+/// - Follow Up Dialog Token (1 byte)
+/// - TOD (6 bytes)
+/// - TOA (6 bytes)
+/// - TOD Error (2 bytes)
+/// - TOA Error (2 bytes)
+pub fn parse_ftm(input: &[u8]) -> IResult<&[u8], Ftm> {
+    let (input, (dialog_token, follow_up_dialog_token, tod, toa, tod_error, toa_error)) = (
+        le_u8,
+        le_u8,
+        parse_timestamp,
+        parse_timestamp,
+        le_u16,
+        le_u16,
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        Ftm {
+            dialog_token,
+            follow_up_dialog_token,
+            tod,
+            toa,
+            tod_error: FtmError::from_bits(tod_error),
+            toa_error: FtmError::from_bits(toa_error),
+        },
+    ))
+}
+
+/// Parse a 48 bit timestamp.
+fn parse_timestamp(input: &[u8]) -> IResult<&[u8], u64> {
+    map(take(6usize), |bytes: &[u8]| {
+        let mut timestamp = [0; 8];
+        timestamp[..6].copy_from_slice(bytes);
+        u64::from_le_bytes(timestamp)
+    })
+    .parse(input)
+}
@@ -0,0 +1,103 @@
+use nom::{
+    IResult, Parser,
+    bytes::complete::{tag, take},
+    number::complete::{le_u8, le_u16},
+};
+
+use crate::frame::components::AdvertisementProtocol;
+use crate::frame::*;
+use crate::parsers::parse_advertisement_protocol;
+
+/// Parse a [GasInitialRequest].
+///
+/// - Dialog Token (1 byte)
+/// - Advertisement Protocol element (variable)
+/// - Query Request Length (2 bytes)
+/// - Query Request (variable)
+pub fn parse_gas_initial_request(input: &[u8]) -> IResult<&[u8], GasInitialRequest> {
+    let (input, (dialog_token, (advertisement_protocol, query))) =
+        (le_u8, parse_query).parse(input)?;
+
+    Ok((
+        input,
+        GasInitialRequest {
+            dialog_token,
+            advertisement_protocol,
+            query,
+        },
+    ))
+}
+
+/// Parse a [GasInitialResponse].
+///
+/// - Dialog Token (1 byte)
+/// - Status Code (2 bytes)
+/// - GAS Comeback Delay (2 bytes)
+/// - Advertisement Protocol element (variable)
+/// - Query Response Length (2 bytes)
+/// - Query Response (variable)
+pub fn parse_gas_initial_response(input: &[u8]) -> IResult<&[u8], GasInitialResponse> {
+    let (input, (dialog_token, status_code, comeback_delay, (advertisement_protocol, query))) =
+        (le_u8, le_u16, le_u16, parse_query).parse(input)?;
+
+    Ok((
+        input,
+        GasInitialResponse {
+            dialog_token,
+            status_code,
+            comeback_delay,
+            advertisement_protocol,
+            query_response: query,
+        },
+    ))
+}
+
+/// Parse a [GasComebackRequest].
+///
+/// - Dialog Token (1 byte)
+pub fn parse_gas_comeback_request(input: &[u8]) -> IResult<&[u8], GasComebackRequest> {
+    let (input, dialog_token) = le_u8(input)?;
+
+    Ok((input, GasComebackRequest { dialog_token }))
+}
+
+/// Parse a [GasComebackResponse].
+///
+/// - Dialog Token (1 byte)
+/// - Status Code (2 bytes)
+/// - GAS Query Response Fragment ID (1 byte)
+/// - GAS Comeback Delay (2 bytes)
+/// - Advertisement Protocol element (variable)
+/// - Query Response Length (2 bytes)
+/// - Query Response (variable)
+pub fn parse_gas_comeback_response(input: &[u8]) -> IResult<&[u8], GasComebackResponse> {
+    let (
+        input,
+        (dialog_token, status_code, fragment_id, comeback_delay, (advertisement_protocol, query)),
+    ) = (le_u8, le_u16, le_u8, le_u16, parse_query).parse(input)?;
+
+    Ok((
+        input,
+        GasComebackResponse {
+            dialog_token,
+            status_code,
+            fragment_id: fragment_id & 0x7f,
+            more_fragments: fragment_id & 0x80 != 0,
+            comeback_delay,
+            advertisement_protocol,
+            query_response: query,
+        },
+    ))
+}
+
+/// Parse the Advertisement Protocol element, followed by the length prefixed query.
+fn parse_query(input: &[u8]) -> IResult<&[u8], (AdvertisementProtocol, Vec<u8>)> {
+    let (input, (_, advertisement_protocol, query)) = (
+        tag(&[108][..]),
+        le_u8.flat_map(take).and_then(parse_advertisement_protocol),
+        le_u16.flat_map(take),
+    )
+        .parse(input)?;
+
+    Ok((input, (advertisement_protocol, query.to_vec())))
+}
@@ -1,11 +1,15 @@
 use nom::Parser;
 use nom::bytes::complete::take;
+use nom::combinator::peek;
 use nom::number::complete::{le_u8, le_u16, le_u64};
 
 use crate::error::Error;
-use crate::frame::components::FrameControl;
+use crate::frame::components::{FrameControl, StationInfo};
 use crate::frame::*;
-use crate::parsers::{parse_mac, parse_management_header, parse_station_info};
+use crate::parsers::{
+    ElementRegistry, parse_action_body, parse_mac, parse_management_header,
+    parse_mesh_peering_close, parse_station_info_with,
+};
 
 /// Parse an [AssociationRequest] frame.
 ///
@@ -17,10 +21,13 @@ use crate::parsers::{parse_mac, parse_management_header, parse_station_info};
 pub fn parse_association_request(
     frame_control: FrameControl,
     input: &[u8],
+    registry: &ElementRegistry,
 ) -> Result<Frame, Error> {
     let (input, header) = parse_management_header(frame_control, input)?;
-    let (_, (beacon_interval, capability_info, station_info)) =
-        (le_u16, le_u16, parse_station_info).parse(input)?;
+    let (_, (beacon_interval, capability_info, station_info)) = (le_u16, le_u16, |input| {
+        parse_station_info_with(input, registry)
+    })
+        .parse(input)?;
 
     Ok(Frame::AssociationRequest(AssociationRequest {
         header,
@@ -41,6 +48,7 @@ pub fn parse_association_request(
 pub fn parse_authentication_frame(
     frame_control: FrameControl,
     input: &[u8],
+    registry: &ElementRegistry,
 ) -> Result<Frame, Error> {
     let (input, header) = parse_management_header(frame_control, input)?;
 
@@ -62,7 +70,7 @@ pub fn parse_authentication_frame(
     } else {
         // Parse station info (extended capabilities) if present
         if !input.is_empty()
-            && let Ok((_input, info)) = parse_station_info(input)
+            && let Ok((_input, info)) = parse_station_info_with(input, registry)
         {
             station_info = Some(info);
         }
@@ -109,10 +117,14 @@ pub fn parse_deauthentication_frame(
 pub fn parse_association_response(
     frame_control: FrameControl,
     input: &[u8],
+    registry: &ElementRegistry,
 ) -> Result<Frame, Error> {
     let (input, header) = parse_management_header(frame_control, input)?;
     let (_, (capability_info, status_code, association_id, station_info)) =
-        (le_u16, le_u16, le_u16, parse_station_info).parse(input)?;
+        (le_u16, le_u16, le_u16, |input| {
+            parse_station_info_with(input, registry)
+        })
+            .parse(input)?;
 
     Ok(Frame::AssociationResponse(AssociationResponse {
         header,
@@ -134,12 +146,13 @@ pub fn parse_association_response(
 pub fn parse_reassociation_request(
     frame_control: FrameControl,
     input: &[u8],
+    registry: &ElementRegistry,
 ) -> Result<Frame, Error> {
     let (input, header) = parse_management_header(frame_control, input)?;
     let (input, (capability_info, listen_interval)) = (le_u16, le_u16).parse(input)?;
 
     let (input, current_ap_address) = parse_mac(input)?;
-    let (_, station_info) = parse_station_info(input)?;
+    let (_, station_info) = parse_station_info_with(input, registry)?;
 
     Ok(Frame::ReassociationRequest(ReassociationRequest {
         header,
@@ -160,11 +173,12 @@ pub fn parse_reassociation_request(
 pub fn parse_reassociation_response(
     frame_control: FrameControl,
     input: &[u8],
+    registry: &ElementRegistry,
 ) -> Result<Frame, Error> {
     let (input, header) = parse_management_header(frame_control, input)?;
     let (_, (capability_info, status_code, association_id)) =
         (le_u16, le_u16, le_u16).parse(input)?;
-    let (_input, station_info) = parse_station_info(input)?;
+    let (_input, station_info) = parse_station_info_with(input, registry)?;
 
     Ok(Frame::ReassociationResponse(ReassociationResponse {
         header,
@@ -182,11 +196,18 @@ pub fn parse_reassociation_response(
 /// - Beacon interval
 /// - Capability info
 /// - Dynamic fields
-pub fn parse_beacon(frame_control: FrameControl, input: &[u8]) -> Result<Frame, Error> {
+pub fn parse_beacon(
+    frame_control: FrameControl,
+    input: &[u8],
+    registry: &ElementRegistry,
+) -> Result<Frame, Error> {
     let (input, header) = parse_management_header(frame_control, input)?;
 
     let (_, (timestamp, beacon_interval, capability_info, station_info)) =
-        (le_u64, le_u16, le_u16, parse_station_info).parse(input)?;
+        (le_u64, le_u16, le_u16, |input| {
+            parse_station_info_with(input, registry)
+        })
+            .parse(input)?;
 
     Ok(Frame::Beacon(Beacon {
         header,
@@ -202,9 +223,13 @@ pub fn parse_beacon(frame_control: FrameControl, input: &[u8]) -> Result<Frame,
 /// The general structure is:
 /// - ManagementHeader
 /// - Dynamic fields
-pub fn parse_probe_request(frame_control: FrameControl, input: &[u8]) -> Result<Frame, Error> {
+pub fn parse_probe_request(
+    frame_control: FrameControl,
+    input: &[u8],
+    registry: &ElementRegistry,
+) -> Result<Frame, Error> {
     let (input, header) = parse_management_header(frame_control, input)?;
-    let (_, station_info) = parse_station_info(input)?;
+    let (_, station_info) = parse_station_info_with(input, registry)?;
 
     Ok(Frame::ProbeRequest(ProbeRequest {
         header,
@@ -219,10 +244,17 @@ pub fn parse_probe_request(frame_control: FrameControl, input: &[u8]) -> Result<
 /// - Beacon interval
 /// - Capability info
 /// - Dynamic fields
-pub fn parse_probe_response(frame_control: FrameControl, input: &[u8]) -> Result<Frame, Error> {
+pub fn parse_probe_response(
+    frame_control: FrameControl,
+    input: &[u8],
+    registry: &ElementRegistry,
+) -> Result<Frame, Error> {
     let (input, header) = parse_management_header(frame_control, input)?;
     let (_, (timestamp, beacon_interval, capability_info, station_info)) =
-        (le_u64, le_u16, le_u16, parse_station_info).parse(input)?;
+        (le_u64, le_u16, le_u16, |input| {
+            parse_station_info_with(input, registry)
+        })
+            .parse(input)?;
 
     Ok(Frame::ProbeResponse(ProbeResponse {
         header,
@@ -239,27 +271,56 @@ pub fn parse_probe_response(frame_control: FrameControl, input: &[u8]) -> Result
 /// - ManagementHeader
 /// - Category (indicating the type of action, e.g., spectrum management, QoS)
 /// - Action (specific action within the category)
-/// - Dynamic fields (vary depending on the category and action)
-pub fn parse_action(frame_control: FrameControl, input: &[u8]) -> Result<Frame, Error> {
+/// - Fixed fields (vary depending on the category and action)
+/// - Dynamic fields
+pub fn parse_action(
+    frame_control: FrameControl,
+    input: &[u8],
+    registry: &ElementRegistry,
+) -> Result<Frame, Error> {
     let (input, header) = parse_management_header(frame_control, input)?;
 
     // Parsing the category field (1 byte)
     let (input, category) = le_u8(input)?;
+    let category = ActionCategory::from(category);
+
+    // The encrypted body of protected frames is kept as is.
+    if header.frame_control.protected() {
+        let (input, action) = le_u8(input)?;
+        return Ok(Frame::Action(Action {
+            header,
+            category,
+            action,
+            body: Some(ActionBody::Encrypted(input.to_vec())),
+            station_info: StationInfo::default(),
+        }));
+    }
 
     // Parsing the action field (1 byte)
-    let (input, action) = le_u8(input)?;
+    // Vendor specific actions have no action field, the first byte of their OUI is used instead
+    // and left in the input.
+    let (input, action) = if category.is_vendor_specific() {
+        peek(le_u8).parse(input)?
+    } else {
+        le_u8(input)?
+    };
 
-    // Parsing the dynamic fields (depends on category and action)
-    let (_, station_info) = parse_station_info(input)?;
+    // Parsing the fixed fields (depends on category and action)
+    // Malformed fixed fields are kept as raw elements.
+    let (input, body) = parse_action_body(category, action, input).unwrap_or((input, None));
 
-    // Assuming `StationInfo` is part of dynamic fields and its parsing
-    // is handled inside `parse_dynamic_fields`
-    // ...
+    // Parsing the dynamic fields, which are optional
+    let station_info = match body {
+        _ if input.is_empty() => StationInfo::default(),
+        Some(ActionBody::MeshPeeringClose) => parse_mesh_peering_close(input, registry)?.1,
+        _ => parse_station_info_with(input, registry)?.1,
+    };
 
     Ok(Frame::Action(Action {
         header,
-        category: category.into(), // Convert to enum variant if needed
-        action,                    // Convert to enum variant if needed
-        station_info,              // Assuming this comes from dynamic fields
+        category,
+        action,
+        body,
+        station_info,
     }))
 }
@@ -0,0 +1,53 @@
+use nom::{IResult, Parser, combinator::map, number::complete::le_u16};
+
+use crate::frame::components::StationInfo;
+use crate::frame::*;
+use crate::parsers::{
+    ElementRegistry, IeIter, parse_mesh_peering_close_management, parse_station_info_with,
+};
+
+/// Parse a [MeshPeeringOpen].
+///
+/// - Capability (2 bytes)
+pub fn parse_mesh_peering_open(input: &[u8]) -> IResult<&[u8], MeshPeeringOpen> {
+    map(le_u16, |capability_info| MeshPeeringOpen {
+        capability_info,
+    })
+    .parse(input)
+}
+
+/// Parse a [MeshPeeringConfirm].
+///
+/// - Capability (2 bytes)
+/// - AID (2 bytes)
+pub fn parse_mesh_peering_confirm(input: &[u8]) -> IResult<&[u8], MeshPeeringConfirm> {
+    map((le_u16, le_u16), |(capability_info, aid)| {
+        MeshPeeringConfirm {
+            capability_info,
+            aid,
+        }
+    })
+    .parse(input)
+}
+
+/// Parse the elements of a Mesh Peering Close frame, which has no fixed fields.
+///
+/// Its Mesh Peering Management element always contains a reason code, see
+/// [parse_mesh_peering_close_management].
+pub fn parse_mesh_peering_close<'a>(
+    input: &'a [u8],
+    registry: &ElementRegistry,
+) -> IResult<&'a [u8], StationInfo> {
+    let (remainder, mut station_info) = parse_station_info_with(input, registry)?;
+
+    let management = IeIter::new(input)
+        .filter(|(element_id, _, _)| *element_id == 117)
+        .filter_map(|(_, _, data)| parse_mesh_peering_close_management(data).ok())
+        .map(|(_, management)| management)
+        .last();
+    if management.is_some() {
+        station_info.mesh_peering_management = management;
+    }
+
+    Ok((remainder, station_info))
+}
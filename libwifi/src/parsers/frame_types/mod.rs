@@ -1,7 +1,29 @@
+mod action;
+mod block_ack;
 mod control;
 mod data;
+mod dpp;
+mod ftm;
+mod gas;
 mod management;
+mod mesh;
+mod p2p;
+mod radio_measurement;
+mod sa_query;
+mod tdls;
+mod wnm;
 
+pub use action::*;
+pub use block_ack::*;
 pub use control::*;
 pub use data::*;
+pub use dpp::*;
+pub use ftm::*;
+pub use gas::*;
 pub use management::*;
+pub use mesh::*;
+pub use p2p::*;
+pub use radio_measurement::*;
+pub use sa_query::*;
+pub use tdls::*;
+pub use wnm::*;
@@ -0,0 +1,42 @@
+use nom::{IResult, Parser, bytes::complete::tag, number::complete::le_u8};
+
+use crate::frame::components::WFA_OUI;
+use crate::frame::*;
+
+/// Parse the fixed fields of a [P2pPublicAction].
+///
+/// - OUI (3 bytes, 50:6f:9a)
+/// - OUI Type (1 byte, 0x09)
+/// - OUI Subtype (1 byte)
+/// - Dialog Token (1 byte)
+pub fn parse_p2p_public_action(input: &[u8]) -> IResult<&[u8], P2pPublicAction> {
+    let (input, (_, _, subtype, dialog_token)) =
+        (tag(&WFA_OUI[..]), tag(&[0x09][..]), le_u8, le_u8).parse(input)?;
+
+    Ok((
+        input,
+        P2pPublicAction {
+            subtype: P2pPublicActionType::from(subtype),
+            dialog_token,
+        },
+    ))
+}
+
+/// Parse the fixed fields of a [P2pAction].
+///
+/// - OUI (3 bytes, 50:6f:9a)
+/// - OUI Type (1 byte, 0x09)
+/// - OUI Subtype (1 byte)
+/// - Dialog Token (1 byte)
+pub fn parse_p2p_action(input: &[u8]) -> IResult<&[u8], P2pAction> {
+    let (input, (_, _, subtype, dialog_token)) =
+        (tag(&WFA_OUI[..]), tag(&[0x09][..]), le_u8, le_u8).parse(input)?;
+
+    Ok((
+        input,
+        P2pAction {
+            subtype: P2pActionType::from(subtype),
+            dialog_token,
+        },
+    ))
+}
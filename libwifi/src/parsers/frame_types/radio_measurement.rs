@@ -0,0 +1,97 @@
+use nom::{
+    IResult, Parser,
+    bytes::complete::tag,
+    number::complete::{le_i8, le_u8, le_u16},
+};
+
+use crate::frame::*;
+use crate::parsers::parse_tpc_report;
+
+/// Parse a [RadioMeasurementRequest].
+///
+/// - Dialog Token (1 byte)
+/// - Number of Repetitions (2 bytes)
+pub fn parse_radio_measurement_request(input: &[u8]) -> IResult<&[u8], RadioMeasurementRequest> {
+    let (input, (dialog_token, repetitions)) = (le_u8, le_u16).parse(input)?;
+
+    Ok((
+        input,
+        RadioMeasurementRequest {
+            dialog_token,
+            repetitions,
+        },
+    ))
+}
+
+/// Parse a [RadioMeasurementReport].
+///
+/// - Dialog Token (1 byte)
+pub fn parse_radio_measurement_report(input: &[u8]) -> IResult<&[u8], RadioMeasurementReport> {
+    let (input, dialog_token) = le_u8(input)?;
+
+    Ok((input, RadioMeasurementReport { dialog_token }))
+}
+
+/// Parse a [LinkMeasurementRequest].
+///
+/// - Dialog Token (1 byte)
+/// - Transmit Power Used (1 byte)
+/// - Max Transmit Power (1 byte)
+pub fn parse_link_measurement_request(input: &[u8]) -> IResult<&[u8], LinkMeasurementRequest> {
+    let (input, (dialog_token, transmit_power, max_transmit_power)) =
+        (le_u8, le_i8, le_i8).parse(input)?;
+
+    Ok((
+        input,
+        LinkMeasurementRequest {
+            dialog_token,
+            transmit_power,
+            max_transmit_power,
+        },
+    ))
+}
+
+/// Parse a [LinkMeasurementReport].
+///
+/// - Dialog Token (1 byte)
+/// - TPC Report element (4 bytes)
+/// - Receive Antenna ID (1 byte)
+/// - Transmit Antenna ID (1 byte)
+/// - RCPI (1 byte)
+/// - RSNI (1 byte)
+pub fn parse_link_measurement_report(input: &[u8]) -> IResult<&[u8], LinkMeasurementReport> {
+    let (input, (dialog_token, _, _, tpc_report)) =
+        (le_u8, tag(&[35u8][..]), tag(&[2u8][..]), parse_tpc_report).parse(input)?;
+    let (input, (receive_antenna_id, transmit_antenna_id, rcpi, rsni)) =
+        (le_u8, le_u8, le_u8, le_u8).parse(input)?;
+
+    Ok((
+        input,
+        LinkMeasurementReport {
+            dialog_token,
+            tpc_report,
+            receive_antenna_id,
+            transmit_antenna_id,
+            rcpi,
+            rsni,
+        },
+    ))
+}
+
+/// Parse a [NeighborReportRequest].
+///
+/// - Dialog Token (1 byte)
+pub fn parse_neighbor_report_request(input: &[u8]) -> IResult<&[u8], NeighborReportRequest> {
+    let (input, dialog_token) = le_u8(input)?;
+
+    Ok((input, NeighborReportRequest { dialog_token }))
+}
+
+/// Parse a [NeighborReportResponse].
+///
+/// - Dialog Token (1 byte)
+pub fn parse_neighbor_report_response(input: &[u8]) -> IResult<&[u8], NeighborReportResponse> {
+    let (input, dialog_token) = le_u8(input)?;
+
+    Ok((input, NeighborReportResponse { dialog_token }))
+}
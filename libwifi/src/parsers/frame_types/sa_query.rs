@@ -0,0 +1,17 @@
+use nom::{IResult, Parser, combinator::map, number::complete::le_u16};
+
+use crate::frame::*;
+
+/// Parse a [SaQueryRequest].
+///
+/// - Transaction Identifier (2 bytes)
+pub fn parse_sa_query_request(input: &[u8]) -> IResult<&[u8], SaQueryRequest> {
+    map(le_u16, |transaction_id| SaQueryRequest { transaction_id }).parse(input)
+}
+
+/// Parse a [SaQueryResponse].
+///
+/// - Transaction Identifier (2 bytes)
+pub fn parse_sa_query_response(input: &[u8]) -> IResult<&[u8], SaQueryResponse> {
+    map(le_u16, |transaction_id| SaQueryResponse { transaction_id }).parse(input)
+}
@@ -0,0 +1,155 @@
+use nom::{
+    IResult, Parser,
+    bytes::complete::tag,
+    combinator::{cond, map},
+    number::complete::{le_u8, le_u16},
+};
+
+use super::parse_action_body;
+use crate::frame::components::StationInfo;
+use crate::frame::*;
+use crate::parsers::parse_station_info;
+
+/// Parse a [TdlsFrame], which is encapsulated in the payload of a data frame.
+///
+/// - LLC/SNAP header (8 bytes, EtherType 0x890d)
+/// - Payload Type (1 byte, 2 for TDLS)
+/// - Category (1 byte, 12 for TDLS)
+/// - Action (1 byte)
+/// - Fixed fields (depends on the action)
+/// - Elements (variable)
+pub fn parse_tdls_frame(input: &[u8]) -> IResult<&[u8], TdlsFrame> {
+    let (input, (_, _, action)) =
+        (tag(&TDLS_LLC_HEADER[..]), tag(&[2, 12][..]), le_u8).parse(input)?;
+
+    let (input, body) =
+        parse_action_body(ActionCategory::Tdls, action, input).unwrap_or((input, None));
+
+    let (input, station_info) = if input.is_empty() {
+        (input, StationInfo::default())
+    } else {
+        parse_station_info(input)?
+    };
+
+    Ok((
+        input,
+        TdlsFrame {
+            action,
+            body,
+            station_info,
+        },
+    ))
+}
+
+/// Parse a [TdlsSetupRequest].
+///
+/// - Dialog Token (1 byte)
+/// - Capability (2 bytes)
+pub fn parse_tdls_setup_request(input: &[u8]) -> IResult<&[u8], TdlsSetupRequest> {
+    map((le_u8, le_u16), |(dialog_token, capability_info)| {
+        TdlsSetupRequest {
+            dialog_token,
+            capability_info,
+        }
+    })
+    .parse(input)
+}
+
+/// Parse a [TdlsSetupResponse].
+///
+/// - Status Code (2 bytes)
+/// - Dialog Token (1 byte)
+/// - Capability (2 bytes, only if the status code is `0`)
+pub fn parse_tdls_setup_response(input: &[u8]) -> IResult<&[u8], TdlsSetupResponse> {
+    let (input, (status_code, dialog_token)) = (le_u16, le_u8).parse(input)?;
+    let (input, capability_info) = cond(status_code == 0, le_u16).parse(input)?;
+
+    Ok((
+        input,
+        TdlsSetupResponse {
+            status_code,
+            dialog_token,
+            capability_info,
+        },
+    ))
+}
+
+/// Parse a [TdlsSetupConfirm].
+///
+/// - Status Code (2 bytes)
+/// - Dialog Token (1 byte)
+pub fn parse_tdls_setup_confirm(input: &[u8]) -> IResult<&[u8], TdlsSetupConfirm> {
+    map((le_u16, le_u8), |(status_code, dialog_token)| {
+        TdlsSetupConfirm {
+            status_code,
+            dialog_token,
+        }
+    })
+    .parse(input)
+}
+
+/// Parse a [TdlsTeardown].
+///
+/// - Reason Code (2 bytes)
+pub fn parse_tdls_teardown(input: &[u8]) -> IResult<&[u8], TdlsTeardown> {
+    map(le_u16, |reason_code| TdlsTeardown { reason_code }).parse(input)
+}
+
+/// Parse a [TdlsPeerTrafficIndication].
+///
+/// - Dialog Token (1 byte)
+pub fn parse_tdls_peer_traffic_indication(
+    input: &[u8],
+) -> IResult<&[u8], TdlsPeerTrafficIndication> {
+    map(le_u8, |dialog_token| TdlsPeerTrafficIndication {
+        dialog_token,
+    })
+    .parse(input)
+}
+
+/// Parse a [TdlsChannelSwitchRequest].
+///
+/// - Target Channel (1 byte)
+/// - Operating Class (1 byte)
+pub fn parse_tdls_channel_switch_request(input: &[u8]) -> IResult<&[u8], TdlsChannelSwitchRequest> {
+    map((le_u8, le_u8), |(target_channel, operating_class)| {
+        TdlsChannelSwitchRequest {
+            target_channel,
+            operating_class,
+        }
+    })
+    .parse(input)
+}
+
+/// Parse a [TdlsChannelSwitchResponse].
+///
+/// - Status Code (2 bytes)
+pub fn parse_tdls_channel_switch_response(
+    input: &[u8],
+) -> IResult<&[u8], TdlsChannelSwitchResponse> {
+    map(le_u16, |status_code| TdlsChannelSwitchResponse {
+        status_code,
+    })
+    .parse(input)
+}
+
+/// Parse a [TdlsDiscoveryRequest].
+///
+/// - Dialog Token (1 byte)
+pub fn parse_tdls_discovery_request(input: &[u8]) -> IResult<&[u8], TdlsDiscoveryRequest> {
+    map(le_u8, |dialog_token| TdlsDiscoveryRequest { dialog_token }).parse(input)
+}
+
+/// Parse a [TdlsDiscoveryResponse].
+///
+/// - Dialog Token (1 byte)
+/// - Capability (2 bytes)
+pub fn parse_tdls_discovery_response(input: &[u8]) -> IResult<&[u8], TdlsDiscoveryResponse> {
+    map((le_u8, le_u16), |(dialog_token, capability_info)| {
+        TdlsDiscoveryResponse {
+            dialog_token,
+            capability_info,
+        }
+    })
+    .parse(input)
+}
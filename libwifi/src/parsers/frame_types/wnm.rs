@@ -0,0 +1,131 @@
+use nom::{
+    IResult, Parser,
+    bytes::complete::{tag, take},
+    combinator::cond,
+    number::complete::{le_u8, le_u16},
+};
+
+use crate::frame::*;
+use crate::parsers::{parse_bss_termination_duration, parse_mac};
+
+/// Parse a [BssTransitionQuery].
+///
+/// - Dialog Token (1 byte)
+/// - BSS Transition Query Reason (1 byte)
+pub fn parse_bss_transition_query(input: &[u8]) -> IResult<&[u8], BssTransitionQuery> {
+    let (input, (dialog_token, reason)) = (le_u8, le_u8).parse(input)?;
+
+    Ok((
+        input,
+        BssTransitionQuery {
+            dialog_token,
+            reason,
+        },
+    ))
+}
+
+/// Parse a [BssTransitionRequest].
+///
+/// - Dialog Token (1 byte)
+/// - Request Mode (1 byte)
+/// - Disassociation Timer (2 bytes)
+/// - Validity Interval (1 byte)
+/// - BSS Termination Duration subelement (12 bytes, optional)
+/// - Session Information URL length (1 byte, optional) and URL (variable)
+pub fn parse_bss_transition_request(input: &[u8]) -> IResult<&[u8], BssTransitionRequest> {
+    let (input, (dialog_token, request_mode, disassociation_timer, validity_interval)) =
+        (le_u8, le_u8, le_u16, le_u8).parse(input)?;
+    let request_mode = BtmRequestMode::from_bits(request_mode);
+
+    let (input, (bss_termination_duration, session_information_url)) = (
+        cond(
+            request_mode.bss_termination_included,
+            (tag(&[4u8, 10][..]), parse_bss_termination_duration).map(|(_, duration)| duration),
+        ),
+        cond(
+            request_mode.ess_disassociation_imminent,
+            le_u8.flat_map(take).map(|url: &[u8]| url.to_vec()),
+        ),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        BssTransitionRequest {
+            dialog_token,
+            request_mode,
+            disassociation_timer,
+            validity_interval,
+            bss_termination_duration,
+            session_information_url,
+        },
+    ))
+}
+
+/// Parse a [BssTransitionResponse].
+///
+/// - Dialog Token (1 byte)
+/// - Status Code (1 byte)
+/// - BSS Termination Delay (1 byte)
+/// - Target BSSID (6 bytes, only if the status code is accept)
+pub fn parse_bss_transition_response(input: &[u8]) -> IResult<&[u8], BssTransitionResponse> {
+    let (input, (dialog_token, status_code, bss_termination_delay)) =
+        (le_u8, le_u8, le_u8).parse(input)?;
+    let status_code = BtmStatusCode::from(status_code);
+    let (input, target_bssid) =
+        cond(status_code == BtmStatusCode::Accept, parse_mac).parse(input)?;
+
+    Ok((
+        input,
+        BssTransitionResponse {
+            dialog_token,
+            status_code,
+            bss_termination_delay,
+            target_bssid,
+        },
+    ))
+}
+
+/// Parse a [WnmSleepModeRequest].
+///
+/// - Dialog Token (1 byte)
+pub fn parse_wnm_sleep_mode_request(input: &[u8]) -> IResult<&[u8], WnmSleepModeRequest> {
+    let (input, dialog_token) = le_u8(input)?;
+
+    Ok((input, WnmSleepModeRequest { dialog_token }))
+}
+
+/// Parse a [WnmSleepModeResponse].
+///
+/// - Dialog Token (1 byte)
+/// - Key Data Length (2 bytes)
+/// - Key Data (variable)
+pub fn parse_wnm_sleep_mode_response(input: &[u8]) -> IResult<&[u8], WnmSleepModeResponse> {
+    let (input, (dialog_token, key_data)) = (le_u8, le_u16.flat_map(take)).parse(input)?;
+
+    Ok((
+        input,
+        WnmSleepModeResponse {
+            dialog_token,
+            key_data: key_data.to_vec(),
+        },
+    ))
+}
+
+/// Parse a [TfsRequest].
+///
+/// - Dialog Token (1 byte)
+pub fn parse_tfs_request(input: &[u8]) -> IResult<&[u8], TfsRequest> {
+    let (input, dialog_token) = le_u8(input)?;
+
+    Ok((input, TfsRequest { dialog_token }))
+}
+
+/// Parse a [TfsResponse].
+///
+/// - Dialog Token (1 byte)
+pub fn parse_tfs_response(input: &[u8]) -> IResult<&[u8], TfsResponse> {
+    let (input, dialog_token) = le_u8(input)?;
+
+    Ok((input, TfsResponse { dialog_token }))
+}
@@ -0,0 +1,1134 @@
+use libwifi::frame::components::*;
+use libwifi::frame::*;
+use libwifi::parse_frame;
+use libwifi::parsers::{parse_mesh_peering_close_management, parse_mesh_peering_management};
+
+const HEADER: [u8; 24] = [
+    208, 0, // FrameControl
+    0, 0, // Duration id
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // First address
+    0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, // Second address
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // Third address
+    0x10, 0x00, // SequenceControl
+];
+
+fn parse_action(body: &[u8]) -> Action {
+    let payload = [&HEADER[..], body].concat();
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    let Frame::Action(action) = frame else {
+        panic!("Expected an action frame");
+    };
+    assert_eq!(action.encode(), payload);
+
+    action
+}
+
+#[test]
+fn test_block_ack_actions() {
+    let action = parse_action(&[
+        3, 0, // Block Ack, ADDBA Request
+        1, // Dialog token
+        0x17, 0x10, // A-MSDU, immediate, TID 5, 64 buffers
+        0, 0, // Timeout
+        0x40, 0x06, // Starting sequence number 100
+        159, 1, 0x00, // ADDBA Extension
+    ]);
+    assert_eq!(action.category, ActionCategory::BlockAck);
+    let Some(ActionBody::AddbaRequest(request)) = action.body else {
+        panic!("Expected an ADDBA Request");
+    };
+    assert_eq!(request.dialog_token, 1);
+    assert_eq!(
+        request.parameters,
+        BlockAckParameterSet {
+            amsdu_supported: true,
+            policy: BlockAckPolicy::Immediate,
+            tid: 5,
+            buffer_size: 64,
+        }
+    );
+    assert_eq!(request.timeout, 0);
+    assert_eq!(request.starting_sequence_control.sequence_number, 100);
+    assert_eq!(action.station_info.data, vec![(159, vec![0x00])]);
+
+    let action = parse_action(&[
+        3, 1, // Block Ack, ADDBA Response
+        1, // Dialog token
+        0, 0, // Status code
+        0x16, 0x10, // Immediate, TID 5, 64 buffers
+        0x10, 0x27, // Timeout
+    ]);
+    let Some(ActionBody::AddbaResponse(response)) = action.body else {
+        panic!("Expected an ADDBA Response");
+    };
+    assert_eq!(response.status_code, 0);
+    assert!(!response.parameters.amsdu_supported);
+    assert_eq!(response.timeout, 10000);
+
+    let action = parse_action(&[
+        3, 2, // Block Ack, DELBA
+        0x00, 0x58, // Initiator, TID 5
+        37, 0, // Reason code
+    ]);
+    let Some(ActionBody::Delba(delba)) = action.body else {
+        panic!("Expected a DELBA");
+    };
+    assert_eq!(
+        delba,
+        Delba {
+            initiator: true,
+            tid: 5,
+            reason_code: 37,
+        }
+    );
+}
+
+#[test]
+fn test_radio_measurement_actions() {
+    let action = parse_action(&[
+        5, 0, // Radio Measurement, Radio Measurement Request
+        7, // Dialog token
+        0, 0, // Repetitions
+        // Measurement Request: Beacon, active, channel 36, wildcard BSSID
+        38, 24, 1, 0, 5, 115, 36, 0, 0, 100, 0, 1, 255, 255, 255, 255, 255, 255, //
+        0, 3, b'a', b'b', b'c', // SSID subelement
+        2, 1, 1, // Reporting Detail subelement
+    ]);
+    assert_eq!(action.category, ActionCategory::RadioMeasurement);
+    let Some(ActionBody::RadioMeasurementRequest(request)) = &action.body else {
+        panic!("Expected a Radio Measurement Request");
+    };
+    assert_eq!(request.dialog_token, 7);
+    let measurement = &action.station_info.measurement_requests[0];
+    assert_eq!(measurement.token, 1);
+    let MeasurementRequestType::Beacon(beacon_request) = &measurement.request else {
+        panic!("Expected a Beacon Request");
+    };
+    assert_eq!(beacon_request.channel, 36);
+    assert_eq!(beacon_request.measurement_duration, 100);
+    assert_eq!(
+        beacon_request.measurement_mode,
+        BeaconMeasurementMode::Active
+    );
+    assert_eq!(beacon_request.bssid, MacAddress::broadcast());
+    assert_eq!(beacon_request.ssid(), Some(&b"abc"[..]));
+    assert_eq!(beacon_request.reporting_detail(), Some(1));
+    assert_eq!(action.station_info.encode(), &action.encode()[29..]);
+
+    let action = parse_action(&[
+        5, 1, // Radio Measurement, Radio Measurement Report
+        7, // Dialog token
+        // Measurement Report: Beacon
+        39, 29, 1, 0, 5, 115, 36, 1, 2, 3, 4, 5, 6, 7, 8, 100, 0, 0x08, 100, 50, //
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 1, 0x10, 0x20, 0x30, 0x40, //
+        // Measurement Report: Channel Load, refused
+        39, 3, 2, 0b100, 3,
+    ]);
+    let reports = &action.station_info.measurement_reports;
+    assert_eq!(reports.len(), 2);
+    let MeasurementReportType::Beacon(beacon_report) = &reports[0].report else {
+        panic!("Expected a Beacon Report");
+    };
+    assert_eq!(
+        beacon_report.actual_measurement_start_time,
+        0x0807060504030201
+    );
+    assert_eq!(beacon_report.rcpi_dbm(), Some(-60.0));
+    assert_eq!(
+        beacon_report.bssid,
+        MacAddress([0x00, 0x11, 0x22, 0x33, 0x44, 0x55])
+    );
+    assert_eq!(beacon_report.parent_tsf, 0x40302010);
+    assert!(reports[1].mode.refused);
+    assert_eq!(
+        reports[1].report,
+        MeasurementReportType::Unknown {
+            measurement_type: 3,
+            data: vec![]
+        }
+    );
+    assert_eq!(action.station_info.encode(), &action.encode()[27..]);
+
+    let action = parse_action(&[
+        5, 3, // Radio Measurement, Link Measurement Report
+        1, // Dialog token
+        35, 2, 10, 5, // TPC Report
+        1, 2, 120, 40, // Antenna IDs, RCPI, RSNI
+    ]);
+    let Some(ActionBody::LinkMeasurementReport(report)) = action.body else {
+        panic!("Expected a Link Measurement Report");
+    };
+    assert_eq!(report.tpc_report.tx_power, 10);
+    assert_eq!(report.tpc_report.link_margin, 5);
+    assert_eq!(report.rcpi, 120);
+    assert_eq!(report.rsni, 40);
+
+    let action = parse_action(&[
+        5, 5, // Radio Measurement, Neighbor Report Response
+        3, // Dialog token
+        // Neighbor Report
+        52, 16, 0x00, 0x11, 0x22, 0x33, 0x44, 0x66, 0x8f, 0x08, 0x00, 0x00, 81, 6, 7, //
+        3, 1, 255, // BSS Transition Candidate Preference
+    ]);
+    let neighbor = &action.station_info.neighbor_reports[0];
+    assert_eq!(
+        neighbor.bssid,
+        MacAddress([0x00, 0x11, 0x22, 0x33, 0x44, 0x66])
+    );
+    assert_eq!(
+        neighbor.bssid_information.ap_reachability,
+        ApReachability::Reachable
+    );
+    assert!(neighbor.bssid_information.security);
+    assert!(neighbor.bssid_information.key_scope);
+    assert!(neighbor.bssid_information.radio_measurement);
+    assert!(neighbor.bssid_information.high_throughput);
+    assert!(!neighbor.bssid_information.very_high_throughput);
+    assert_eq!(neighbor.operating_class, 81);
+    assert_eq!(neighbor.channel, 6);
+    assert_eq!(neighbor.phy_type, 7);
+    assert_eq!(neighbor.preference(), Some(255));
+    assert_eq!(action.station_info.encode(), &action.encode()[27..]);
+}
+
+#[test]
+fn test_wnm_actions() {
+    let action = parse_action(&[
+        10, 7,    // WNM, BSS Transition Management Request
+        1,    // Dialog token
+        0x1f, // Candidate list, abridged, disassociation imminent, BSS and ESS termination
+        100, 0,   // Disassociation timer
+        255, // Validity interval
+        4, 10, 1, 2, 3, 4, 5, 6, 7, 8, 60, 0, // BSS Termination Duration
+        3, b'a', b'/', b'b', // Session Information URL
+        // Candidate list
+        52, 16, 0x00, 0x11, 0x22, 0x33, 0x44, 0x66, 0x8f, 0x08, 0x00, 0x00, 115, 36, 9, //
+        3, 1, 200, // BSS Transition Candidate Preference
+    ]);
+    assert_eq!(action.category, ActionCategory::Wnm);
+    let Some(ActionBody::BssTransitionRequest(request)) = &action.body else {
+        panic!("Expected a BSS Transition Management Request");
+    };
+    assert!(request.request_mode.preferred_candidate_list_included);
+    assert!(request.request_mode.disassociation_imminent);
+    assert!(!request.request_mode.link_removal_imminent);
+    assert_eq!(request.disassociation_timer, 100);
+    assert_eq!(request.validity_interval, 255);
+    assert_eq!(
+        request.bss_termination_duration,
+        Some(BssTerminationDuration {
+            bss_termination_tsf: 0x0807060504030201,
+            duration: 60,
+        })
+    );
+    assert_eq!(request.session_information_url, Some(b"a/b".to_vec()));
+    let candidates = &action.station_info.neighbor_reports;
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].channel, 36);
+    assert_eq!(candidates[0].preference(), Some(200));
+
+    let action = parse_action(&[
+        10, 8, // WNM, BSS Transition Management Response
+        1, 0, 0, // Dialog token, accept, termination delay
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x66, // Target BSSID
+    ]);
+    let Some(ActionBody::BssTransitionResponse(response)) = action.body else {
+        panic!("Expected a BSS Transition Management Response");
+    };
+    assert_eq!(response.status_code, BtmStatusCode::Accept);
+    assert_eq!(
+        response.target_bssid,
+        Some(MacAddress([0x00, 0x11, 0x22, 0x33, 0x44, 0x66]))
+    );
+
+    let action = parse_action(&[10, 8, 1, 7, 0]);
+    let Some(ActionBody::BssTransitionResponse(response)) = action.body else {
+        panic!("Expected a BSS Transition Management Response");
+    };
+    assert_eq!(
+        response.status_code,
+        BtmStatusCode::RejectNoSuitableBssTransitionCandidates
+    );
+    assert_eq!(response.target_bssid, None);
+
+    let action = parse_action(&[
+        10, 16, // WNM, WNM Sleep Mode Request
+        2,  // Dialog token
+        93, 4, 0, 0, 10, 0, // WNM Sleep Mode: enter, 10 DTIM intervals
+        91, 5, 1, 0b10, 1, 1, 0xaa, // TFS Request
+    ]);
+    assert!(matches!(
+        action.body,
+        Some(ActionBody::WnmSleepModeRequest(WnmSleepModeRequest {
+            dialog_token: 2
+        }))
+    ));
+    let sleep_mode = action.station_info.wnm_sleep_mode.as_ref().unwrap();
+    assert_eq!(sleep_mode.action_type, 0);
+    assert_eq!(sleep_mode.interval, 10);
+    assert_eq!(action.station_info.tfs_requests[0].tfs_id, 1);
+    assert_eq!(action.station_info.tfs_requests[0].action_code, 0b10);
+
+    let action = parse_action(&[
+        10, 17, // WNM, WNM Sleep Mode Response
+        2,  // Dialog token
+        3, 0, 1, 2, 3, // Key data
+        93, 4, 1, 0, 0, 0, // WNM Sleep Mode: exit, accepted
+        92, 4, 1, 2, 0, 1, // TFS Response: accepted TFS ID 1
+    ]);
+    let Some(ActionBody::WnmSleepModeResponse(response)) = &action.body else {
+        panic!("Expected a WNM Sleep Mode Response");
+    };
+    assert_eq!(response.key_data, vec![1, 2, 3]);
+    assert_eq!(
+        action.station_info.tfs_responses[0].statuses(),
+        vec![(0, 1)]
+    );
+    assert_eq!(action.station_info.encode(), &action.encode()[32..]);
+
+    let action = parse_action(&[
+        10, 13, // WNM, TFS Request
+        5,  // Dialog token
+        91, 23, 1, 0b11, // TFS Request: TFS ID 1, delete after match, notify
+        1, 19, // TFS subelement
+        14, 17, 0, 0, 0x04, // TCLAS: user priority 0, Ethernet, match the EtherType
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x88, 0x8e, //
+    ]);
+    assert!(matches!(
+        action.body,
+        Some(ActionBody::TfsRequest(TfsRequest { dialog_token: 5 }))
+    ));
+    let request = &action.station_info.tfs_requests[0];
+    assert_eq!(request.tfs_id, 1);
+    assert_eq!(request.action_code, 0b11);
+    assert_eq!(request.subelements.len(), 1);
+
+    let action = parse_action(&[
+        10, 14, // WNM, TFS Response
+        5,  // Dialog token
+        92, 4, 1, 2, 0, 1, // TFS Response: accepted TFS ID 1
+    ]);
+    assert!(matches!(
+        action.body,
+        Some(ActionBody::TfsResponse(TfsResponse { dialog_token: 5 }))
+    ));
+    assert_eq!(
+        action.station_info.tfs_responses[0].statuses(),
+        vec![(0, 1)]
+    );
+
+    // Collocated Interference Reports aren't parsed.
+    let action = parse_action(&[10, 12, 5, 0]);
+    assert!(action.body.is_none());
+}
+
+#[test]
+fn test_sa_query_actions() {
+    let action = parse_action(&[
+        8, 0, // SA Query, SA Query Request
+        0x34, 0x12, // Transaction ID
+    ]);
+    assert!(matches!(
+        action.body,
+        Some(ActionBody::SaQueryRequest(SaQueryRequest {
+            transaction_id: 0x1234
+        }))
+    ));
+
+    let action = parse_action(&[
+        8, 1, // SA Query, SA Query Response
+        0x34, 0x12, // Transaction ID
+    ]);
+    assert!(matches!(
+        action.body,
+        Some(ActionBody::SaQueryResponse(SaQueryResponse {
+            transaction_id: 0x1234
+        }))
+    ));
+    assert!(action.category.is_robust());
+
+    // Categories above 16 keep their code
+    let action = parse_action(&[21, 0]);
+    assert_eq!(action.category, ActionCategory::Vht);
+    assert!(!action.category.is_robust());
+}
+
+#[test]
+fn test_robust_action_categories() {
+    // The category codes of robust action frames, everything else isn't robust.
+    let robust = [
+        0, 1, 2, 3, 5, 6, 8, 9, 10, 13, 14, 16, 18, 19, 23, 24, 25, 27, 28, 29, 126,
+    ];
+    for code in 0..=255 {
+        let category = ActionCategory::from(code);
+        assert_eq!(
+            category.is_robust(),
+            robust.contains(&code),
+            "Category {code} ({category:?})"
+        );
+    }
+    assert_eq!(ActionCategory::from(2), ActionCategory::Dls);
+    assert_eq!(ActionCategory::Dls as u8, 2);
+}
+
+#[test]
+fn test_unprotected_robust_frames() {
+    let rsn = RsnInformation {
+        mfp_required: true,
+        ..Default::default()
+    };
+    let is_spoofed =
+        |frame: &Frame, protected: bool| rsn.mfp_required && frame.is_robust() && !protected;
+
+    let deauthentication = [&[192, 0][..], &HEADER[2..], &[7, 0]].concat();
+    let frame = parse_frame(&deauthentication, false).unwrap();
+    assert!(frame.is_robust());
+    assert!(is_spoofed(&frame, false));
+
+    let public = [&HEADER[..], &[4, 0]].concat();
+    let frame = parse_frame(&public, false).unwrap();
+    assert!(!frame.is_robust());
+    assert!(!is_spoofed(&frame, false));
+
+    let protected = [&[192, 0x40][..], &HEADER[2..], &[0x12, 0x34]].concat();
+    let frame = parse_frame(&protected, false).unwrap();
+    let Frame::Deauthentication(deauthentication) = &frame else {
+        panic!("Expected a deauthentication frame");
+    };
+    let protected = deauthentication.header.frame_control.protected();
+    assert!(protected);
+    assert!(!is_spoofed(&frame, protected));
+
+    // The body of protected action frames is kept encrypted
+    let body = [
+        0x01, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, // CCMP header
+        0x9c, 0x3e, 0xd4, 0x71, 0x05, 0xfa, 0x28, 0x6b, // Encrypted SA Query Request and MIC
+        0x42, 0x8e, 0x13, 0xb7,
+    ];
+    let protected = [&[208, 0x40][..], &HEADER[2..], &body].concat();
+    let frame = parse_frame(&protected, false).unwrap();
+    assert!(frame.is_robust());
+    let Frame::Action(action) = &frame else {
+        panic!("Expected an action frame");
+    };
+    let Some(ActionBody::Encrypted(data)) = &action.body else {
+        panic!("Expected an encrypted body");
+    };
+    assert_eq!(data, &body[2..]);
+    assert!(action.station_info.elements.is_empty());
+    assert_eq!(action.encode(), protected);
+}
+
+#[test]
+fn test_gas_actions() {
+    let query = [
+        &[0x00, 0x01, 0x04, 0x00, 0x02, 0x01, 0x07, 0x01][..], // ANQP Query List
+        &[
+            0xdd, 0xdd, 0x08, 0x00, 0x50, 0x6f, 0x9a, 0x11, 0x01, 0x00, 0x03, 0x04,
+        ], // HS2.0 Query List
+    ]
+    .concat();
+    let action = parse_action(
+        &[
+            &[4, 10, 5, 108, 2, 0x7f, 0][..], // GAS Initial Request, ANQP
+            &(query.len() as u16).to_le_bytes(),
+            &query,
+        ]
+        .concat(),
+    );
+    let Some(ActionBody::GasInitialRequest(request)) = &action.body else {
+        panic!("Expected a GAS Initial Request");
+    };
+    assert_eq!(request.dialog_token, 5);
+    assert!(request.advertisement_protocol.supports_anqp());
+    assert_eq!(
+        request.anqp_elements().unwrap(),
+        vec![
+            AnqpElement::QueryList(vec![258, 263]),
+            AnqpElement::Hs20(Hs20AnqpElement::QueryList(vec![3, 4])),
+        ]
+    );
+
+    let query_response = [
+        // Venue Name: Business, Doctor's office, "Cafe"
+        &[
+            0x02, 0x01, 0x0a, 0x00, 2, 1, 7, b'e', b'n', b'g', b'C', b'a', b'f', b'e',
+        ][..],
+        // Network Authentication Type: terms and conditions
+        &[0x04, 0x01, 0x03, 0x00, 0, 0, 0],
+        // Roaming Consortium
+        &[0x05, 0x01, 0x04, 0x00, 3, 0x00, 0x1b, 0xc5],
+        // IP Address Type Availability: single NATed private IPv4, no IPv6
+        &[0x06, 0x01, 0x01, 0x00, 0x0c],
+        // NAI Realm: example.com, EAP-TTLS with MSCHAPv2
+        &[0x07, 0x01, 0x18, 0x00, 1, 0, 20, 0, 0, 11],
+        b"example.com",
+        &[1, 5, 21, 1, 2, 1, 4],
+        // 3GPP Cellular Network: MCC 310, MNC 410
+        &[0x08, 0x01, 0x08, 0x00, 0, 6, 0, 4, 1, 0x13, 0x00, 0x14],
+        // Domain Name
+        &[0x0c, 0x01, 0x0c, 0x00, 11],
+        b"example.com",
+        // HS2.0 WAN Metrics: link up, 10 Mbit/s
+        &[
+            0xdd, 0xdd, 0x13, 0x00, 0x50, 0x6f, 0x9a, 0x11, 0x04, 0x00, 0x01,
+        ],
+        &[0x10, 0x27, 0, 0, 0x10, 0x27, 0, 0, 0, 0, 0, 0],
+        // Venue Name with an invalid name
+        &[0x02, 0x01, 0x07, 0x00, 2, 1, 4, b'e', b'n', b'g', 0xff],
+    ]
+    .concat();
+    let action = parse_action(
+        &[
+            &[4, 11, 5, 0, 0, 0, 0, 108, 2, 0x7f, 0][..], // GAS Initial Response, ANQP
+            &(query_response.len() as u16).to_le_bytes(),
+            &query_response,
+        ]
+        .concat(),
+    );
+    let Some(ActionBody::GasInitialResponse(response)) = &action.body else {
+        panic!("Expected a GAS Initial Response");
+    };
+    assert_eq!(response.status_code, 0);
+    assert_eq!(response.comeback_delay, 0);
+    let elements = response.anqp_elements().unwrap();
+    assert_eq!(elements.len(), 9);
+
+    let AnqpElement::VenueName(venue_name) = &elements[0] else {
+        panic!("Expected a Venue Name");
+    };
+    assert_eq!(venue_name.venue_info.group, 2);
+    assert_eq!(venue_name.names[0].language_code, *b"eng");
+    assert_eq!(venue_name.names[0].name, "Cafe");
+    let AnqpElement::NetworkAuthenticationTypes(types) = &elements[1] else {
+        panic!("Expected a Network Authentication Type");
+    };
+    assert_eq!(
+        types[0].indicator,
+        NetworkAuthenticationIndicator::AcceptanceOfTermsAndConditions
+    );
+    assert_eq!(
+        elements[2],
+        AnqpElement::RoamingConsortium(vec![vec![0x00, 0x1b, 0xc5]])
+    );
+    assert_eq!(
+        elements[3],
+        AnqpElement::IpAddressTypeAvailability(IpAddressTypeAvailability { ipv6: 0, ipv4: 3 })
+    );
+    let AnqpElement::NaiRealms(realms) = &elements[4] else {
+        panic!("Expected a NAI Realm");
+    };
+    assert_eq!(realms[0].realms(), vec!["example.com"]);
+    assert_eq!(realms[0].eap_methods[0].method, 21);
+    assert_eq!(
+        realms[0].eap_methods[0].authentication_parameters[0].value,
+        vec![4]
+    );
+    let AnqpElement::CellularNetwork(network) = &elements[5] else {
+        panic!("Expected a 3GPP Cellular Network");
+    };
+    assert_eq!(network.plmns[0].mcc(), "310");
+    assert_eq!(network.plmns[0].mnc(), "410");
+    assert_eq!(
+        elements[6],
+        AnqpElement::DomainNames(vec!["example.com".to_string()])
+    );
+    let AnqpElement::Hs20(Hs20AnqpElement::WanMetrics(metrics)) = &elements[7] else {
+        panic!("Expected HS2.0 WAN Metrics");
+    };
+    assert_eq!(metrics.link_status, 1);
+    assert_eq!(metrics.downlink_speed, 10_000);
+    assert!(matches!(
+        elements[8],
+        AnqpElement::Unknown { info_id: 258, .. }
+    ));
+
+    let encoded: Vec<u8> = elements.iter().flat_map(AnqpElement::encode).collect();
+    assert_eq!(encoded, query_response);
+
+    let action = parse_action(&[4, 12, 5]);
+    assert!(matches!(
+        action.body,
+        Some(ActionBody::GasComebackRequest(GasComebackRequest {
+            dialog_token: 5
+        }))
+    ));
+
+    // The first fragment of a response ends in the middle of an ANQP element
+    let fragment = &query_response[..20];
+    let action = parse_action(
+        &[
+            &[4, 13, 5, 0, 0, 0x80, 0, 0, 108, 2, 0x7f, 0][..], // GAS Comeback Response
+            &(fragment.len() as u16).to_le_bytes(),
+            fragment,
+        ]
+        .concat(),
+    );
+    let Some(ActionBody::GasComebackResponse(response)) = &action.body else {
+        panic!("Expected a GAS Comeback Response");
+    };
+    assert_eq!(response.fragment_id, 0);
+    assert!(response.more_fragments);
+    assert_eq!(response.anqp_elements(), None);
+}
+
+#[test]
+fn test_ftm_actions() {
+    let action = parse_action(&[
+        4, 32, // Public, FTM Request
+        1,  // Trigger
+        38, 4, 1, 0, 8, 1, // LCI Measurement Request
+        38, 8, 2, 0, 11, 1, 0, 0, 0, 0, // Location Civic Measurement Request
+        206, 9, 0x00, 0x42, 10, 0, 0, 0x46, 0x34, 10, 0, // FTM Parameters
+    ]);
+    assert!(matches!(
+        action.body,
+        Some(ActionBody::FtmRequest(FtmRequest { trigger: 1 }))
+    ));
+    let requests = &action.station_info.measurement_requests;
+    assert!(matches!(
+        requests[0].request,
+        MeasurementRequestType::Lci(_)
+    ));
+    let MeasurementRequestType::LocationCivic(civic) = &requests[1].request else {
+        panic!("Expected a Location Civic Measurement Request");
+    };
+    assert_eq!(civic.location_subject, 1);
+    let parameters = action.station_info.ftm_parameters.as_ref().unwrap();
+    assert_eq!(parameters.number_of_bursts(), 4);
+    assert_eq!(parameters.burst_duration_us(), Some(1000));
+    assert_eq!(parameters.min_delta_ftm, 10);
+    assert!(parameters.asap_capable);
+    assert!(parameters.asap);
+    assert_eq!(parameters.ftms_per_burst, 8);
+    assert_eq!(parameters.format_and_bandwidth, 13);
+    assert_eq!(parameters.burst_period, 10);
+    assert_eq!(action.station_info.encode(), &action.encode()[27..]);
+
+    let action = parse_action(&[
+        4, 33, // Public, FTM
+        2, 1, // Dialog token, follow up dialog token
+        0x40, 0x42, 0x0f, 0, 0, 0, // TOD
+        0xe0, 0xc8, 0x10, 0, 0, 0, // TOA
+        10, 0, // TOD Error
+        10, 0x80, // TOA Error, invalid measurement
+        255, 5, 9, 0x78, 0x56, 0x34, 0x12, // FTM Synchronization Information
+    ]);
+    let Some(ActionBody::Ftm(ftm)) = &action.body else {
+        panic!("Expected an FTM frame");
+    };
+    assert_eq!(ftm.dialog_token, 2);
+    assert_eq!(ftm.tod, 1_000_000);
+    assert_eq!(ftm.toa, 1_100_000);
+    assert_eq!(ftm.tod_error.max_error(), Some(512));
+    assert!(ftm.toa_error.invalid_measurement);
+    assert_eq!(
+        action.station_info.ftm_synchronization_information,
+        Some(0x12345678)
+    );
+    assert_eq!(action.station_info.encode(), &action.encode()[44..]);
+
+    let measurement = ftm.measurement(5_000_000, 5_066_000).unwrap();
+    assert_eq!(measurement.rtt(), 34_000);
+    assert!((measurement.distance() - 5.0965).abs() < 0.001);
+
+    // Timestamps that the responder marked as invalid are ignored
+    let mut invalid = ftm.clone();
+    invalid.tod_error.invalid_measurement = true;
+    assert_eq!(invalid.measurement(5_000_000, 5_066_000), None);
+
+    // The responder's timestamps wrap around
+    let wrapped = FtmMeasurement {
+        t1: (1 << 48) - 50_000,
+        t2: 0,
+        t3: 66_000,
+        t4: 50_000,
+    };
+    assert_eq!(wrapped.rtt(), 34_000);
+    let distance = estimate_distance(&[measurement, wrapped]).unwrap();
+    assert!((distance - 5.0965).abs() < 0.001);
+    assert_eq!(estimate_distance(&[]), None);
+}
+
+#[test]
+fn test_dpp_actions() {
+    let action = parse_action(
+        &[
+            &[4, 9, 0x50, 0x6f, 0x9a, 0x1a, 1, 0][..], // DPP Authentication Request
+            &[0x02, 0x10, 32, 0],                      // Responder Bootstrapping Key Hash
+            &[0xaa; 32],
+            &[0x01, 0x10, 32, 0], // Initiator Bootstrapping Key Hash
+            &[0xbb; 32],
+            &[0x03, 0x10, 8, 0, 1, 2, 3, 4, 5, 6, 7, 8], // Initiator Protocol Key
+            &[0x18, 0x10, 2, 0, 81, 6],                  // Channel
+            &[0x19, 0x10, 1, 0, 2],                      // Protocol Version
+            &[0x04, 0x10, 6, 0, 9, 8, 7, 6, 5, 4],       // Wrapped Data
+        ]
+        .concat(),
+    );
+    let Some(ActionBody::Dpp(frame)) = &action.body else {
+        panic!("Expected a DPP frame");
+    };
+    assert_eq!(frame.crypto_suite, 1);
+    assert_eq!(frame.frame_type, DppFrameType::AuthenticationRequest);
+    assert_eq!(frame.attributes.len(), 6);
+    assert_eq!(
+        frame.attribute(0x1002),
+        Some(&DppAttribute::ResponderBootstrappingKeyHash(vec![0xaa; 32]))
+    );
+    assert_eq!(
+        frame.attributes[3],
+        DppAttribute::Channel {
+            operating_class: 81,
+            channel: 6
+        }
+    );
+    assert_eq!(frame.attributes[4], DppAttribute::ProtocolVersion(2));
+    assert_eq!(
+        frame.attributes[5],
+        DppAttribute::WrappedData(vec![9, 8, 7, 6, 5, 4])
+    );
+    assert_eq!(frame.status(), None);
+
+    let action = parse_action(
+        &[
+            &[4, 9, 0x50, 0x6f, 0x9a, 0x1a, 1, 1][..], // DPP Authentication Response
+            &[0x00, 0x10, 1, 0, 0],                    // Status
+            &[0x07, 0x10, 4, 0, 1, 2, 3, 4],           // Responder Nonce
+            &[0x08, 0x10, 1, 0, 0b10],                 // Responder Capabilities: Configurator
+            &[0x06, 0x10, 2, 0, 0b01, 0],              // Malformed Initiator Capabilities
+        ]
+        .concat(),
+    );
+    let Some(ActionBody::Dpp(frame)) = &action.body else {
+        panic!("Expected a DPP frame");
+    };
+    assert_eq!(frame.frame_type, DppFrameType::AuthenticationResponse);
+    assert_eq!(frame.status(), Some(DppStatus::Ok));
+    let DppAttribute::ResponderCapabilities(capabilities) = &frame.attributes[2] else {
+        panic!("Expected the Responder Capabilities");
+    };
+    assert!(capabilities.configurator);
+    assert!(!capabilities.enrollee);
+    assert_eq!(
+        frame.attributes[3],
+        DppAttribute::Unknown {
+            id: 0x1006,
+            data: vec![0b01, 0]
+        }
+    );
+
+    let connector = b"eyJ0eXAiOiJkcHBDb24ifQ.eyJncm91cHMiOltdfQ.c2ln";
+    let action = parse_action(
+        &[
+            &[4, 9, 0x50, 0x6f, 0x9a, 0x1a, 1, 5][..], // DPP Peer Discovery Request
+            &[0x16, 0x10, 1, 0, 7],                    // Transaction ID
+            &[0x0d, 0x10, connector.len() as u8, 0],   // Connector
+            connector,
+        ]
+        .concat(),
+    );
+    let Some(ActionBody::Dpp(frame)) = &action.body else {
+        panic!("Expected a DPP frame");
+    };
+    assert_eq!(frame.frame_type, DppFrameType::PeerDiscoveryRequest);
+    assert!(matches!(
+        frame.attributes[0],
+        DppAttribute::Unknown { id: 0x1016, .. }
+    ));
+    assert_eq!(
+        frame.attributes[1],
+        DppAttribute::Connector(String::from_utf8(connector.to_vec()).unwrap())
+    );
+}
+
+#[test]
+fn test_p2p_actions() {
+    let name = b"Android_1234";
+    let action = parse_action(
+        &[
+            &[4, 9, 0x50, 0x6f, 0x9a, 0x09, 0, 7][..], // P2P GO Negotiation Request, token 7
+            // P2P element, which is split after the Group Owner Intent
+            &[221, 13, 0x50, 0x6f, 0x9a, 0x09],
+            &[0x02, 2, 0, 0x25, 0x00],    // Capability
+            &[0x04, 1, 0, (13 << 1) | 1], // Group Owner Intent 13, tie breaker
+            &[221, 69, 0x50, 0x6f, 0x9a, 0x09],
+            &[0x06, 5, 0, b'D', b'E', 0x04, 81, 6], // Listen Channel
+            &[
+                0x0b, 12, 0, b'D', b'E', 0x04, 81, 3, 1, 6, 11, 115, 2, 36, 40,
+            ], // Channel List
+            &[0x0d, 33, 0, 0x02, 0x11, 0x22, 0x33, 0x44, 0x55], // Device Info
+            &[
+                0x01, 0x88, 0x00, 0x0a, 0x00, 0x50, 0xf2, 0x04, 0x00, 0x05, 0,
+            ], // Methods, type
+            &[0x10, 0x11, 0, name.len() as u8],
+            name,
+            &[0x11, 3, 0, 0xaa, 0xbb, 0xcc], // Malformed Operating Channel
+        ]
+        .concat(),
+    );
+    let Some(ActionBody::P2pPublicAction(frame)) = &action.body else {
+        panic!("Expected a P2P public action");
+    };
+    assert_eq!(frame.subtype, P2pPublicActionType::GoNegotiationRequest);
+    assert_eq!(frame.dialog_token, 7);
+
+    let attributes = action.station_info.p2p_attributes().unwrap();
+    assert_eq!(attributes.len(), 6);
+    let P2pAttribute::Capability(capability) = &attributes[0] else {
+        panic!("Expected the P2P Capability");
+    };
+    assert!(capability.device_capability.service_discovery);
+    assert!(capability.device_capability.concurrent_operation);
+    assert!(capability.device_capability.invitation_procedure);
+    assert!(!capability.group_capability.group_owner);
+    assert_eq!(
+        attributes[1],
+        P2pAttribute::GroupOwnerIntent(GroupOwnerIntent {
+            tie_breaker: true,
+            intent: 13
+        })
+    );
+    assert_eq!(
+        attributes[2],
+        P2pAttribute::ListenChannel(P2pChannel {
+            country: *b"DE\x04",
+            operating_class: 81,
+            channel: 6
+        })
+    );
+    let P2pAttribute::ChannelList(list) = &attributes[3] else {
+        panic!("Expected the Channel List");
+    };
+    assert_eq!(list.entries.len(), 2);
+    assert_eq!(list.entries[0].channels, vec![1, 6, 11]);
+    assert_eq!(list.entries[1].operating_class, 115);
+    let P2pAttribute::DeviceInfo(info) = &attributes[4] else {
+        panic!("Expected the Device Info");
+    };
+    assert_eq!(
+        info.device_address,
+        MacAddress([0x02, 0x11, 0x22, 0x33, 0x44, 0x55])
+    );
+    assert_eq!(info.config_methods, 0x0188);
+    assert!(info.secondary_device_types.is_empty());
+    assert_eq!(info.device_name, "Android_1234");
+    assert_eq!(
+        attributes[5],
+        P2pAttribute::Unknown {
+            id: 0x11,
+            data: vec![0xaa, 0xbb, 0xcc]
+        }
+    );
+
+    // The Group Info of a Provision Discovery Response
+    let action = parse_action(
+        &[
+            &[4, 9, 0x50, 0x6f, 0x9a, 0x09, 8, 3][..],
+            &[221, 48, 0x50, 0x6f, 0x9a, 0x09],
+            &[0x0e, 41, 0, 40], // Group Info with a single client
+            &[0x02, 0xaa, 0xbb, 0xcc, 0xdd, 0xee], // Device address
+            &[0x02, 0xaa, 0xbb, 0xcc, 0xdd, 0xef], // Interface address
+            &[
+                0x01, 0x00, 0x08, 0x00, 0x0a, 0x00, 0x50, 0xf2, 0x04, 0x00, 0x05,
+            ], // Methods, type
+            &[1, 0x00, 0x01, 0x00, 0x50, 0xf2, 0x04, 0x00, 0x01], // Secondary device type
+            &[0x10, 0x11, 0, 4],
+            b"Peer",
+        ]
+        .concat(),
+    );
+    let Some(ActionBody::P2pPublicAction(frame)) = &action.body else {
+        panic!("Expected a P2P public action");
+    };
+    assert_eq!(
+        frame.subtype,
+        P2pPublicActionType::ProvisionDiscoveryResponse
+    );
+    let attributes = action.station_info.p2p_attributes().unwrap();
+    let [P2pAttribute::GroupInfo(clients)] = &attributes[..] else {
+        panic!("Expected the Group Info");
+    };
+    assert_eq!(clients.len(), 1);
+    assert_eq!(
+        clients[0].interface_address,
+        MacAddress([0x02, 0xaa, 0xbb, 0xcc, 0xdd, 0xef])
+    );
+    assert!(clients[0].device_capability.service_discovery);
+    assert_eq!(clients[0].secondary_device_types.len(), 1);
+    assert_eq!(clients[0].device_name, "Peer");
+
+    // The Notice of Absence action of a P2P group owner
+    let action = parse_action(
+        &[
+            &[127, 0x50, 0x6f, 0x9a, 0x09, 0, 1][..],
+            &[221, 22, 0x50, 0x6f, 0x9a, 0x09],
+            &[0x0c, 15, 0, 2, 0x80 | 10], // Notice of Absence: index 2, OppPS, CTWindow 10
+            &[
+                255, 0x40, 0x9c, 0, 0, 0xa0, 0x86, 0x01, 0, 0x78, 0x56, 0x34, 0x12,
+            ],
+        ]
+        .concat(),
+    );
+    assert_eq!(action.category, ActionCategory::VendorSpecific);
+    let Some(ActionBody::P2pAction(frame)) = &action.body else {
+        panic!("Expected a P2P action");
+    };
+    assert_eq!(frame.subtype, P2pActionType::NoticeOfAbsence);
+    assert_eq!(frame.dialog_token, 1);
+    // Vendor specific actions have no action field, the OUI is encoded with the fixed fields.
+    assert_eq!(action.action, 0x50);
+    assert_eq!(frame.encode(), [0x50, 0x6f, 0x9a, 0x09, 0, 1]);
+    assert_eq!(
+        action.station_info.p2p_attributes(),
+        Some(vec![P2pAttribute::NoticeOfAbsence(NoticeOfAbsence {
+            index: 2,
+            ct_window: 10,
+            opp_ps: true,
+            descriptors: vec![NoticeOfAbsenceDescriptor {
+                count: 255,
+                duration: 40_000,
+                interval: 100_000,
+                start_time: 0x1234_5678,
+            }],
+        })])
+    );
+}
+
+#[test]
+fn test_unknown_vendor_specific_action() {
+    // Another vendor whose OUI starts with 0x50 isn't mistaken for a P2P action.
+    let action = parse_action(&[127, 0x50, 0x02, 0x00, 0x09, 0, 0]);
+    assert_eq!(action.category, ActionCategory::VendorSpecific);
+    assert_eq!(action.action, 0x50);
+    assert!(action.body.is_none());
+}
+
+#[test]
+fn test_tdls_discovery_response() {
+    let action = parse_action(&[
+        4, 14, // Public, TDLS Discovery Response
+        9,  // Dialog token
+        0x21, 0x04, // Capability
+        1, 2, 0x82, 0x84, // Supported Rates
+        101, 18, // Link Identifier
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, //
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x01, //
+        0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb,
+    ]);
+    let Some(ActionBody::TdlsDiscoveryResponse(response)) = &action.body else {
+        panic!("Expected a TDLS Discovery Response");
+    };
+    assert_eq!(response.dialog_token, 9);
+    assert_eq!(response.capability_info, 0x0421);
+    assert_eq!(
+        action.station_info.link_identifier,
+        Some(LinkIdentifier {
+            bssid: MacAddress([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+            initiator: MacAddress([0x02, 0, 0, 0, 0, 0x01]),
+            responder: MacAddress([0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb]),
+        })
+    );
+    assert_eq!(action.station_info.encode(), &action.encode()[24 + 5..]);
+}
+
+#[test]
+fn test_mesh_peering_actions() {
+    let action = parse_action(&[
+        15, 1, // Self Protected, Mesh Peering Open
+        0x00, 0x00, // Capability
+        1, 2, 0x82, 0x84, // Supported Rates
+        114, 4, b'm', b'e', b's', b'h', // Mesh ID
+        113, 7, 1, 1, 0, 1, 0, 0x02, 0x09, // Mesh Configuration
+        117, 4, 0, 0, 0x34, 0x12, // Mesh Peering Management
+    ]);
+    assert_eq!(action.category, ActionCategory::SelfProtected);
+    let Some(ActionBody::MeshPeeringOpen(open)) = &action.body else {
+        panic!("Expected a Mesh Peering Open");
+    };
+    assert_eq!(open.capability_info, 0);
+    assert_eq!(action.station_info.mesh_id, Some(b"mesh".to_vec()));
+    let management = action
+        .station_info
+        .mesh_peering_management
+        .as_ref()
+        .unwrap();
+    assert_eq!(management.local_link_id, 0x1234);
+    assert_eq!(management.peer_link_id, None);
+
+    let action = parse_action(&[
+        15, 2, // Self Protected, Mesh Peering Confirm
+        0x00, 0x00, // Capability
+        3, 0, // AID
+        117, 6, 0, 0, 0x78, 0x56, 0x34, 0x12, // Mesh Peering Management
+    ]);
+    let Some(ActionBody::MeshPeeringConfirm(confirm)) = &action.body else {
+        panic!("Expected a Mesh Peering Confirm");
+    };
+    assert_eq!(confirm.aid, 3);
+    let management = action
+        .station_info
+        .mesh_peering_management
+        .as_ref()
+        .unwrap();
+    assert_eq!(management.local_link_id, 0x5678);
+    assert_eq!(management.peer_link_id, Some(0x1234));
+
+    let action = parse_action(&[
+        15, 3, // Self Protected, Mesh Peering Close
+        114, 4, b'm', b'e', b's', b'h', // Mesh ID
+        117, 8, 0, 0, 0x34, 0x12, 0x78, 0x56, 52, 0, // Mesh Peering Management
+    ]);
+    assert!(matches!(action.body, Some(ActionBody::MeshPeeringClose)));
+    let management = action
+        .station_info
+        .mesh_peering_management
+        .as_ref()
+        .unwrap();
+    assert_eq!(management.reason_code, Some(52));
+
+    let action = parse_action(&[
+        15, 3, // Self Protected, Mesh Peering Close
+        114, 4, b'm', b'e', b's', b'h', // Mesh ID
+        117, 6, 0, 0, 0x34, 0x12, 52, 0, // Mesh Peering Management without peer link ID
+    ]);
+    let management = action
+        .station_info
+        .mesh_peering_management
+        .as_ref()
+        .unwrap();
+    assert_eq!(management.local_link_id, 0x1234);
+    assert_eq!(management.peer_link_id, None);
+    assert_eq!(management.reason_code, Some(52));
+    // Other frames interpret a single trailing field as peer link ID.
+    let element = [0, 0, 0x34, 0x12, 52, 0];
+    assert_eq!(
+        &parse_mesh_peering_close_management(&element).unwrap().1,
+        management
+    );
+    assert_eq!(
+        parse_mesh_peering_management(&element)
+            .unwrap()
+            .1
+            .peer_link_id,
+        Some(52)
+    );
+
+    let action = parse_action(&[15, 5, 140, 4, 1, 2, 3, 4]);
+    assert!(matches!(
+        action.body,
+        Some(ActionBody::MeshGroupKeyAcknowledge)
+    ));
+    assert_eq!(action.station_info.data, vec![(140, vec![1, 2, 3, 4])]);
+}
+
+#[test]
+fn test_hwmp_actions() {
+    let action = parse_action(&[
+        13, 1, // Mesh, HWMP Mesh Path Selection
+        126, 21, // Root Announcement
+        1, 2, 31, // Gate, hop count, TTL
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x03, // Root
+        7, 0, 0, 0, // Sequence number
+        0xe8, 0x03, 0, 0, // Interval
+        0x40, 0x01, 0, 0, // Metric
+        130, 37, // Path Request
+        0b10, 0, 31, // Individually addressed, hop count, TTL
+        1, 0, 0, 0, // Path discovery ID
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x01, // Originator
+        10, 0, 0, 0, // Originator sequence number
+        0x88, 0x13, 0, 0, // Lifetime
+        0, 0, 0, 0, // Metric
+        1, // Target count
+        0b101, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02, 0, 0, 0, 0, // Target only, unknown SN
+    ]);
+    assert_eq!(action.category, ActionCategory::Mesh);
+    assert!(matches!(
+        action.body,
+        Some(ActionBody::HwmpMeshPathSelection)
+    ));
+    let [request] = &action.station_info.path_requests[..] else {
+        panic!("Expected a single Path Request");
+    };
+    assert!(request.flags.individual_addressing);
+    assert!(!request.flags.address_extension);
+    assert_eq!(request.element_ttl, 31);
+    assert_eq!(request.originator, MacAddress([0x02, 0, 0, 0, 0, 0x01]));
+    assert_eq!(request.originator_sequence_number, 10);
+    assert_eq!(request.originator_external_address, None);
+    assert_eq!(request.lifetime, 5000);
+    assert!(request.targets[0].target_only);
+    assert!(request.targets[0].unknown_sequence_number);
+    assert_eq!(
+        request.targets[0].address,
+        MacAddress([0x02, 0, 0, 0, 0, 0x02])
+    );
+    let announcement = action.station_info.root_announcement.as_ref().unwrap();
+    assert!(announcement.flags.gate_announcement);
+    assert_eq!(announcement.hop_count, 2);
+    assert_eq!(announcement.interval, 1000);
+    assert_eq!(announcement.metric, 320);
+    assert_eq!(action.station_info.encode(), &action.encode()[24 + 2..]);
+
+    let action = parse_action(&[
+        13, 1, // Mesh, HWMP Mesh Path Selection
+        131, 37, // Path Reply
+        0x40, 1, 30, // Address extension, hop count, TTL
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x02, // Target
+        20, 0, 0, 0, // Target sequence number
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // Target external address
+        0x88, 0x13, 0, 0, // Lifetime
+        0xa0, 0, 0, 0, // Metric
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x01, // Originator
+        10, 0, 0, 0, // Originator sequence number
+        132, 15, // Path Error
+        31, 1, // TTL, one destination
+        0, 0x02, 0x00, 0x00, 0x00, 0x00, 0x04, 5, 0, 0, 0, 63, 0, // No forwarding information
+    ]);
+    let [reply] = &action.station_info.path_replies[..] else {
+        panic!("Expected a single Path Reply");
+    };
+    assert!(reply.flags.address_extension);
+    assert_eq!(
+        reply.target_external_address,
+        Some(MacAddress([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]))
+    );
+    assert_eq!(reply.metric, 160);
+    assert_eq!(reply.originator_sequence_number, 10);
+    let error = action.station_info.path_error.as_ref().unwrap();
+    assert_eq!(error.destinations.len(), 1);
+    assert_eq!(error.destinations[0].sequence_number, 5);
+    assert_eq!(error.destinations[0].reason_code, 63);
+    assert_eq!(action.station_info.encode(), &action.encode()[24 + 2..]);
+
+    let action = parse_action(&[
+        13, 2, // Mesh, Gate Announcement
+        125, 15, // Gate Announcement
+        0, 0, 31, // Flags, hop count, TTL
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x05, // Mesh gate
+        3, 0, 0, 0, // Sequence number
+        0xe8, 0x03, // Interval
+    ]);
+    assert!(matches!(action.body, Some(ActionBody::GateAnnouncement)));
+    assert_eq!(
+        action.station_info.gate_announcement,
+        Some(GateAnnouncement {
+            flags: HwmpFlags::default(),
+            hop_count: 0,
+            element_ttl: 31,
+            mesh_gate: MacAddress([0x02, 0, 0, 0, 0, 0x05]),
+            sequence_number: 3,
+            interval: 1000,
+        })
+    );
+
+    let action = parse_action(&[
+        13, 0, // Mesh, Link Metric Report
+        115, 5, 0, 0x10, 0, 0, 0, // Mesh Link Metric Report
+    ]);
+    assert!(matches!(action.body, Some(ActionBody::LinkMetricReport)));
+    assert_eq!(
+        action.station_info.data,
+        vec![(115, vec![0, 0x10, 0, 0, 0])]
+    );
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use libwifi::frame::components::{CustomElement, ElementKey};
+use libwifi::parsers::{ElementRegistry, parse_station_info, parse_station_info_with};
+
+#[derive(Debug, PartialEq)]
+struct SiteId(u16);
+
+impl CustomElement for SiteId {
+    fn encode(&self) -> Vec<u8> {
+        self.0.to_le_bytes().to_vec()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Proprietary(Vec<u8>);
+
+impl CustomElement for Proprietary {
+    fn encode(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+}
+
+fn parse_site_id(data: &[u8]) -> Option<Arc<dyn CustomElement>> {
+    Some(Arc::new(SiteId(u16::from_le_bytes(data.try_into().ok()?))))
+}
+
+fn parse_proprietary(data: &[u8]) -> Option<Arc<dyn CustomElement>> {
+    Some(Arc::new(Proprietary(data.to_vec())))
+}
+
+#[test]
+fn test_custom_element_parsers() {
+    let mut registry = ElementRegistry::default();
+    registry.register_element_parser(ElementKey::Element(250), parse_site_id);
+    registry.register_element_parser(ElementKey::Extension(200), parse_proprietary);
+    registry.register_element_parser(
+        ElementKey::Vendor([0x00, 0x12, 0x34], 0x07),
+        parse_proprietary,
+    );
+
+    let payload = [
+        // SSID "test"
+        0, 4, b't', b'e', b's', b't', //
+        // Element 250: site ID 42
+        250, 2, 0x2a, 0x00, //
+        // Extension element 200
+        255, 3, 200, 0x01, 0x02, //
+        // Vendor specific element 00:12:34, type 7
+        221, 6, 0x00, 0x12, 0x34, 0x07, 0xaa, 0xbb,
+    ];
+
+    let station_info = parse_station_info_with(&payload, &registry).unwrap().1;
+    assert!(station_info.data.is_empty());
+    assert!(station_info.vendor_specific.is_empty());
+
+    let custom = &station_info.custom_elements;
+    assert_eq!(custom.len(), 3);
+    assert_eq!(custom.get::<SiteId>(), Some(&SiteId(42)));
+    assert_eq!(
+        custom.get_all::<Proprietary>().collect::<Vec<_>>(),
+        vec![
+            &Proprietary(vec![0x01, 0x02]),
+            &Proprietary(vec![0xaa, 0xbb])
+        ]
+    );
+    assert_eq!(custom.get_by_key(ElementKey::Extension(200)).count(), 1);
+
+    assert_eq!(station_info.encode(), payload);
+
+    // Other callers aren't affected by the registered parsers
+    let station_info = parse_station_info(&payload).unwrap().1;
+    assert!(station_info.custom_elements.is_empty());
+    // Unknown extension elements are kept as raw data as well.
+    assert_eq!(
+        station_info.data,
+        vec![(250, vec![0x2a, 0x00]), (255, vec![200, 0x01, 0x02])]
+    );
+    assert_eq!(station_info.vendor_specific.len(), 1);
+
+    registry.unregister_element_parser(ElementKey::Element(250));
+    let station_info = parse_station_info_with(&payload, &registry).unwrap().1;
+    assert_eq!(station_info.custom_elements.len(), 2);
+    assert_eq!(station_info.data, vec![(250, vec![0x2a, 0x00])]);
+}
@@ -1,4 +1,6 @@
-use libwifi::frame::Frame;
+use crc::{CRC_32_ISO_HDLC, Crc};
+use libwifi::frame::components::MacAddress;
+use libwifi::frame::{ActionBody, DataFrame, Frame, TdlsSetupRequest};
 use libwifi::parse_frame;
 
 #[test]
@@ -21,6 +23,15 @@ fn test_data() {
     let frame = parse_frame(&payload, false).expect("Payload should be valid");
     println!("{frame:?}");
     assert!(matches!(frame, Frame::Data(_)));
+
+    // The FCS isn't part of the data.
+    let fcs = Crc::<u32>::new(&CRC_32_ISO_HDLC).checksum(&payload);
+    let with_fcs = [&payload[..], &fcs.to_le_bytes()].concat();
+    let frame = parse_frame(&with_fcs, true).expect("Payload should be valid");
+    let Frame::Data(data) = frame else {
+        panic!("Expected a data frame");
+    };
+    assert_eq!(data.data, payload[24..]);
 }
 
 #[test]
@@ -70,3 +81,81 @@ fn test_qos_null() {
     println!("{frame:?}");
     assert!(matches!(frame, Frame::QosNull(_)));
 }
+
+#[test]
+fn test_tdls_frames() {
+    let header = [
+        136, 1, // Frame Control, to DS
+        44, 0, // Duration Id
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // BSSID
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x01, // Initiator
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x02, // Responder
+        64, 119, // SequencControl
+        0, 0, // QoS
+    ];
+    let link_identifier = [
+        101, 18, // Link Identifier
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, //
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x01, //
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x02,
+    ];
+    let data = [
+        &[0xaa, 0xaa, 0x03, 0x00, 0x00, 0x00, 0x89, 0x0d][..], // LLC/SNAP, TDLS EtherType
+        &[2, 12, 0],                                           // TDLS, Setup Request
+        &[5, 0x21, 0x04],                                      // Dialog token, capability
+        &[1, 2, 0x82, 0x84],                                   // Supported Rates
+        &link_identifier,
+    ]
+    .concat();
+    let payload = [&header[..], &data].concat();
+
+    let Frame::QosData(frame) = parse_frame(&payload, false).expect("Payload should be valid")
+    else {
+        panic!("Expected a QoS data frame");
+    };
+    let tdls = frame.tdls_frame().expect("Expected a TDLS frame");
+    assert_eq!(tdls.action, 0);
+    let Some(ActionBody::TdlsSetupRequest(request)) = &tdls.body else {
+        panic!("Expected a TDLS Setup Request");
+    };
+    assert_eq!(
+        request,
+        &TdlsSetupRequest {
+            dialog_token: 5,
+            capability_info: 0x0421
+        }
+    );
+    let link = tdls.station_info.link_identifier.as_ref().unwrap();
+    assert_eq!(link.bssid, MacAddress([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]));
+    assert_eq!(link.initiator, MacAddress([0x02, 0, 0, 0, 0, 0x01]));
+    assert_eq!(link.responder, MacAddress([0x02, 0, 0, 0, 0, 0x02]));
+    assert_eq!(tdls.encode(), data);
+
+    // A teardown with an unspecified reason
+    let data = [
+        &[0xaa, 0xaa, 0x03, 0x00, 0x00, 0x00, 0x89, 0x0d][..],
+        &[2, 12, 3, 1, 0],
+        &link_identifier,
+    ]
+    .concat();
+    let payload = [&header[..], &data].concat();
+    let Frame::QosData(frame) = parse_frame(&payload, false).expect("Payload should be valid")
+    else {
+        panic!("Expected a QoS data frame");
+    };
+    let tdls = frame.tdls_frame().expect("Expected a TDLS frame");
+    let Some(ActionBody::TdlsTeardown(teardown)) = &tdls.body else {
+        panic!("Expected a TDLS Teardown");
+    };
+    assert_eq!(teardown.reason_code, 1);
+    assert!(tdls.station_info.link_identifier.is_some());
+
+    // Protected data can't be decoded
+    let mut payload = payload;
+    payload[1] |= 0x40;
+    let Frame::QosData(frame) = parse_frame(&payload, false).expect("Payload should be valid")
+    else {
+        panic!("Expected a QoS data frame");
+    };
+    assert!(frame.tdls_frame().is_none());
+}
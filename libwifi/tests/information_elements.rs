@@ -0,0 +1,807 @@
+use std::sync::Arc;
+
+use libwifi::frame::components::*;
+use libwifi::parsers::{
+    ElementRegistry, IeIter, decode_ie, parse_station_info, parse_station_info_with,
+};
+
+#[test]
+fn test_eht_elements() {
+    let payload = [
+        0,
+        4,
+        116,
+        101,
+        115,
+        116, // SSID "test"
+        // EHT Capabilities
+        255,
+        18,
+        108, //
+        0x01,
+        0x00, // MAC capabilities: EPCS priority access
+        0x62,
+        0x00,
+        0x00,
+        0x00,
+        0x01,
+        0x00,
+        0x00,
+        0x00,
+        0x00, // PHY capabilities
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22, // MCS/NSS set
+        // EHT Operation: information + disabled subchannel bitmap present, 320 MHz
+        255,
+        11,
+        106, //
+        0b0000_0011,
+        0xfc,
+        0xff,
+        0xfc,
+        0xff, // Parameters + Basic EHT-MCS And NSS Set
+        4,
+        47,
+        31,
+        0x02,
+        0x00, // Control, CCFS0, CCFS1, Disabled Subchannel Bitmap
+        // Basic Multi-Link
+        255,
+        37,
+        107, //
+        0xb0,
+        0x01, // Control: Basic, Link ID, BSS Params Change Count, EML + MLD capabilities
+        13,   // Common info length
+        2,
+        0,
+        0,
+        0,
+        0,
+        1, // MLD MAC address
+        1, // Link ID
+        3, // BSS parameters change count
+        0x01,
+        0x00, // EML capabilities
+        0x01,
+        0x00, // MLD capabilities
+        0,
+        19, // Per-STA Profile subelement
+        0x32,
+        0x00, // STA Control: link id 2, complete profile, STA MAC address present
+        7,    // STA info length
+        2,
+        0,
+        0,
+        0,
+        0,
+        2, // STA MAC address
+        0x11,
+        0x04, // Capability info
+        0,
+        6,
+        54,
+        71,
+        72,
+        122,
+        45,
+        50, // SSID "6GHz-2"
+    ];
+
+    let (_, station_info) = parse_station_info(&payload).expect("Elements should be valid");
+
+    let operation = station_info.eht_operation.clone().unwrap();
+    let information = operation.operation_information.clone().unwrap();
+    assert_eq!(information.channel_width, EhtChannelWidth::Mhz320);
+    assert_eq!(information.ccfs0, 47);
+    assert_eq!(information.disabled_subchannel_bitmap, Some(2));
+
+    let capabilities = station_info.eht_capabilities.clone().unwrap();
+    assert!(capabilities.mac_capabilities.epcs_priority_access);
+    assert!(capabilities.phy_capabilities.support_320mhz_in_6ghz);
+    assert!(capabilities.phy_capabilities.su_beamformer);
+    assert!(capabilities.phy_capabilities.su_beamformee);
+    assert!(capabilities.phy_capabilities.partial_bandwidth_dl_mu_mimo);
+
+    let Some(MultiLink::Basic(multi_link)) = station_info.multi_link.clone() else {
+        panic!("Expected a basic multi-link element");
+    };
+    assert_eq!(multi_link.mld_mac_address, MacAddress([2, 0, 0, 0, 0, 1]));
+    assert_eq!(multi_link.link_id, Some(1));
+    assert_eq!(multi_link.bss_parameters_change_count, Some(3));
+    assert!(multi_link.eml_capabilities.unwrap().emlsr_support);
+    assert_eq!(
+        multi_link.mld_capabilities.unwrap().max_simultaneous_links,
+        1
+    );
+
+    let profile = &multi_link.per_sta_profiles[0];
+    assert_eq!(profile.link_id, 2);
+    assert!(profile.complete_profile);
+    assert_eq!(
+        profile.sta_mac_address,
+        Some(MacAddress([2, 0, 0, 0, 0, 2]))
+    );
+    assert_eq!(profile.capability_info, Some(0x0411));
+    assert_eq!(profile.station_info.ssid.as_deref(), Some("6GHz-2"));
+
+    // Encoding the elements should result in the original payload.
+    assert_eq!(station_info.encode(), payload);
+}
+
+#[test]
+fn test_reduced_neighbor_report() {
+    let payload = [
+        0, 4, 116, 101, 115, 116, // SSID "test"
+        201, 50, // Reduced Neighbor Report
+        // Neighbor AP Information: 6 GHz AP on channel 37 with two 13 byte TBTT fields
+        0x10, 13, 131, 37, // TBTT Information Header, operating class, channel
+        255, 2, 0, 0, 0, 0, 3, 0x78, 0x56, 0x34, 0x12, 0x4e, 0xf6, // First TBTT field
+        10, 2, 0, 0, 0, 0, 4, 0x21, 0x43, 0x65, 0x87, 0x02, 0x10, // Second TBTT field
+        // Neighbor AP Information: 5 GHz AP on channel 36 with MLD parameters
+        0x00, 16, 128, 36, // TBTT Information Header, operating class, channel
+        0, 2, 0, 0, 0, 0, 5, 0x78, 0x56, 0x34, 0x12, 0x42, 127, 0x01, 0x52, 0x00,
+    ];
+
+    let (_, station_info) = parse_station_info(&payload).expect("Elements should be valid");
+    let report = &station_info.reduced_neighbor_reports[0];
+    assert_eq!(report.neighbor_ap_information.len(), 2);
+
+    let six_ghz = &report.neighbor_ap_information[0];
+    assert_eq!(six_ghz.operating_class, 131);
+    assert_eq!(six_ghz.channel, 37);
+    assert_eq!(six_ghz.tbtt_information.len(), 2);
+
+    let first = &six_ghz.tbtt_information[0];
+    assert_eq!(first.tbtt_offset, 255);
+    assert_eq!(first.bssid, Some(MacAddress([2, 0, 0, 0, 0, 3])));
+    assert_eq!(first.short_ssid, Some(0x12345678));
+    let bss_parameters = first.bss_parameters.clone().unwrap();
+    assert!(bss_parameters.same_ssid);
+    assert!(bss_parameters.multiple_bssid);
+    assert!(bss_parameters.transmitted_bssid);
+    assert!(bss_parameters.colocated_ap);
+    assert!(!bss_parameters.oct_recommended);
+    assert_eq!(first.psd_20mhz, Some(-10));
+    assert!(first.mld_parameters.is_none());
+
+    let mld = &report.neighbor_ap_information[1].tbtt_information[0];
+    let mld_parameters = mld.mld_parameters.clone().unwrap();
+    assert_eq!(mld_parameters.ap_mld_id, 1);
+    assert_eq!(mld_parameters.link_id, 2);
+    assert_eq!(mld_parameters.bss_parameters_change_count, 5);
+
+    assert_eq!(station_info.encode(), payload);
+
+    // A TBTT Information Length of 0 keeps the remaining fields as raw data
+    let payload = [
+        201, 11, // Reduced Neighbor Report
+        0x00, 1, 115, 1, 10, // Neighbor AP Information with a 1 byte TBTT field
+        0x00, 0, 115, 6, 0x11, 0x22, // Neighbor AP Information with a length of 0
+    ];
+    let (_, station_info) = parse_station_info(&payload).expect("Elements should be valid");
+    let report = &station_info.reduced_neighbor_reports[0];
+    assert_eq!(report.neighbor_ap_information.len(), 1);
+    assert_eq!(
+        report.neighbor_ap_information[0].tbtt_information[0].tbtt_offset,
+        10
+    );
+    assert_eq!(report.other_data, vec![0x00, 0, 115, 6, 0x11, 0x22]);
+    assert_eq!(station_info.encode(), payload);
+
+    // Neighbor AP Information fields without TBTT Information fields can't be encoded
+    let mut report = report.clone();
+    report.neighbor_ap_information[0].tbtt_information.clear();
+    report.other_data.clear();
+    assert!(report.encode().is_empty());
+}
+
+#[test]
+fn test_rsn_extension_and_security_profile() {
+    let payload = [
+        // RSN: CCMP group/pairwise, AKMs SAE + PSK
+        48, 24, 1, 0, 0x00, 0x0f, 0xac, 4, 1, 0, 0x00, 0x0f, 0xac, 4, 2, 0, 0x00, 0x0f, 0xac, 8,
+        0x00, 0x0f, 0xac, 2, 0x80, 0x00, //
+        // RSNXE: SAE hash-to-element
+        244, 1, 0x20, //
+        // Vendor specific
+        221, 5, 0x00, 0x11, 0x22, 0x01, 0x00,
+    ];
+
+    let station_info = parse_station_info(&payload).unwrap().1;
+    let rsn_extension = station_info.rsn_extension.as_ref().unwrap();
+    assert!(rsn_extension.sae_hash_to_element);
+    assert!(!rsn_extension.sae_pk);
+
+    assert_eq!(
+        station_info.security_profile(),
+        SecurityProfile::Wpa3Transition(SaeCapabilities {
+            hash_to_element: true,
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        station_info.security_profile().to_string(),
+        "WPA3-Transition"
+    );
+
+    assert_eq!(station_info.encode(), payload);
+
+    // A declared but empty second byte
+    let payload = [244, 2, 0x21, 0x00];
+    let station_info = parse_station_info(&payload).unwrap().1;
+    assert_eq!(station_info.rsn_extension.as_ref().unwrap().length, 2);
+    assert_eq!(station_info.encode(), payload);
+
+    // Bits of a trailing byte beyond the declared field length aren't capabilities
+    let payload = [244, 2, 0x20, 0x1f];
+    let station_info = parse_station_info(&payload).unwrap().1;
+    let rsn_extension = station_info.rsn_extension.as_ref().unwrap();
+    assert!(rsn_extension.sae_hash_to_element);
+    assert_eq!(rsn_extension.length, 1);
+    assert!(!rsn_extension.secure_ltf);
+    assert!(!rsn_extension.pbac);
+    assert_eq!(rsn_extension.trailing_data, vec![0x1f]);
+    assert_eq!(station_info.encode(), payload);
+}
+
+#[test]
+fn test_wmm_and_mu_edca_parameters() {
+    let payload = [
+        // WMM Parameter element
+        221, 24, 0x00, 0x50, 0xf2, 0x02, 0x01, 0x01, //
+        0x81, 0x00, // QoS info: count 1, U-APSD; reserved
+        0x03, 0xa4, 0x00, 0x00, // BE
+        0x27, 0xa4, 0x00, 0x00, // BK
+        0x42, 0x43, 0x5e, 0x00, // VI
+        0x62, 0x32, 0x2f, 0x00, // VO
+        // MU EDCA Parameter Set
+        255, 14, 38, 0x01, //
+        0x08, 0xa4, 0x08, //
+        0x28, 0xa4, 0x08, //
+        0x44, 0x43, 0x08, //
+        0x68, 0x32, 0x08,
+    ];
+
+    let station_info = parse_station_info(&payload).unwrap().1;
+    assert!(station_info.vendor_specific.is_empty());
+
+    let Some(Wmm::Parameter {
+        version,
+        parameters,
+    }) = &station_info.wmm
+    else {
+        panic!("Expected a WMM Parameter element");
+    };
+    assert_eq!(*version, 1);
+    assert_eq!(parameters.qos_info.parameter_set_count, 1);
+    assert!(parameters.qos_info.u_apsd);
+    assert_eq!(parameters.ac_parameters.len(), 4);
+
+    let video = parameters.get(AccessCategory::Video).unwrap();
+    assert_eq!(video.aifsn, 2);
+    assert!(!video.acm);
+    assert_eq!(video.cw_min(), 7);
+    assert_eq!(video.cw_max(), 15);
+    assert_eq!(video.txop_limit_us(), 3008);
+
+    let mu_edca = station_info.mu_edca_parameter_set.as_ref().unwrap();
+    assert_eq!(mu_edca.ac_parameters.len(), 4);
+    assert_eq!(
+        mu_edca.ac_parameters[3].access_category,
+        AccessCategory::Voice
+    );
+    assert_eq!(mu_edca.ac_parameters[3].aifsn, 8);
+    assert_eq!(mu_edca.ac_parameters[3].mu_edca_timer, 8);
+    assert_eq!(mu_edca.ac_parameters[3].mu_edca_timer_tu(), 64);
+
+    assert_eq!(station_info.encode(), payload);
+}
+
+#[test]
+fn test_fast_transition_elements() {
+    let mut payload = vec![
+        // Mobility Domain: MDID 0x1234, FT over DS
+        54, 3, 0x34, 0x12, 0x01, //
+        // Fast BSS Transition
+        55, 96, 0x00, 0x03,
+    ];
+    payload.extend([0x11; 16]); // MIC
+    payload.extend([0x22; 32]); // ANonce
+    payload.extend([0x33; 32]); // SNonce
+    payload.extend([1, 6, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55]); // R1KH-ID
+    payload.extend([3, 4, b'n', b'a', b's', b'1']); // R0KH-ID
+    // Timeout Interval: reassociation deadline of 1000 TUs
+    payload.extend([56, 5, 1, 0xe8, 0x03, 0x00, 0x00]);
+
+    let station_info = parse_station_info(&payload).unwrap().1;
+    assert!(station_info.data.is_empty());
+
+    let mobility_domain = station_info.mobility_domain.as_ref().unwrap();
+    assert_eq!(mobility_domain.mdid, 0x1234);
+    assert!(mobility_domain.ft_capability_policy.ft_over_ds);
+    assert!(
+        !mobility_domain
+            .ft_capability_policy
+            .resource_request_protocol_capable
+    );
+
+    let fast_transition = station_info.fast_transition.as_ref().unwrap();
+    assert_eq!(fast_transition.mic_control.element_count, 3);
+    assert_eq!(fast_transition.mic, vec![0x11; 16]);
+    assert_eq!(fast_transition.anonce, [0x22; 32]);
+    assert_eq!(fast_transition.snonce, [0x33; 32]);
+    assert_eq!(
+        fast_transition.r1kh_id(),
+        Some(&MacAddress([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]))
+    );
+    assert_eq!(fast_transition.r0kh_id(), Some(b"nas1".as_slice()));
+
+    let timeout_interval = station_info.timeout_interval.as_ref().unwrap();
+    assert_eq!(
+        timeout_interval.interval_type,
+        TimeoutIntervalType::ReassociationDeadline
+    );
+    assert_eq!(timeout_interval.value, 1000);
+
+    assert_eq!(station_info.encode(), payload);
+}
+
+#[test]
+fn test_passpoint_elements() {
+    let payload = [
+        // Hotspot 2.0 Indication: Release 3, PPS MO ID present
+        221, 7, 0x50, 0x6f, 0x9a, 0x10, 0x22, 0x01, 0x00, //
+        // Interworking: free public network with internet, venue and HESSID
+        107, 9, 0x13, 0x02, 0x01, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, //
+        // Advertisement Protocol: ANQP
+        108, 2, 0x7f, 0x00, //
+        // Roaming Consortium: two OIs with 3 and 5 bytes
+        111, 10, 0x00, 0x53, 0x00, 0x1b, 0xc5, 0x04, 0x6f, 0x9a, 0x01, 0x02,
+    ];
+
+    let station_info = parse_station_info(&payload).unwrap().1;
+    assert!(station_info.vendor_specific.is_empty());
+    assert!(station_info.data.is_empty());
+
+    let hs20 = station_info.hs20_indication.as_ref().unwrap();
+    assert_eq!(hs20.release(), 3);
+    assert_eq!(hs20.pps_mo_id, Some(1));
+    assert_eq!(hs20.anqp_domain_id, None);
+
+    let interworking = station_info.interworking.as_ref().unwrap();
+    assert_eq!(
+        interworking.access_network_type,
+        AccessNetworkType::FreePublicNetwork
+    );
+    assert!(interworking.internet);
+    assert!(!interworking.asra);
+    assert_eq!(
+        interworking.venue_info,
+        Some(VenueInfo {
+            group: 2,
+            venue_type: 1
+        })
+    );
+    assert_eq!(
+        interworking.hessid,
+        Some(MacAddress([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]))
+    );
+
+    let advertisement_protocol = station_info.advertisement_protocol.as_ref().unwrap();
+    assert!(advertisement_protocol.supports_anqp());
+    assert_eq!(
+        advertisement_protocol.tuples[0].query_response_length_limit,
+        0x7f
+    );
+
+    let roaming_consortium = station_info.roaming_consortium.as_ref().unwrap();
+    assert_eq!(
+        roaming_consortium.ois,
+        vec![vec![0x00, 0x1b, 0xc5], vec![0x04, 0x6f, 0x9a, 0x01, 0x02]]
+    );
+
+    assert_eq!(station_info.encode(), payload);
+
+    // The reserved bit of the Hotspot 2.0 Indication is kept and Interworking elements with an
+    // invalid length are rejected and kept as raw data
+    let payload = [
+        221, 5, 0x50, 0x6f, 0x9a, 0x10, 0x28, //
+        107, 5, 0x13, 0x02, 0x01, 0x00, 0x11,
+    ];
+    let station_info = parse_station_info(&payload).unwrap().1;
+    let hs20 = station_info.hs20_indication.as_ref().unwrap();
+    assert!(hs20.reserved);
+    assert_eq!(hs20.release(), 3);
+    assert!(station_info.interworking.is_none());
+    assert_eq!(station_info.data, vec![(107, payload[9..].to_vec())]);
+    assert!(!station_info.elements[1].decoded);
+    assert_eq!(station_info.encode(), payload);
+}
+
+#[test]
+fn test_bss_load_elements() {
+    let payload = [
+        // BSS Load: 5 stations, 50% utilization
+        11, 5, 0x05, 0x00, 0x80, 0x00, 0x00, //
+        // ERP Information: non-ERP present, use protection
+        42, 1, 0x03, //
+        // Extended BSS Load
+        193, 6, 0x02, 0x00, 0x40, 0x10, 0x20, 0x30,
+    ];
+
+    let station_info = parse_station_info(&payload).unwrap().1;
+    assert!(station_info.data.is_empty());
+
+    let bss_load = station_info.bss_load.as_ref().unwrap();
+    assert_eq!(bss_load.station_count, 5);
+    assert_eq!(bss_load.channel_utilization, 0x80);
+    assert!((bss_load.channel_utilization_percent() - 50.2).abs() < 0.1);
+    assert_eq!(bss_load.available_admission_capacity, 0);
+
+    let erp_information = station_info.erp_information.as_ref().unwrap();
+    assert!(erp_information.non_erp_present);
+    assert!(erp_information.use_protection);
+    assert!(!erp_information.barker_preamble_mode);
+
+    let extended_bss_load = station_info.extended_bss_load.as_ref().unwrap();
+    assert_eq!(extended_bss_load.mu_mimo_capable_station_count, 2);
+    assert_eq!(extended_bss_load.spatial_stream_underutilization, 0x40);
+    assert_eq!(
+        extended_bss_load.observable_secondary_80mhz_utilization,
+        0x30
+    );
+
+    assert_eq!(station_info.encode(), payload);
+}
+
+#[test]
+fn test_spectrum_management_elements() {
+    let payload = [
+        // Power Capability: 0 to 20 dBm
+        33, 2, 0x00, 0x14, //
+        // TPC Report: 17 dBm
+        35, 2, 0x11, 0x00, //
+        // Supported Channels: 36-48 and 149-161
+        36, 4, 36, 4, 149, 4, //
+        // Quiet: next TBTT, 10 TUs with an offset of 5 TUs
+        40, 6, 0x01, 0x00, 0x0a, 0x00, 0x05, 0x00,
+    ];
+
+    let station_info = parse_station_info(&payload).unwrap().1;
+    assert!(station_info.data.is_empty());
+
+    let power_capability = station_info.power_capability.as_ref().unwrap();
+    assert_eq!(power_capability.min_tx_power, 0);
+    assert_eq!(power_capability.max_tx_power, 20);
+
+    assert_eq!(station_info.tpc_report.as_ref().unwrap().tx_power, 17);
+
+    let supported_channels = station_info.supported_channels.as_ref().unwrap();
+    assert_eq!(
+        supported_channels.channels(),
+        vec![36, 40, 44, 48, 149, 153, 157, 161]
+    );
+
+    // A malformed subband whose channel numbers would exceed 255
+    let supported_channels = SupportedChannels {
+        subbands: vec![ChannelSubband {
+            first_channel: 36,
+            number_of_channels: 255,
+        }],
+    };
+    let channels = supported_channels.channels();
+    assert_eq!(channels.len(), 55);
+    assert_eq!(channels.last(), Some(&252));
+
+    assert_eq!(
+        station_info.quiet,
+        vec![Quiet {
+            count: 1,
+            period: 0,
+            duration: 10,
+            offset: 5,
+        }]
+    );
+
+    assert_eq!(station_info.encode(), payload);
+}
+
+#[test]
+fn test_channel_switch_elements() {
+    let payload = [
+        // Extended CSA: switch to channel 36 in operating class 128 (80 MHz) in 5 TBTTs
+        60, 4, 0x01, 128, 36, 5, //
+        // Channel Switch Wrapper with a Wide Bandwidth Channel Switch to 80 MHz
+        196, 5, 194, 3, 0x01, 42, 0x00, //
+        // Max Channel Switch Time: 16 TUs
+        255, 4, 52, 0x10, 0x00, 0x00,
+    ];
+
+    let station_info = parse_station_info(&payload).unwrap().1;
+    assert!(station_info.data.is_empty());
+
+    let extended_channel_switch = station_info.extended_channel_switch.as_ref().unwrap();
+    assert_eq!(extended_channel_switch.mode, ChannelSwitchMode::Restrict);
+    assert_eq!(extended_channel_switch.new_operating_class, 128);
+    assert_eq!(extended_channel_switch.count, 5);
+
+    let wrapper = station_info.channel_switch_wrapper.as_ref().unwrap();
+    let wide_bandwidth = wrapper.wide_bandwidth_channel_switch().unwrap();
+    assert_eq!(wide_bandwidth.new_ccfs0, 42);
+    assert_eq!(wide_bandwidth.bandwidth(), Some(80));
+
+    assert_eq!(station_info.max_channel_switch_time, Some(16));
+
+    assert_eq!(
+        station_info.channel_switch_target(),
+        Some(ChannelSwitchTarget {
+            channel: 36,
+            operating_class: Some(128),
+            primary_frequency: 5180,
+            bandwidth: 80,
+        })
+    );
+
+    assert_eq!(station_info.encode(), payload);
+
+    // A legacy channel switch to a 40 MHz channel on 2.4 GHz.
+    let station_info = StationInfo {
+        channel_switch: Some(ChannelSwitchAnnouncment {
+            mode: ChannelSwitchMode::Unrestricted,
+            new_channel: 6,
+            count: 3,
+        }),
+        secondary_channel_offset: Some(SecondaryChannelOffset::Above),
+        ..Default::default()
+    };
+    let target = station_info.channel_switch_target().unwrap();
+    assert_eq!(target.primary_frequency, 2437);
+    assert_eq!(target.bandwidth, 40);
+}
+
+#[test]
+fn test_mesh_elements() {
+    let payload = [
+        // Mesh ID "mesh"
+        114, 4, b'm', b'e', b's', b'h', //
+        // Mesh Configuration: HWMP, airtime, no congestion control, neighbor offset, SAE
+        113, 7, 1, 1, 0, 1, 1, 0x02, 0x09, //
+        // Mesh Peering Management: local link ID 1, peer link ID 2
+        117, 6, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00, //
+        // Mesh Awake Window: 10 TUs
+        119, 2, 0x0a, 0x00, //
+        // Unhandled element
+        222, 3, 0x01, 0x02, 0x03,
+    ];
+
+    let station_info = parse_station_info(&payload).unwrap().1;
+    assert_eq!(station_info.mesh_id.as_deref(), Some(b"mesh".as_slice()));
+
+    let configuration = station_info.mesh_configuration.as_ref().unwrap();
+    assert_eq!(
+        configuration.path_selection_protocol,
+        PathSelectionProtocol::Hwmp
+    );
+    assert_eq!(
+        configuration.path_selection_metric,
+        PathSelectionMetric::Airtime
+    );
+    assert_eq!(
+        configuration.congestion_control_mode,
+        CongestionControlMode::Null
+    );
+    assert_eq!(
+        configuration.synchronization_method,
+        SynchronizationMethod::NeighborOffset
+    );
+    assert_eq!(
+        configuration.authentication_protocol,
+        MeshAuthenticationProtocol::Sae
+    );
+    assert_eq!(configuration.formation_info.number_of_peerings, 1);
+    assert!(configuration.capability.accepting_additional_peerings);
+    assert!(configuration.capability.forwarding);
+    assert!(!configuration.capability.mcca_enabled);
+
+    let peering = station_info.mesh_peering_management.as_ref().unwrap();
+    assert_eq!(peering.local_link_id, 1);
+    assert_eq!(peering.peer_link_id, Some(2));
+    assert_eq!(peering.reason_code, None);
+
+    assert_eq!(station_info.mesh_awake_window, Some(10));
+
+    assert_eq!(station_info.encode(), payload);
+}
+
+#[test]
+fn test_vendor_decoders() {
+    let payload = [
+        // OWE Transition Mode: BSSID, SSID "owe", operating class 115, channel 36
+        221, 16, 0x50, 0x6f, 0x9a, 0x1c, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 3, b'o', b'w', b'e',
+        115, 36, //
+        // P2P: Capability attribute
+        221, 9, 0x50, 0x6f, 0x9a, 0x09, 0x02, 0x02, 0x00, 0x21, 0x00, //
+        // Aruba AP name "ap-1"
+        221, 9, 0x00, 0x0b, 0x86, 0x01, 0x03, b'a', b'p', b'-', b'1', //
+        // Unknown vendor element
+        221, 6, 0x00, 0x12, 0x34, 0x01, 0xaa, 0xbb,
+    ];
+
+    let station_info = parse_station_info(&payload).unwrap().1;
+    let vendor = &station_info.vendor_specific;
+    assert_eq!(vendor.len(), 4);
+
+    let Some(DecodedVendorElement::OweTransitionMode(owe)) = vendor[0].decode() else {
+        panic!("Expected an OWE Transition Mode element");
+    };
+    assert_eq!(owe.bssid, MacAddress([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]));
+    assert_eq!(owe.ssid, b"owe");
+    assert_eq!(owe.operating_class, Some(115));
+    assert_eq!(owe.channel, Some(36));
+
+    let Some(DecodedVendorElement::P2p(attributes)) = vendor[1].decode() else {
+        panic!("Expected a P2P element");
+    };
+    assert_eq!(
+        attributes,
+        vec![P2pAttribute::Capability(P2pCapability {
+            device_capability: P2pDeviceCapability::from_bits(0x21),
+            group_capability: P2pGroupCapability::default(),
+        })]
+    );
+
+    let Some(DecodedVendorElement::ArubaApName(name)) = vendor[2].decode() else {
+        panic!("Expected an Aruba AP name");
+    };
+    assert_eq!(name, "ap-1");
+
+    assert!(vendor[3].decode().is_none());
+
+    // Downstream crates may register their own decoders.
+    #[derive(Debug, PartialEq)]
+    struct Proprietary(u16);
+
+    let mut registry = ElementRegistry::default();
+    registry.register_vendor_decoder([0x00, 0x12, 0x34], 0x01, |data| {
+        let value = u16::from_le_bytes(data.try_into().ok()?);
+        Some(DecodedVendorElement::Custom(Arc::new(Proprietary(value))))
+    });
+    let Some(DecodedVendorElement::Custom(value)) = vendor[3].decode_with(&registry) else {
+        panic!("Expected a custom element");
+    };
+    assert_eq!(
+        value.downcast_ref::<Proprietary>(),
+        Some(&Proprietary(0xbbaa))
+    );
+
+    // The raw bytes are kept, so the elements are encoded as is.
+    assert_eq!(station_info.encode(), payload);
+}
+
+#[test]
+fn test_lossless_element_list() {
+    let payload = [
+        // Supported rates before the SSID
+        1, 2, 0x82, 0x84, //
+        // Empty SSID of a hidden network
+        0, 0, //
+        // HE Capabilities, which are only kept as raw bytes
+        255, 4, 35, 0x01, 0x02, 0x03, //
+        // Unknown extension element
+        255, 3, 200, 0xaa, 0xbb, //
+        // DS Parameter Set
+        3, 1, 6, //
+        // Unknown element
+        222, 3, 0x01, 0x02, 0x03,
+    ];
+
+    // Keeping the elements can be disabled.
+    let mut registry = ElementRegistry::default();
+    registry.set_keep_elements(false);
+    let station_info = parse_station_info_with(&payload, &registry).unwrap().1;
+    assert!(station_info.elements.is_empty());
+    assert!(station_info.encode_elements().is_empty());
+
+    let mut station_info = parse_station_info(&payload).unwrap().1;
+    let elements = &station_info.elements;
+    assert_eq!(elements.len(), 6);
+    assert_eq!(elements[1].id, 0);
+    assert!(elements[1].data.is_empty());
+    assert!(elements[1].decoded);
+    assert_eq!(station_info.ssid.as_deref(), Some(""));
+    assert_eq!(elements[2].extension_id, Some(35));
+    assert_eq!(elements[2].data, vec![0x01, 0x02, 0x03]);
+    assert_eq!(
+        elements[3],
+        InformationElement {
+            id: 255,
+            extension_id: Some(200),
+            data: vec![0xaa, 0xbb],
+            decoded: false,
+        }
+    );
+    assert!(elements[4].decoded);
+
+    // Elements can be decoded individually.
+    let decoded = elements[4]
+        .decode()
+        .expect("DS Parameter Set should be decoded");
+    assert_eq!(decoded.ds_parameter_set, Some(6));
+    assert!(elements[3].decode().is_none());
+    assert!(elements[5].decode().is_none());
+
+    assert_eq!(station_info.encode_elements(), payload);
+
+    assert_eq!(station_info.encode_lossless(), payload);
+
+    // Encoding from the typed fields uses a fixed order.
+    assert_ne!(station_info.encode(), payload);
+
+    // Modifications of the typed fields are encoded.
+    station_info.ds_parameter_set = Some(11);
+    assert!(
+        station_info
+            .encode()
+            .windows(3)
+            .any(|window| window == [3, 1, 11])
+    );
+    assert_eq!(station_info.encode_lossless(), station_info.encode());
+    assert_eq!(station_info.encode_elements(), payload);
+}
+
+#[test]
+fn test_short_trailing_element() {
+    // Elements that are shorter than an FCS are parsed as well.
+    let payload = [
+        3, 1, 6, // DS Parameter Set
+        0, 0, // Empty SSID
+        42, 1, 0x04, // ERP Information
+    ];
+
+    let (remainder, station_info) = parse_station_info(&payload).unwrap();
+    assert!(remainder.is_empty());
+    assert_eq!(station_info.elements.len(), 3);
+    assert!(station_info.erp_information.is_some());
+    assert_eq!(station_info.encode_elements(), payload);
+
+    // Trailing bytes that don't form a complete element are returned.
+    let truncated = [&payload[..], &[221, 4, 0x00]].concat();
+    let (remainder, station_info) = parse_station_info(&truncated).unwrap();
+    assert_eq!(remainder, [221, 4, 0x00]);
+    assert_eq!(station_info.encode_elements(), payload);
+}
+
+#[test]
+fn test_element_iterator() {
+    let payload = [
+        0, 3, b'a', b'b', b'c', // SSID
+        3, 1, 6, // DS Parameter Set
+        255, 3, 200, 0xaa, 0xbb, // Unknown extension element
+        0x12, 0x34, 0x56, 0x78, // FCS
+    ];
+
+    let mut elements = IeIter::new(&payload);
+    assert_eq!(elements.next(), Some((0, None, &b"abc"[..])));
+    assert_eq!(elements.next(), Some((3, None, &[6][..])));
+    assert_eq!(elements.next(), Some((255, Some(200), &[0xaa, 0xbb][..])));
+    // The FCS is interpreted as an element with a length larger than the remaining bytes.
+    assert_eq!(elements.next(), None);
+    assert_eq!(elements.remainder(), [0x12, 0x34, 0x56, 0x78]);
+
+    // A FCS with a small second byte is mistaken for an element, so it has to be stripped first.
+    let mut elements = IeIter::new(&[0x12, 0x02, 0x56, 0x78]);
+    assert_eq!(elements.next(), Some((0x12, None, &[0x56, 0x78][..])));
+
+    let (id, ext_id, data) = IeIter::new(&payload).nth(1).unwrap();
+    let decoded = decode_ie(id, ext_id, data).unwrap();
+    assert_eq!(decoded.ds_parameter_set, Some(6));
+    assert!(decode_ie(255, Some(200), &[0xaa, 0xbb]).is_none());
+
+    // Extension elements are decoded without the extension ID.
+    let decoded = decode_ie(255, Some(52), &[0x10, 0x00, 0x00]).unwrap();
+    assert_eq!(decoded.max_channel_switch_time, Some(0x10));
+}
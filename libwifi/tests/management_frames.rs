@@ -1,5 +1,10 @@
+use std::sync::Arc;
+
+use crc::{CRC_32_ISO_HDLC, Crc};
 use libwifi::frame::Frame;
-use libwifi::parse_frame;
+use libwifi::frame::components::{CustomElement, ElementKey, MacAddress};
+use libwifi::parsers::ElementRegistry;
+use libwifi::{parse_frame, parse_frame_with};
 
 #[test]
 fn test_beacon() {
@@ -40,9 +45,39 @@ fn test_beacon() {
     println!("{frame:?}");
     assert!(matches!(frame, Frame::Beacon(_)));
 
-    if let Frame::Beacon(beacon) = frame {
-        assert_eq!("My face when IP", beacon.station_info.ssid.unwrap());
+    if let Frame::Beacon(mut beacon) = frame {
+        // Replaying a parsed beacon results in the exact same bytes.
+        assert_eq!(beacon.encode(), payload);
+        assert_eq!(
+            "My face when IP",
+            beacon.station_info.ssid.as_deref().unwrap()
+        );
+
+        // Modifications of the typed fields are encoded.
+        beacon.station_info.ds_parameter_set = Some(11);
+        let encoded = beacon.encode();
+        assert_ne!(encoded, payload);
+        assert!(encoded.windows(3).any(|window| window == [3, 1, 11]));
     }
+
+    // Also if a FCS follows the elements.
+    let fcs = Crc::<u32>::new(&CRC_32_ISO_HDLC).checksum(&payload);
+    let with_fcs = [&payload[..], &fcs.to_le_bytes()].concat();
+    let frame = parse_frame(&with_fcs, true).expect("Payload should be valid");
+    let Frame::Beacon(beacon) = frame else {
+        panic!("Expected a beacon");
+    };
+    assert_eq!(beacon.encode(), payload);
+
+    // Without the received elements, the typed fields are encoded.
+    let mut registry = ElementRegistry::default();
+    registry.set_keep_elements(false);
+    let frame = parse_frame_with(&payload, false, &registry).expect("Payload should be valid");
+    let Frame::Beacon(beacon) = frame else {
+        panic!("Expected a beacon");
+    };
+    assert!(beacon.station_info.elements.is_empty());
+    assert_eq!(beacon.encode()[..36], payload[..36]);
 }
 
 #[test]
@@ -128,3 +163,163 @@ fn test_deauthentication() {
         224, 146, 3, 0,
     ];
 }
+
+#[test]
+fn test_beacon_multiple_bssid() {
+    let payload = [
+        // Header
+        128, 0, // FrameControl
+        0, 0, // Duration id
+        255, 255, 255, 255, 255, 255, // First address
+        2, 0, 0, 0, 0, 14, // Second address
+        2, 0, 0, 0, 0, 14, // Third address
+        96, 119, // SequencControl
+        // Data start
+        151, 161, 39, 206, 165, 0, 0, 0, // timestamp
+        100, 0, // interval
+        17, 4, // capability
+        0, 4, 109, 97, 105, 110, // SSID "main"
+        1, 2, 130, 132, // Supported rates
+        3, 1, 6, // DS parameter set
+        48, 20, 1, 0, 0, 15, 172, 4, 1, 0, 0, 15, 172, 4, 1, 0, 0, 15, 172, 2, 12, 0, // RSN
+        71, 41, 3, // Multiple BSSID, MaxBSSID Indicator
+        0, 22, // Nontransmitted BSSID Profile
+        83, 2, 1, 4, // Nontransmitted BSSID Capability
+        0, 5, 103, 117, 101, 115, 116, // SSID "guest"
+        85, 3, 1, 1, 0, // Multiple BSSID-Index
+        255, 4, 56, 1, 48, 0, // Non-Inheritance: RSN
+        0, 14, // Nontransmitted BSSID Profile
+        83, 2, 17, 4, // Nontransmitted BSSID Capability
+        0, 3, 105, 111, 116, // SSID "iot"
+        85, 3, 2, 1, 0, // Multiple BSSID-Index
+    ];
+
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    let Frame::Beacon(beacon) = frame else {
+        panic!("Expected a beacon");
+    };
+
+    let bsses = beacon.nontransmitted_bsses(ElementRegistry::builtin());
+    assert_eq!(bsses.len(), 2);
+
+    let guest = &bsses[0];
+    assert_eq!(guest.bssid, MacAddress([2, 0, 0, 0, 0, 15]));
+    assert_eq!(guest.bssid_index, 1);
+    assert_eq!(guest.capability_info, Some(0x0401));
+    assert_eq!(guest.station_info.ssid.as_deref(), Some("guest"));
+    assert_eq!(guest.station_info.channel(), Some(6));
+    // RSN is listed in the Non-Inheritance element.
+    assert!(guest.station_info.rsn_information.is_none());
+    assert!(guest.station_info.multiple_bssids.is_empty());
+
+    let iot = &bsses[1];
+    // The BSSID wraps around within the lower `MaxBSSID Indicator` bits.
+    assert_eq!(iot.bssid, MacAddress([2, 0, 0, 0, 0, 8]));
+    assert_eq!(iot.station_info.ssid.as_deref(), Some("iot"));
+    assert_eq!(iot.station_info.supported_rates.len(), 2);
+    assert!(iot.station_info.rsn_information.is_some());
+}
+
+#[test]
+fn test_beacon_split_multiple_bssid_profile() {
+    let payload = [
+        // Header
+        128, 0, // FrameControl
+        0, 0, // Duration id
+        255, 255, 255, 255, 255, 255, // First address
+        2, 0, 0, 0, 0, 14, // Second address
+        2, 0, 0, 0, 0, 14, // Third address
+        96, 119, // SequencControl
+        // Data start
+        151, 161, 39, 206, 165, 0, 0, 0, // timestamp
+        100, 0, // interval
+        17, 4, // capability
+        0, 4, 109, 97, 105, 110, // SSID "main"
+        3, 1, 6, // DS parameter set
+        71, 14, 3, // Multiple BSSID, MaxBSSID Indicator
+        0, 11, // Nontransmitted BSSID Profile
+        83, 2, 1, 4, // Nontransmitted BSSID Capability
+        0, 5, 103, 117, 101, 115, 116, // SSID "guest"
+        71, 24, 3, // Multiple BSSID, MaxBSSID Indicator
+        0, 5, // Continuation of the previous profile
+        85, 3, 1, 1, 0, // Multiple BSSID-Index
+        0, 14, // Nontransmitted BSSID Profile
+        83, 2, 17, 4, // Nontransmitted BSSID Capability
+        0, 3, 105, 111, 116, // SSID "iot"
+        85, 3, 2, 1, 0, // Multiple BSSID-Index
+    ];
+
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    let Frame::Beacon(beacon) = frame else {
+        panic!("Expected a beacon");
+    };
+
+    let bsses = beacon.nontransmitted_bsses(ElementRegistry::builtin());
+    assert_eq!(bsses.len(), 2);
+
+    let guest = &bsses[0];
+    assert_eq!(guest.bssid, MacAddress([2, 0, 0, 0, 0, 15]));
+    assert_eq!(guest.capability_info, Some(0x0401));
+    assert_eq!(guest.station_info.ssid.as_deref(), Some("guest"));
+    assert_eq!(guest.station_info.channel(), Some(6));
+
+    let iot = &bsses[1];
+    assert_eq!(iot.bssid_index, 2);
+    assert_eq!(iot.station_info.ssid.as_deref(), Some("iot"));
+}
+
+#[derive(Debug, PartialEq)]
+struct SiteId(u16);
+
+impl CustomElement for SiteId {
+    fn encode(&self) -> Vec<u8> {
+        self.0.to_le_bytes().to_vec()
+    }
+}
+
+#[test]
+fn test_beacon_hidden_nontransmitted_bss() {
+    let payload = [
+        // Header
+        128, 0, // FrameControl
+        0, 0, // Duration id
+        255, 255, 255, 255, 255, 255, // First address
+        2, 0, 0, 0, 0, 14, // Second address
+        2, 0, 0, 0, 0, 14, // Third address
+        96, 119, // SequencControl
+        // Data start
+        151, 161, 39, 206, 165, 0, 0, 0, // timestamp
+        100, 0, // interval
+        17, 4, // capability
+        0, 4, 109, 97, 105, 110, // SSID "main"
+        255, 4, 35, 0x01, 0x02, 0x03, // HE Capabilities
+        255, 3, 200, 0xaa, 0xbb, // Unknown extension element
+        250, 2, 0x2a, 0x00, // Site ID
+        71, 14, 3, // Multiple BSSID, MaxBSSID Indicator
+        0, 11, // Nontransmitted BSSID Profile
+        83, 2, 1, 4, // Nontransmitted BSSID Capability
+        0, 0, // Empty SSID of a hidden network
+        85, 3, 1, 1, 0, // Multiple BSSID-Index
+    ];
+
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    let Frame::Beacon(beacon) = frame else {
+        panic!("Expected a beacon");
+    };
+
+    let mut registry = ElementRegistry::default();
+    registry.register_element_parser(ElementKey::Element(250), |data| {
+        Some(Arc::new(SiteId(u16::from_le_bytes(data.try_into().ok()?))))
+    });
+    let bsses = beacon.nontransmitted_bsses(&registry);
+    assert_eq!(bsses.len(), 1);
+
+    // The empty SSID isn't replaced by the inherited one.
+    let hidden = &bsses[0].station_info;
+    assert_eq!(hidden.ssid.as_deref(), Some(""));
+    // Elements without a typed encoding are inherited as well.
+    assert_eq!(hidden.he_capabilities, Some(vec![35, 0x01, 0x02, 0x03]));
+    assert_eq!(hidden.data, vec![(255, vec![200, 0xaa, 0xbb])]);
+    // The elements are parsed with the custom parsers of the registry.
+    assert_eq!(hidden.custom_elements.get::<SiteId>(), Some(&SiteId(42)));
+}